@@ -0,0 +1,386 @@
+use crate::expressions::{
+    untyped_lambda_calculus::{app::App, unty_abs::UntyAbs, unty_var::UntyVar, UntyLamExpr},
+    CalcStepError, FreeVars, ReductionStrategy,
+};
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum StepRule {
+    Beta,
+    /// `λx. M x → M` when `x` is not free in `M`.
+    Eta,
+    /// A rule for a built-in constant, such as `succ 1 → 2`.
+    Delta,
+    /// Unfolding a fixed point, `fix (λf. e) → e[f := fix (λf. e)]`.
+    Unfold,
+}
+
+impl Display for StepRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StepRule::Beta => write!(f, "β"),
+            StepRule::Eta => write!(f, "η"),
+            StepRule::Delta => write!(f, "δ"),
+            StepRule::Unfold => write!(f, "fix"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PathStep {
+    Lhs,
+    Rhs,
+    Body,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Path(pub Vec<PathStep>);
+
+impl Path {
+    fn child(&self, step: PathStep) -> Self {
+        let mut steps = self.0.clone();
+        steps.push(step);
+        Self(steps)
+    }
+}
+
+// One letter per step so the frontend can walk a path with a plain string: `l`/`r` enter the
+// two sides of an application, `b` the body of an abstraction. The root is the empty string.
+impl Display for Path {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for step in &self.0 {
+            let c = match step {
+                PathStep::Lhs => 'l',
+                PathStep::Rhs => 'r',
+                PathStep::Body => 'b',
+            };
+            write!(f, "{c}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Renaming {
+    pub from: UntyVar,
+    pub to: UntyVar,
+    /// Position of the renamed binder in the result.
+    pub binder: Path,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct StepAnnotation {
+    pub rule: StepRule,
+    /// Position of the contracted redex in the term before the step.
+    pub redex: Path,
+    pub bound_var: UntyVar,
+    /// The operand of the contracted application, just the bound variable for an η-step.
+    pub argument: UntyLamExpr,
+    pub renamings: Vec<Renaming>,
+    /// Positions in the result where a copy of the argument was inserted.
+    pub argument_copies: Vec<Path>,
+}
+
+/// Performs the same step as `calc_step` and describes what it did.
+pub fn annotated_step(
+    expr: &UntyLamExpr,
+    strategy: ReductionStrategy,
+) -> Result<(UntyLamExpr, StepAnnotation), CalcStepError> {
+    let redex = find_redex(expr, strategy, Path::default()).ok_or(CalcStepError::NoRedex)?;
+    let mut annotation = None;
+    let result = contract_at(expr, &redex, 0, &mut annotation);
+    Ok((result, annotation.expect("the redex path leads to a redex")))
+}
+
+/// Contracts the leftmost outermost η-redex, including those under abstractions. No strategy
+/// picks η-redexes, so they are only contracted when asked for.
+pub fn annotated_eta_step(
+    expr: &UntyLamExpr,
+) -> Result<(UntyLamExpr, StepAnnotation), CalcStepError> {
+    let redex = find_eta_redex(expr, Path::default()).ok_or(CalcStepError::NoRedex)?;
+    let mut annotation = None;
+    let result = contract_at(expr, &redex, 0, &mut annotation);
+    Ok((result, annotation.expect("the redex path leads to a redex")))
+}
+
+fn is_beta_redex(app: &App<UntyLamExpr>) -> bool {
+    matches!(*app.lhs, UntyLamExpr::Abs(_))
+}
+
+fn is_eta_redex(abs: &UntyAbs<UntyLamExpr>) -> bool {
+    match &*abs.expr {
+        UntyLamExpr::App(app) => {
+            *app.rhs == UntyLamExpr::Var(abs.var.clone()) && !app.lhs.free_vars().contains(&abs.var)
+        }
+        _ => false,
+    }
+}
+
+fn find_eta_redex(expr: &UntyLamExpr, path: Path) -> Option<Path> {
+    match expr {
+        UntyLamExpr::Var(_) => None,
+        UntyLamExpr::Abs(abs) if is_eta_redex(abs) => Some(path),
+        UntyLamExpr::Abs(abs) => find_eta_redex(&abs.expr, path.child(PathStep::Body)),
+        UntyLamExpr::App(app) => find_eta_redex(&app.lhs, path.child(PathStep::Lhs))
+            .or_else(|| find_eta_redex(&app.rhs, path.child(PathStep::Rhs))),
+    }
+}
+
+// Follows `ReductionStrategy::step_app` like `calc_step` does, but returns the redex's path.
+fn find_redex(expr: &UntyLamExpr, strategy: ReductionStrategy, path: Path) -> Option<Path> {
+    match expr {
+        UntyLamExpr::Var(_) => None,
+        UntyLamExpr::Abs(abs) => {
+            if strategy.reduces_under_abs() {
+                find_redex(&abs.expr, strategy, path.child(PathStep::Body))
+            } else {
+                None
+            }
+        }
+        UntyLamExpr::App(app) => {
+            let here = || {
+                is_beta_redex(app)
+                    .then(|| path.clone())
+                    .ok_or(CalcStepError::NoRedex)
+            };
+            let in_lhs = || {
+                find_redex(&app.lhs, strategy, path.child(PathStep::Lhs))
+                    .ok_or(CalcStepError::NoRedex)
+            };
+            let in_rhs = || {
+                find_redex(&app.rhs, strategy, path.child(PathStep::Rhs))
+                    .ok_or(CalcStepError::NoRedex)
+            };
+            strategy.step_app(here, in_lhs, in_rhs).ok()
+        }
+    }
+}
+
+fn contract_at(
+    expr: &UntyLamExpr,
+    redex: &Path,
+    depth: usize,
+    annotation: &mut Option<StepAnnotation>,
+) -> UntyLamExpr {
+    match (expr, redex.0.get(depth)) {
+        (UntyLamExpr::App(app), None) => {
+            let UntyLamExpr::Abs(abs) = &*app.lhs else {
+                unreachable!("find_redex only returns beta redexes")
+            };
+            let mut trace = Trace::default();
+            let result = substitute_traced(
+                &abs.expr,
+                &abs.var,
+                &app.rhs,
+                redex.clone(),
+                true,
+                &mut trace,
+            );
+            *annotation = Some(StepAnnotation {
+                rule: StepRule::Beta,
+                redex: redex.clone(),
+                bound_var: abs.var.clone(),
+                argument: (*app.rhs).clone(),
+                renamings: trace.renamings,
+                argument_copies: trace.copies,
+            });
+            result
+        }
+        (UntyLamExpr::Abs(abs), None) => {
+            let UntyLamExpr::App(app) = &*abs.expr else {
+                unreachable!("find_eta_redex only returns eta redexes")
+            };
+            *annotation = Some(StepAnnotation {
+                rule: StepRule::Eta,
+                redex: redex.clone(),
+                bound_var: abs.var.clone(),
+                argument: (*app.rhs).clone(),
+                renamings: vec![],
+                argument_copies: vec![],
+            });
+            (*app.lhs).clone()
+        }
+        (UntyLamExpr::App(app), Some(PathStep::Lhs)) => App::new_expr(
+            contract_at(&app.lhs, redex, depth + 1, annotation),
+            (*app.rhs).clone(),
+        ),
+        (UntyLamExpr::App(app), Some(PathStep::Rhs)) => App::new_expr(
+            (*app.lhs).clone(),
+            contract_at(&app.rhs, redex, depth + 1, annotation),
+        ),
+        (UntyLamExpr::Abs(abs), Some(PathStep::Body)) => UntyAbs::new_expr(
+            abs.var.clone(),
+            contract_at(&abs.expr, redex, depth + 1, annotation),
+        ),
+        _ => unreachable!("redex paths follow the shape of the term"),
+    }
+}
+
+#[derive(Default)]
+struct Trace {
+    renamings: Vec<Renaming>,
+    copies: Vec<Path>,
+}
+
+// Mirrors `Substitution::substitute` for the untyped calculus while recording renamed binders
+// and, if `record_copies` is set, every position `e` was inserted at.
+fn substitute_traced(
+    expr: &UntyLamExpr,
+    v: &UntyVar,
+    e: &UntyLamExpr,
+    path: Path,
+    record_copies: bool,
+    trace: &mut Trace,
+) -> UntyLamExpr {
+    match expr {
+        UntyLamExpr::Var(var) if var == v => {
+            if record_copies {
+                trace.copies.push(path);
+            }
+            e.clone()
+        }
+        UntyLamExpr::Var(_) => expr.clone(),
+        UntyLamExpr::App(app) => App::new_expr(
+            substitute_traced(
+                &app.lhs,
+                v,
+                e,
+                path.child(PathStep::Lhs),
+                record_copies,
+                trace,
+            ),
+            substitute_traced(
+                &app.rhs,
+                v,
+                e,
+                path.child(PathStep::Rhs),
+                record_copies,
+                trace,
+            ),
+        ),
+        UntyLamExpr::Abs(abs) => {
+            // `UntyAbs::substitute` with the renaming recorded
+            let Some(var) = abs.substitution_binder(v, &e.free_vars()) else {
+                return expr.clone();
+            };
+            let body = if var == abs.var {
+                (*abs.expr).clone()
+            } else {
+                trace.renamings.push(Renaming {
+                    from: abs.var.clone(),
+                    to: var.clone(),
+                    binder: path.clone(),
+                });
+                substitute_traced(
+                    &abs.expr,
+                    &abs.var,
+                    &var.clone().into_expr(),
+                    path.child(PathStep::Body),
+                    false,
+                    trace,
+                )
+            };
+            UntyAbs::new_expr(
+                var,
+                substitute_traced(
+                    &body,
+                    v,
+                    e,
+                    path.child(PathStep::Body),
+                    record_copies,
+                    trace,
+                ),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{annotated_eta_step, annotated_step, StepRule};
+    use crate::expressions::CalcStepError;
+    use crate::expressions::{untyped_lambda_calculus::UntyLamExpr, Expression, ReductionStrategy};
+    use crate::parsers::untyped_lambda_calculus::UntypedLambdaCalculusParser;
+
+    fn parse(input: &str) -> UntyLamExpr {
+        UntypedLambdaCalculusParser::parse(input).unwrap()
+    }
+
+    #[test]
+    fn agrees_with_calc_step() {
+        let strategies = [
+            ReductionStrategy::NormalOrder,
+            ReductionStrategy::ApplicativeOrder,
+            ReductionStrategy::CallByName,
+            ReductionStrategy::CallByValue,
+        ];
+        for input in [
+            "(λx. z) ((λy. y) w)",
+            "λa. (λx. x) a",
+            "x ((λy. y) z)",
+            "(λx y. x y1) (y y1)",
+            "(λx. λx1. x) x1",
+            "(λf x. f (f x)) (λy. y) a",
+            "(λx. x x) (λx. x x)",
+            "a b",
+        ] {
+            for strategy in strategies {
+                let mut expr = parse(input);
+                for _ in 0..10 {
+                    let expected = expr.calc_step(strategy);
+                    let annotated = annotated_step(&expr, strategy);
+                    assert_eq!(
+                        annotated.as_ref().map(|(e, _)| e),
+                        expected.as_ref(),
+                        "{input} under {strategy:?}"
+                    );
+                    match expected {
+                        Ok(next) => expr = next,
+                        Err(_) => break,
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn describes_beta_step() {
+        let (result, annotation) = annotated_step(
+            &parse("c ((λx y. x (x y)) (f y))"),
+            ReductionStrategy::NormalOrder,
+        )
+        .unwrap();
+        assert_eq!(result, parse("c (λy1. f y (f y y1))"));
+        assert_eq!(annotation.rule, StepRule::Beta);
+        assert_eq!(annotation.redex.to_string(), "r");
+        assert_eq!(annotation.bound_var.to_string(), "x");
+        assert_eq!(annotation.argument, parse("f y"));
+        assert_eq!(annotation.renamings.len(), 1);
+        assert_eq!(annotation.renamings[0].from.to_string(), "y");
+        assert_eq!(annotation.renamings[0].to.to_string(), "y1");
+        assert_eq!(annotation.renamings[0].binder.to_string(), "r");
+        let copies: Vec<String> = annotation
+            .argument_copies
+            .iter()
+            .map(|p| p.to_string())
+            .collect();
+        assert_eq!(copies, vec!["rbl", "rbrl"]);
+    }
+
+    #[test]
+    fn describes_eta_step() {
+        let expr = parse("c (λy. (λx. f x) y) (λz. z z)");
+        let (result, annotation) = annotated_eta_step(&expr).unwrap();
+        assert_eq!(result, parse("c (λx. f x) (λz. z z)"));
+        assert_eq!(annotation.rule, StepRule::Eta);
+        assert_eq!(annotation.redex.to_string(), "lr");
+        assert_eq!(annotation.bound_var.to_string(), "y");
+        assert_eq!(annotation.argument, parse("y"));
+        assert!(annotation.argument_copies.is_empty());
+
+        let (result, annotation) = annotated_eta_step(&result).unwrap();
+        assert_eq!(result, parse("c f (λz. z z)"));
+        assert_eq!(annotation.redex.to_string(), "lr");
+        assert_eq!(annotated_eta_step(&result), Err(CalcStepError::NoRedex));
+    }
+}
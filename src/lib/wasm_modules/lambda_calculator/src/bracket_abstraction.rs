@@ -0,0 +1,257 @@
+use crate::expressions::combinatory_logic::{combinator::Combinator, CombExpr};
+use crate::expressions::untyped_lambda_calculus::{app::App, unty_var::UntyVar, UntyLamExpr};
+use crate::expressions::{Expression, FreeVars, ReductionStrategy};
+use std::fmt::Display;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+/// How `λx. M` is compiled into combinators, written `[x] M` below.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub enum AbstractionScheme {
+    /// `[x] x = I`, `[x] y = K y` and `[x] (M N) = S ([x] M) ([x] N)`. The output grows
+    /// exponentially with the nesting of abstractions.
+    #[default]
+    Naive = 0,
+    /// Avoids `S` where `x` occurs on one side only, using `K`, `B`, `C` and η.
+    Turner = 1,
+    /// Like `Turner`, but with `W` for duplication and without `S` and `I`, so
+    /// `[x] (M x) = W ([x] M)` where `x` occurs in `M`.
+    Bckw = 2,
+    /// `S`, `K` and `I` with the `K` and η rules of `Turner`, then written in terms of `ι`.
+    Iota = 3,
+}
+
+impl Display for AbstractionScheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AbstractionScheme::Naive => write!(f, "naive SKI"),
+            AbstractionScheme::Turner => write!(f, "Turner SKIBC"),
+            AbstractionScheme::Bckw => write!(f, "BCKW"),
+            AbstractionScheme::Iota => write!(f, "ι"),
+        }
+    }
+}
+
+impl AbstractionScheme {
+    pub const ALL: [Self; 4] = [Self::Naive, Self::Turner, Self::Bckw, Self::Iota];
+
+    /// The combinators the translations consist of.
+    pub fn basis(self) -> &'static [Combinator] {
+        match self {
+            AbstractionScheme::Naive => &[Combinator::S, Combinator::K, Combinator::I],
+            AbstractionScheme::Turner => &[
+                Combinator::S,
+                Combinator::K,
+                Combinator::I,
+                Combinator::B,
+                Combinator::C,
+            ],
+            AbstractionScheme::Bckw => {
+                &[Combinator::B, Combinator::C, Combinator::K, Combinator::W]
+            }
+            AbstractionScheme::Iota => &[Combinator::Iota],
+        }
+    }
+
+    /// Translates `e` from the innermost abstraction outwards. Free variables stay variables.
+    pub fn compile(self, e: &UntyLamExpr) -> CombExpr {
+        match e {
+            UntyLamExpr::Var(v) => v.clone().into_expr(),
+            UntyLamExpr::App(app) => App::new_expr(self.compile(&app.lhs), self.compile(&app.rhs)),
+            UntyLamExpr::Abs(abs) => self.abstract_var(&abs.var, &self.compile(&abs.expr)),
+        }
+    }
+
+    fn abstract_var(self, x: &UntyVar, body: &CombExpr) -> CombExpr {
+        match self {
+            AbstractionScheme::Iota => Self::to_iota(&self.bracket(x, body)),
+            _ => self.bracket(x, body),
+        }
+    }
+
+    fn bracket(self, x: &UntyVar, body: &CombExpr) -> CombExpr {
+        let comb = |c: Combinator| -> CombExpr { c.new_expr() };
+        let app = App::new_expr;
+        let optimized = self != AbstractionScheme::Naive;
+        let uses_bc = matches!(self, AbstractionScheme::Turner | AbstractionScheme::Bckw);
+        match body {
+            CombExpr::Var(v) if v == x => match self {
+                AbstractionScheme::Bckw => app(comb(Combinator::W), comb(Combinator::K)),
+                _ => comb(Combinator::I),
+            },
+            _ if optimized && !body.free_vars().contains(x) => {
+                app(comb(Combinator::K), body.clone())
+            }
+            CombExpr::Var(_) | CombExpr::Comb(_) => app(comb(Combinator::K), body.clone()),
+            CombExpr::App(a) => {
+                let (m, n) = (&*a.lhs, &*a.rhs);
+                let in_m = m.free_vars().contains(x);
+                let in_n = n.free_vars().contains(x);
+                match (optimized, in_m, n) {
+                    (true, false, CombExpr::Var(v)) if v == x => m.clone(),
+                    (true, false, _) if uses_bc => {
+                        app(app(comb(Combinator::B), m.clone()), self.bracket(x, n))
+                    }
+                    (true, true, _) if !in_n && uses_bc => {
+                        app(app(comb(Combinator::C), self.bracket(x, m)), n.clone())
+                    }
+                    (_, _, CombExpr::Var(v)) if v == x && self == AbstractionScheme::Bckw => {
+                        app(comb(Combinator::W), self.bracket(x, m))
+                    }
+                    // `S f g = W (B (C f) g)`
+                    _ if self == AbstractionScheme::Bckw => app(
+                        comb(Combinator::W),
+                        app(
+                            app(
+                                comb(Combinator::B),
+                                app(comb(Combinator::C), self.bracket(x, m)),
+                            ),
+                            self.bracket(x, n),
+                        ),
+                    ),
+                    _ => app(
+                        app(comb(Combinator::S), self.bracket(x, m)),
+                        self.bracket(x, n),
+                    ),
+                }
+            }
+        }
+    }
+
+    // `I = ι ι`, `K = ι (ι (ι ι))` and `S = ι (ι (ι (ι ι)))`, all equal in the extensional sense.
+    fn to_iota(e: &CombExpr) -> CombExpr {
+        let iota = || Combinator::Iota.new_expr();
+        // `ι ι` under `wraps` further applications of `ι`
+        let tower = |wraps: usize| {
+            (0..wraps).fold(App::new_expr(iota(), iota()), |acc, _| {
+                App::new_expr(iota(), acc)
+            })
+        };
+        match e {
+            CombExpr::Var(_) => e.clone(),
+            CombExpr::Comb(Combinator::I) => tower(0),
+            CombExpr::Comb(Combinator::K) => tower(2),
+            CombExpr::Comb(Combinator::S) => tower(3),
+            CombExpr::Comb(c) => c.new_expr(),
+            CombExpr::App(app) => App::new_expr(Self::to_iota(&app.lhs), Self::to_iota(&app.rhs)),
+        }
+    }
+
+    /// Whether translating `e` and back to a lambda term keeps its β-normal form, up to α and
+    /// η since the optimized schemes use η. `None` if `e` or its translation has no normal
+    /// form within `max_steps` steps.
+    pub fn preserves_normal_form(self, e: &UntyLamExpr, max_steps: u32) -> Option<bool> {
+        let expected = normal_form(e, max_steps)?;
+        let translated = normal_form(&self.compile(e).to_lambda(), max_steps)?;
+        Some(translated.eta_reduce().alpha_eq(&expected.eta_reduce()))
+    }
+}
+
+fn normal_form(e: &UntyLamExpr, max_steps: u32) -> Option<UntyLamExpr> {
+    let mut e = e.clone();
+    for _ in 0..max_steps {
+        match e.calc_step(ReductionStrategy::NormalOrder) {
+            Ok(next) => e = next,
+            Err(_) => return Some(e),
+        }
+    }
+    e.is_normal_form(ReductionStrategy::NormalOrder)
+        .then_some(e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AbstractionScheme;
+    use crate::parsers::untyped_lambda_calculus::UntypedLambdaCalculusParser;
+
+    fn compile(scheme: AbstractionScheme, input: &str) -> String {
+        let e = UntypedLambdaCalculusParser::parse(input).unwrap();
+        scheme.compile(&e).to_string()
+    }
+
+    #[test]
+    fn schemes() {
+        let k = "λx y. x";
+        assert_eq!(compile(AbstractionScheme::Naive, k), "S (K K) I");
+        assert_eq!(compile(AbstractionScheme::Turner, k), "K");
+        assert_eq!(compile(AbstractionScheme::Bckw, k), "K");
+        assert_eq!(compile(AbstractionScheme::Iota, "λx. x"), "ι ι");
+        let flip = "λf x y. f y x";
+        assert_eq!(compile(AbstractionScheme::Turner, flip), "C");
+        assert_eq!(compile(AbstractionScheme::Turner, "λx. f (g x)"), "B f g");
+        assert_eq!(compile(AbstractionScheme::Bckw, "λx. x x"), "W (W K)");
+        assert_eq!(
+            compile(AbstractionScheme::Naive, "λx. f y"),
+            "S (K f) (K y)"
+        );
+        assert_eq!(compile(AbstractionScheme::Naive, "λx. f x"), "S (K f) I");
+    }
+
+    #[test]
+    fn outputs_stay_in_the_basis() {
+        let e = UntypedLambdaCalculusParser::parse("λf x y. f (y x) (λz. z x f)").unwrap();
+        for scheme in AbstractionScheme::ALL {
+            let mut combinators = vec![];
+            let compiled = scheme.compile(&e);
+            let mut stack = vec![&compiled];
+            while let Some(e) = stack.pop() {
+                let (head, args) = e.spine();
+                combinators.push(head);
+                stack.extend(args);
+            }
+            assert!(combinators.iter().all(|c| match c {
+                crate::expressions::combinatory_logic::CombExpr::Comb(c) =>
+                    scheme.basis().contains(c),
+                _ => false,
+            }));
+        }
+    }
+
+    #[test]
+    fn naive_sizes_explode() {
+        let mut sizes = vec![];
+        let mut input = "x1".to_string();
+        for i in 1..=5 {
+            input = format!("λx{i}. {input}");
+            let e = UntypedLambdaCalculusParser::parse(&input).unwrap();
+            sizes.push((
+                AbstractionScheme::Naive.compile(&e).size(),
+                AbstractionScheme::Turner.compile(&e).size(),
+            ));
+        }
+        assert_eq!(sizes, vec![(1, 1), (3, 3), (9, 5), (27, 7), (81, 9)]);
+    }
+
+    #[test]
+    fn normal_forms_are_preserved() {
+        for input in [
+            "λf x y. f y x",
+            "(λm n f x. m f (n f x)) (λf x. f x) (λf x. f (f x))",
+            "λx. (λy. y y) x",
+            "(λx y. x) a b",
+            "λf. f (λx. f x x)",
+        ] {
+            let e = UntypedLambdaCalculusParser::parse(input).unwrap();
+            for scheme in AbstractionScheme::ALL {
+                assert_eq!(
+                    scheme.preserves_normal_form(&e, 10_000),
+                    Some(true),
+                    "{scheme} on {input}"
+                );
+            }
+        }
+        let omega = UntypedLambdaCalculusParser::parse("(λx. x x) (λx. x x)").unwrap();
+        assert_eq!(
+            AbstractionScheme::Naive.preserves_normal_form(&omega, 100),
+            None
+        );
+        // the lambda term is normalized within the budget, its translation isn't
+        let e = UntypedLambdaCalculusParser::parse("(λx y. y x) a b").unwrap();
+        assert_eq!(AbstractionScheme::Naive.preserves_normal_form(&e, 2), None);
+        assert_eq!(
+            AbstractionScheme::Naive.preserves_normal_form(&e, 100),
+            Some(true)
+        );
+    }
+}
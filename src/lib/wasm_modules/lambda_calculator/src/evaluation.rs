@@ -0,0 +1,144 @@
+use crate::annotation::annotated_step;
+use crate::expressions::{untyped_lambda_calculus::UntyLamExpr, ReductionStrategy};
+use crate::statistics::ReductionStats;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EvaluationState {
+    Running,
+    Finished,
+    Cancelled,
+}
+
+#[derive(Debug, Clone)]
+pub struct Evaluation {
+    expr: UntyLamExpr,
+    strategy: ReductionStrategy,
+    steps: u32,
+    state: EvaluationState,
+    stats: ReductionStats,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen(getter_with_clone))]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct EvaluationProgress {
+    /// Steps taken since the evaluation was started.
+    pub steps: u32,
+    /// `false` once a normal form is reached or the evaluation was cancelled.
+    pub more_work: bool,
+    pub expr: String,
+}
+
+impl Evaluation {
+    pub fn new(expr: UntyLamExpr, strategy: ReductionStrategy) -> Self {
+        Self {
+            stats: ReductionStats::new(&expr),
+            expr,
+            strategy,
+            steps: 0,
+            state: EvaluationState::Running,
+        }
+    }
+
+    pub fn expr(&self) -> &UntyLamExpr {
+        &self.expr
+    }
+
+    pub fn into_expr(self) -> UntyLamExpr {
+        self.expr
+    }
+
+    pub fn steps(&self) -> u32 {
+        self.steps
+    }
+
+    pub fn stats(&self) -> &ReductionStats {
+        &self.stats
+    }
+
+    pub fn state(&self) -> EvaluationState {
+        self.state
+    }
+
+    pub fn cancel(&mut self) {
+        if self.state == EvaluationState::Running {
+            self.state = EvaluationState::Cancelled;
+        }
+    }
+
+    /// Takes at most `budget` steps and reports whether more work remains.
+    pub fn run(&mut self, budget: u32) -> EvaluationState {
+        for _ in 0..budget {
+            if self.state != EvaluationState::Running {
+                break;
+            }
+            match annotated_step(&self.expr, self.strategy) {
+                Ok((next, annotation)) => {
+                    self.stats.record(&next, &annotation);
+                    self.expr = next;
+                    self.steps = self.steps.saturating_add(1);
+                }
+                Err(_) => self.state = EvaluationState::Finished,
+            }
+        }
+        // a budget that ends exactly on a normal form should not ask for another round
+        if self.state == EvaluationState::Running && self.expr.is_normal_form(self.strategy) {
+            self.state = EvaluationState::Finished;
+        }
+        self.state
+    }
+
+    pub fn progress(&self) -> EvaluationProgress {
+        EvaluationProgress {
+            steps: self.steps,
+            more_work: self.state == EvaluationState::Running,
+            expr: self.expr.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Evaluation, EvaluationState};
+    use crate::expressions::ReductionStrategy;
+    use crate::parsers::untyped_lambda_calculus::UntypedLambdaCalculusParser;
+
+    fn evaluation(input: &str) -> Evaluation {
+        Evaluation::new(
+            UntypedLambdaCalculusParser::parse(input).unwrap(),
+            ReductionStrategy::NormalOrder,
+        )
+    }
+
+    #[test]
+    fn runs_in_chunks() {
+        let mut evaluation = evaluation("(λf x. f (f (f x))) (λy. y) a");
+        assert_eq!(evaluation.run(2), EvaluationState::Running);
+        assert_eq!(evaluation.steps(), 2);
+        assert_eq!(evaluation.run(2), EvaluationState::Running);
+        assert_eq!(evaluation.run(2), EvaluationState::Finished);
+        assert_eq!(evaluation.steps(), 5);
+        assert_eq!(evaluation.expr().to_string(), "a");
+        assert_eq!(evaluation.run(2), EvaluationState::Finished);
+        assert_eq!(evaluation.steps(), 5);
+    }
+
+    #[test]
+    fn finishes_on_exact_budget() {
+        let mut evaluation = evaluation("(λx. x) a");
+        assert_eq!(evaluation.run(1), EvaluationState::Finished);
+        assert!(!evaluation.progress().more_work);
+    }
+
+    #[test]
+    fn cancels_divergent_terms() {
+        let mut evaluation = evaluation("(λx. x x) (λx. x x)");
+        assert_eq!(evaluation.run(1000), EvaluationState::Running);
+        assert_eq!(evaluation.steps(), 1000);
+        evaluation.cancel();
+        assert_eq!(evaluation.run(1000), EvaluationState::Cancelled);
+        assert_eq!(evaluation.steps(), 1000);
+        assert!(!evaluation.progress().more_work);
+    }
+}
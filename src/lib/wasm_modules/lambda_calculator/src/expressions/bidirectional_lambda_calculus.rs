@@ -0,0 +1,204 @@
+use self::ann::Ann;
+use self::hole::Hole;
+use super::simply_typed_lambda_calculus::typed_abs::TypedAbs;
+use super::typed_lambda_calculus::{
+    absurd::Absurd, case::Case, fold::Fold, inj::Inj, pair::Pair, proj::Proj, ty::Type,
+    unfold::Unfold, unit::Unit,
+};
+use super::untyped_lambda_calculus::{
+    app::{App, IsAbs, IsAtomic},
+    unty_abs::UntyAbs,
+    unty_var::UntyVar,
+};
+use super::Sum;
+use std::fmt::{Debug, Display};
+pub mod ann;
+pub mod hole;
+
+// The syntax of `TypedLamExpr` where abstractions may leave out the type of their variable,
+// injections, `absurd` and `fold` their `as T`, and any term may be ascribed a type. The
+// bidirectional checker elaborates it back into a `TypedLamExpr`. Holes stand for the
+// unfinished parts of a proof.
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub enum BidirExpr {
+    Var(UntyVar),
+    Abs(UntyAbs<Self>),
+    TypedAbs(TypedAbs<Type, Self>),
+    App(App<Self>),
+    Ann(Ann<Type, Self>),
+    Unit(Unit),
+    Pair(Pair<Self>),
+    Proj(Proj<Self>),
+    Inj(Inj<Option<Type>, Self>),
+    Case(Case<Self>),
+    Absurd(Absurd<Option<Type>, Self>),
+    Fold(Fold<Option<Type>, Self>),
+    Unfold(Unfold<Self>),
+    Hole(Hole),
+}
+
+impl BidirExpr {
+    /// Rebuilds the term with every hole replaced by `f` of it, from left to right.
+    pub fn map_holes(&self, f: &mut impl FnMut(Hole) -> Self) -> Self {
+        match self {
+            BidirExpr::Var(_) | BidirExpr::Unit(_) => self.clone(),
+            BidirExpr::Abs(abs) => UntyAbs::new_expr(abs.var.clone(), abs.expr.map_holes(f)),
+            BidirExpr::TypedAbs(abs) => {
+                TypedAbs::new_expr(abs.var.clone(), abs.ty.clone(), abs.expr.map_holes(f))
+            }
+            BidirExpr::App(app) => App::new_expr(app.lhs.map_holes(f), app.rhs.map_holes(f)),
+            BidirExpr::Ann(ann) => Ann::new_expr(ann.expr.map_holes(f), ann.ty.clone()),
+            BidirExpr::Pair(pair) => Pair::new_expr(pair.fst.map_holes(f), pair.snd.map_holes(f)),
+            BidirExpr::Proj(proj) => Proj::new_expr(proj.side, proj.expr.map_holes(f)),
+            BidirExpr::Inj(inj) => Inj::new_expr(inj.side, inj.expr.map_holes(f), inj.ty.clone()),
+            BidirExpr::Case(case) => Case::new_expr(
+                case.expr.map_holes(f),
+                UntyAbs::new(case.left.var.clone(), case.left.expr.map_holes(f)),
+                UntyAbs::new(case.right.var.clone(), case.right.expr.map_holes(f)),
+            ),
+            BidirExpr::Absurd(absurd) => {
+                Absurd::new_expr(absurd.expr.map_holes(f), absurd.ty.clone())
+            }
+            BidirExpr::Fold(fold) => Fold::new_expr(fold.expr.map_holes(f), fold.ty.clone()),
+            BidirExpr::Unfold(unfold) => Unfold::new_expr(unfold.expr.map_holes(f)),
+            BidirExpr::Hole(hole) => f(*hole),
+        }
+    }
+
+    pub fn holes(&self) -> Vec<Hole> {
+        let mut holes = vec![];
+        self.map_holes(&mut |hole| {
+            holes.push(hole);
+            hole.into_expr()
+        });
+        holes
+    }
+}
+
+impl Sum<UntyVar> for BidirExpr {
+    fn pack(content: UntyVar) -> Self {
+        Self::Var(content)
+    }
+}
+
+impl Sum<UntyAbs<Self>> for BidirExpr {
+    fn pack(content: UntyAbs<Self>) -> Self {
+        Self::Abs(content)
+    }
+}
+
+impl Sum<TypedAbs<Type, Self>> for BidirExpr {
+    fn pack(content: TypedAbs<Type, Self>) -> Self {
+        Self::TypedAbs(content)
+    }
+}
+
+impl Sum<App<Self>> for BidirExpr {
+    fn pack(content: App<Self>) -> Self {
+        Self::App(content)
+    }
+}
+
+impl Sum<Ann<Type, Self>> for BidirExpr {
+    fn pack(content: Ann<Type, Self>) -> Self {
+        Self::Ann(content)
+    }
+}
+
+impl Sum<Unit> for BidirExpr {
+    fn pack(content: Unit) -> Self {
+        Self::Unit(content)
+    }
+}
+
+impl Sum<Pair<Self>> for BidirExpr {
+    fn pack(content: Pair<Self>) -> Self {
+        Self::Pair(content)
+    }
+}
+
+impl Sum<Proj<Self>> for BidirExpr {
+    fn pack(content: Proj<Self>) -> Self {
+        Self::Proj(content)
+    }
+}
+
+impl Sum<Inj<Option<Type>, Self>> for BidirExpr {
+    fn pack(content: Inj<Option<Type>, Self>) -> Self {
+        Self::Inj(content)
+    }
+}
+
+impl Sum<Case<Self>> for BidirExpr {
+    fn pack(content: Case<Self>) -> Self {
+        Self::Case(content)
+    }
+}
+
+impl Sum<Absurd<Option<Type>, Self>> for BidirExpr {
+    fn pack(content: Absurd<Option<Type>, Self>) -> Self {
+        Self::Absurd(content)
+    }
+}
+
+impl Sum<Fold<Option<Type>, Self>> for BidirExpr {
+    fn pack(content: Fold<Option<Type>, Self>) -> Self {
+        Self::Fold(content)
+    }
+}
+
+impl Sum<Unfold<Self>> for BidirExpr {
+    fn pack(content: Unfold<Self>) -> Self {
+        Self::Unfold(content)
+    }
+}
+
+impl Sum<Hole> for BidirExpr {
+    fn pack(content: Hole) -> Self {
+        Self::Hole(content)
+    }
+}
+
+impl IsAbs for BidirExpr {
+    fn is_abs(&self) -> bool {
+        matches!(
+            self,
+            Self::Abs(_)
+                | Self::TypedAbs(_)
+                | Self::Inj(_)
+                | Self::Case(_)
+                | Self::Absurd(_)
+                | Self::Fold(_)
+        )
+    }
+}
+
+impl IsAtomic for BidirExpr {
+    fn is_atomic(&self) -> bool {
+        matches!(
+            self,
+            Self::Var(_) | Self::Ann(_) | Self::Unit(_) | Self::Pair(_) | Self::Hole(_)
+        )
+    }
+}
+
+impl Display for BidirExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BidirExpr::Var(e) => Display::fmt(e, f),
+            BidirExpr::Abs(e) => Display::fmt(e, f),
+            BidirExpr::TypedAbs(e) => Display::fmt(e, f),
+            BidirExpr::App(e) => Display::fmt(e, f),
+            BidirExpr::Ann(e) => Display::fmt(e, f),
+            BidirExpr::Unit(e) => Display::fmt(e, f),
+            BidirExpr::Pair(e) => Display::fmt(e, f),
+            BidirExpr::Proj(e) => Display::fmt(e, f),
+            BidirExpr::Inj(e) => Display::fmt(e, f),
+            BidirExpr::Case(e) => Display::fmt(e, f),
+            BidirExpr::Absurd(e) => Display::fmt(e, f),
+            BidirExpr::Fold(e) => Display::fmt(e, f),
+            BidirExpr::Unfold(e) => Display::fmt(e, f),
+            BidirExpr::Hole(e) => Display::fmt(e, f),
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::expressions::Sum;
+use std::fmt::Display;
+
+/// `(e : T)`, which turns checking `e` against `T` into synthesizing `T`.
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub struct Ann<TY, EXPR> {
+    pub(crate) expr: Box<EXPR>,
+    pub(crate) ty: TY,
+}
+
+impl<TY, EXPR> Display for Ann<TY, EXPR>
+where
+    TY: Display,
+    EXPR: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({} : {})", self.expr, self.ty)
+    }
+}
+
+impl<TY, EXPR> Ann<TY, EXPR> {
+    pub fn new(expr: EXPR, ty: TY) -> Self {
+        Self {
+            expr: Box::new(expr),
+            ty,
+        }
+    }
+    pub fn new_expr<SUM: Sum<Self>>(expr: EXPR, ty: TY) -> SUM {
+        SUM::pack(Self::new(expr, ty))
+    }
+    pub fn into_expr<SUM: Sum<Self>>(self) -> SUM {
+        SUM::pack(self)
+    }
+    pub fn expr(&self) -> &EXPR {
+        &self.expr
+    }
+    pub fn ty(&self) -> &TY {
+        &self.ty
+    }
+}
@@ -0,0 +1,29 @@
+use crate::expressions::Sum;
+use std::fmt::Display;
+
+/// `?n`, a part of a proof term that is still to be filled in.
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone, Copy)]
+pub struct Hole {
+    pub(crate) id: usize,
+}
+
+impl Display for Hole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "?{}", self.id)
+    }
+}
+
+impl Hole {
+    pub fn new(id: usize) -> Self {
+        Self { id }
+    }
+    pub fn new_expr<SUM: Sum<Self>>(id: usize) -> SUM {
+        SUM::pack(Self::new(id))
+    }
+    pub fn into_expr<SUM: Sum<Self>>(self) -> SUM {
+        SUM::pack(self)
+    }
+    pub fn id(&self) -> usize {
+        self.id
+    }
+}
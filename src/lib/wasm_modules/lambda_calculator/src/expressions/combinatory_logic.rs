@@ -0,0 +1,202 @@
+use self::combinator::Combinator;
+use super::untyped_lambda_calculus::{
+    app::{App, ApplyAbs, IsAbs, IsAtomic},
+    unty_var::UntyVar,
+    UntyLamExpr,
+};
+use super::{CalcStepError, Expression, FreeVars, ReductionStrategy, Sum};
+use std::collections::BTreeSet;
+use std::fmt::{Debug, Display};
+pub mod combinator;
+
+/// Terms of combinatory logic. There are no binders, so reduction is always weak and the
+/// strategies only differ in the order of the redexes.
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub enum CombExpr {
+    Var(UntyVar),
+    Comb(Combinator),
+    App(App<Self>),
+}
+
+impl CombExpr {
+    pub fn is_normal_form(&self, strategy: ReductionStrategy) -> bool {
+        self.calc_step(strategy).is_err()
+    }
+
+    pub fn size(&self) -> u32 {
+        match self {
+            CombExpr::Var(_) | CombExpr::Comb(_) => 1,
+            CombExpr::App(app) => 1 + app.lhs.size() + app.rhs.size(),
+        }
+    }
+
+    /// Replaces every combinator by the lambda term that defines it.
+    pub fn to_lambda(&self) -> UntyLamExpr {
+        match self {
+            CombExpr::Var(v) => v.clone().into_expr(),
+            CombExpr::Comb(comb) => comb.to_lambda(),
+            CombExpr::App(app) => App::new_expr(app.lhs.to_lambda(), app.rhs.to_lambda()),
+        }
+    }
+
+    /// The head of the application spine and its arguments, from left to right.
+    pub fn spine(&self) -> (&Self, Vec<&Self>) {
+        match self {
+            CombExpr::App(app) => {
+                let (head, mut args) = app.lhs.spine();
+                args.push(&app.rhs);
+                (head, args)
+            }
+            _ => (self, vec![]),
+        }
+    }
+}
+
+impl Sum<UntyVar> for CombExpr {
+    fn pack(content: UntyVar) -> Self {
+        Self::Var(content)
+    }
+}
+impl Sum<Combinator> for CombExpr {
+    fn pack(content: Combinator) -> Self {
+        Self::Comb(content)
+    }
+}
+impl Sum<App<Self>> for CombExpr {
+    fn pack(content: App<Self>) -> Self {
+        Self::App(content)
+    }
+}
+
+impl Expression<Self> for CombExpr {
+    fn is_value(&self) -> bool {
+        match self {
+            CombExpr::Var(e) => <UntyVar as Expression<Self>>::is_value(e),
+            CombExpr::Comb(e) => <Combinator as Expression<Self>>::is_value(e),
+            CombExpr::App(e) => e.is_value(),
+        }
+    }
+
+    fn calc_step(&self, strategy: ReductionStrategy) -> Result<Self, CalcStepError> {
+        match self {
+            CombExpr::Var(e) => e.calc_step(strategy),
+            CombExpr::Comb(e) => e.calc_step(strategy),
+            CombExpr::App(e) => e.calc_step(strategy),
+        }
+    }
+}
+
+impl FreeVars<UntyVar> for CombExpr {
+    fn free_vars(&self) -> BTreeSet<UntyVar> {
+        match self {
+            CombExpr::Var(exp) => exp.free_vars(),
+            CombExpr::Comb(exp) => exp.free_vars(),
+            CombExpr::App(exp) => exp.free_vars(),
+        }
+    }
+}
+
+impl IsAbs for CombExpr {
+    fn is_abs(&self) -> bool {
+        false
+    }
+}
+
+impl IsAtomic for CombExpr {
+    fn is_atomic(&self) -> bool {
+        !matches!(self, Self::App(_))
+    }
+}
+
+// `App` contracts `self arg` when `self` is a combinator applied to all but its last argument.
+impl ApplyAbs<Self> for CombExpr {
+    fn apply_abs(&self, arg: &Self) -> Option<Self> {
+        let (head, mut args) = self.spine();
+        let CombExpr::Comb(comb) = head else {
+            return None;
+        };
+        args.push(arg);
+        (args.len() == comb.arity()).then(|| {
+            let args: Vec<_> = args.into_iter().cloned().collect();
+            comb.contract(&args)
+        })
+    }
+}
+
+impl Display for CombExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CombExpr::Var(e) => Display::fmt(e, f),
+            CombExpr::Comb(e) => Display::fmt(e, f),
+            CombExpr::App(e) => Display::fmt(e, f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CombExpr;
+    use crate::expressions::{Expression, ReductionStrategy};
+    use crate::parsers::combinatory_logic::CombinatoryLogicParser;
+
+    fn normalize(input: &str, strategy: ReductionStrategy) -> String {
+        let mut e = CombinatoryLogicParser::parse(input).unwrap();
+        for _ in 0..100 {
+            match e.calc_step(strategy) {
+                Ok(next) => e = next,
+                Err(_) => break,
+            }
+        }
+        e.to_string()
+    }
+
+    #[test]
+    fn contractions() {
+        for (input, expected) in [
+            ("S K K x", "x"),
+            ("B f g x", "f (g x)"),
+            ("C f x y", "f y x"),
+            ("W f x", "f x x"),
+            ("S (K (S I)) K x y", "y x"),
+        ] {
+            assert_eq!(normalize(input, ReductionStrategy::NormalOrder), expected);
+        }
+    }
+
+    #[test]
+    fn iota() {
+        assert_eq!(
+            normalize("ι (ι (ι ι)) x y", ReductionStrategy::NormalOrder),
+            "x"
+        );
+        assert_eq!(
+            CombinatoryLogicParser::parse("S K x")
+                .unwrap()
+                .to_lambda()
+                .to_string(),
+            "(λ x. λ y. λ z. x z (y z)) (λ x. λ y. x) x"
+        );
+    }
+
+    #[test]
+    fn weak_reduction() {
+        let e = CombinatoryLogicParser::parse("S K").unwrap();
+        assert!(e.is_normal_form(ReductionStrategy::NormalOrder));
+        let e = CombinatoryLogicParser::parse("K x y z").unwrap();
+        assert_eq!(
+            e.calc_step(ReductionStrategy::NormalOrder)
+                .unwrap()
+                .to_string(),
+            "x z"
+        );
+        // `K I Ω` only terminates if the discarded argument is left alone
+        let omega = "(S I I (S I I))";
+        assert_eq!(
+            normalize(&format!("K I {omega}"), ReductionStrategy::NormalOrder),
+            "I"
+        );
+        let e = CombinatoryLogicParser::parse(&format!("K I {omega}")).unwrap();
+        let e: CombExpr = e.calc_step(ReductionStrategy::ApplicativeOrder).unwrap();
+        assert_eq!(e.to_string(), "K I (I (S I I) (I (S I I)))");
+    }
+}
@@ -0,0 +1,104 @@
+use crate::expressions::untyped_lambda_calculus::{app::App, UntyLamExpr};
+use crate::expressions::{CalcStepError, Expression, FreeVars, ReductionStrategy, Sum};
+use crate::parsers::untyped_lambda_calculus::UntypedLambdaCalculusParser;
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+/// A primitive constant of combinatory logic, which contracts once it has all its arguments.
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone, Copy)]
+pub enum Combinator {
+    /// `S x y z = x z (y z)`
+    S,
+    /// `K x y = x`
+    K,
+    /// `I x = x`
+    I,
+    /// `B x y z = x (y z)`
+    B,
+    /// `C x y z = x z y`
+    C,
+    /// `W x y = x y y`
+    W,
+    /// `ι x = x S K`, which alone is a basis.
+    Iota,
+}
+
+impl Display for Combinator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Combinator::Iota => write!(f, "ι"),
+            _ => write!(f, "{self:?}"),
+        }
+    }
+}
+
+impl<VAR> FreeVars<VAR> for Combinator {
+    fn free_vars(&self) -> BTreeSet<VAR> {
+        BTreeSet::new()
+    }
+}
+
+impl Combinator {
+    pub fn new_expr<EXPR: Sum<Self>>(self) -> EXPR {
+        EXPR::pack(self)
+    }
+
+    pub fn arity(self) -> usize {
+        match self {
+            Combinator::I | Combinator::Iota => 1,
+            Combinator::K | Combinator::W => 2,
+            Combinator::S | Combinator::B | Combinator::C => 3,
+        }
+    }
+
+    /// `λx y z. x z (y z)` for `S` and so on, with `ι` defined by `S` and `K`.
+    pub fn to_lambda(self) -> UntyLamExpr {
+        let source = match self {
+            Combinator::S => "λx y z. x z (y z)",
+            Combinator::K => "λx y. x",
+            Combinator::I => "λx. x",
+            Combinator::B => "λx y z. x (y z)",
+            Combinator::C => "λx y z. x z y",
+            Combinator::W => "λx y. x y y",
+            Combinator::Iota => "λf. f (λx y z. x z (y z)) (λx y. x)",
+        };
+        UntypedLambdaCalculusParser::parse(source).expect("the definitions parse")
+    }
+
+    /// The contractum of the combinator applied to exactly `arity` arguments.
+    pub fn contract<EXPR>(self, args: &[EXPR]) -> EXPR
+    where
+        EXPR: Sum<App<EXPR>> + Sum<Self> + Clone,
+    {
+        assert_eq!(
+            args.len(),
+            self.arity(),
+            "{self} takes {} arguments",
+            self.arity()
+        );
+        let arg = |i: usize| args[i].clone();
+        match self {
+            Combinator::S => {
+                App::new_expr(App::new_expr(arg(0), arg(2)), App::new_expr(arg(1), arg(2)))
+            }
+            Combinator::K | Combinator::I => arg(0),
+            Combinator::B => App::new_expr(arg(0), App::new_expr(arg(1), arg(2))),
+            Combinator::C => App::new_expr(App::new_expr(arg(0), arg(2)), arg(1)),
+            Combinator::W => App::new_expr(App::new_expr(arg(0), arg(1)), arg(1)),
+            Combinator::Iota => App::new_expr(
+                App::new_expr(arg(0), Combinator::S.new_expr()),
+                Combinator::K.new_expr(),
+            ),
+        }
+    }
+}
+
+impl<EXPR> Expression<EXPR> for Combinator {
+    fn is_value(&self) -> bool {
+        true
+    }
+
+    fn calc_step(&self, _strategy: ReductionStrategy) -> Result<EXPR, CalcStepError> {
+        Err(CalcStepError::NoRedex)
+    }
+}
@@ -0,0 +1,92 @@
+use self::let_in::LetIn;
+use super::untyped_lambda_calculus::{
+    app::{App, IsAbs, IsAtomic},
+    unty_abs::UntyAbs,
+    unty_var::UntyVar,
+    UntyLamExpr,
+};
+use super::Sum;
+use std::fmt::{Debug, Display};
+pub mod let_in;
+
+// The untyped calculus extended with `let`, which only matters for type inference:
+// evaluation works on the desugared `UntyLamExpr`.
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub enum LetLamExpr {
+    Var(UntyVar),
+    Abs(UntyAbs<Self>),
+    App(App<Self>),
+    Let(LetIn<Self>),
+}
+
+impl LetLamExpr {
+    /// Replaces every `let x = e1 in e2` by `(λx. e2) e1`.
+    pub fn desugar(&self) -> UntyLamExpr {
+        match self {
+            LetLamExpr::Var(v) => v.clone().into_expr(),
+            LetLamExpr::Abs(abs) => UntyAbs::new_expr(abs.var.clone(), abs.expr.desugar()),
+            LetLamExpr::App(app) => App::new_expr(app.lhs.desugar(), app.rhs.desugar()),
+            LetLamExpr::Let(l) => App::new_expr(
+                UntyAbs::new_expr(l.var.clone(), l.body.desugar()),
+                l.value.desugar(),
+            ),
+        }
+    }
+}
+
+impl From<&UntyLamExpr> for LetLamExpr {
+    fn from(expr: &UntyLamExpr) -> Self {
+        match expr {
+            UntyLamExpr::Var(v) => v.clone().into_expr(),
+            UntyLamExpr::Abs(abs) => UntyAbs::new_expr(abs.var.clone(), abs.expr.as_ref().into()),
+            UntyLamExpr::App(app) => {
+                App::new_expr(app.lhs.as_ref().into(), app.rhs.as_ref().into())
+            }
+        }
+    }
+}
+
+impl Sum<UntyVar> for LetLamExpr {
+    fn pack(content: UntyVar) -> Self {
+        Self::Var(content)
+    }
+}
+impl Sum<UntyAbs<Self>> for LetLamExpr {
+    fn pack(content: UntyAbs<Self>) -> Self {
+        Self::Abs(content)
+    }
+}
+impl Sum<App<Self>> for LetLamExpr {
+    fn pack(content: App<Self>) -> Self {
+        Self::App(content)
+    }
+}
+impl Sum<LetIn<Self>> for LetLamExpr {
+    fn pack(content: LetIn<Self>) -> Self {
+        Self::Let(content)
+    }
+}
+
+// `let` extends as far to the right as an abstraction, so it needs the same parentheses.
+impl IsAbs for LetLamExpr {
+    fn is_abs(&self) -> bool {
+        matches!(self, Self::Abs(_) | Self::Let(_))
+    }
+}
+
+impl IsAtomic for LetLamExpr {
+    fn is_atomic(&self) -> bool {
+        matches!(self, Self::Var(_))
+    }
+}
+
+impl Display for LetLamExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LetLamExpr::Var(e) => Display::fmt(e, f),
+            LetLamExpr::Abs(e) => Display::fmt(e, f),
+            LetLamExpr::App(e) => Display::fmt(e, f),
+            LetLamExpr::Let(e) => Display::fmt(e, f),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use super::super::untyped_lambda_calculus::unty_var::UntyVar;
+use super::super::Sum;
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub struct LetIn<EXPR> {
+    pub(crate) var: UntyVar,
+    pub(crate) value: Box<EXPR>,
+    pub(crate) body: Box<EXPR>,
+}
+
+impl<EXPR> Display for LetIn<EXPR>
+where
+    EXPR: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "let {} = {} in {}", self.var, self.value, self.body)
+    }
+}
+
+impl<EXPR> LetIn<EXPR> {
+    pub fn new(var: UntyVar, value: EXPR, body: EXPR) -> Self {
+        Self {
+            var,
+            value: Box::new(value),
+            body: Box::new(body),
+        }
+    }
+    pub fn new_expr<SUM: Sum<Self>>(var: UntyVar, value: EXPR, body: EXPR) -> SUM {
+        SUM::pack(Self::new(var, value, body))
+    }
+    pub fn into_expr<SUM: Sum<Self>>(self) -> SUM {
+        SUM::pack(self)
+    }
+}
@@ -1,7 +1,104 @@
+use crate::annotation::StepRule;
+use std::collections::BTreeSet;
+use std::fmt::Display;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+pub mod bidirectional_lambda_calculus;
+pub mod combinatory_logic;
+pub mod let_lambda_calculus;
+pub mod pcf;
+pub mod pure_type_system;
+pub mod record_lambda_calculus;
+pub mod simply_typed_lambda_calculus;
+pub mod system_f;
+pub mod typed_lambda_calculus;
 pub mod untyped_lambda_calculus;
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum CalcStepError {
     NoRedex,
+    /// A δ-rule whose result doesn't fit into a natural number literal.
+    Overflow,
+}
+
+impl Display for CalcStepError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CalcStepError::NoRedex => write!(f, "expression contains no redex"),
+            CalcStepError::Overflow => write!(f, "the result is too large for a natural number"),
+        }
+    }
+}
+
+/// `result`, or the step `next` if `result` found no redex. Other errors stop the search, so
+/// that they aren't hidden by a redex elsewhere.
+pub(crate) fn or_next<T>(
+    result: Result<T, CalcStepError>,
+    next: impl FnOnce() -> Result<T, CalcStepError>,
+) -> Result<T, CalcStepError> {
+    match result {
+        Err(CalcStepError::NoRedex) => next(),
+        result => result,
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub enum ReductionStrategy {
+    /// Leftmost outermost redex first, also under abstractions.
+    #[default]
+    NormalOrder = 0,
+    /// Leftmost innermost redex first, also under abstractions.
+    ApplicativeOrder = 1,
+    /// Leftmost outermost redex first, but never under abstractions or in arguments.
+    CallByName = 2,
+    /// Arguments are reduced to values before they are passed, never under abstractions.
+    CallByValue = 3,
+}
+
+impl ReductionStrategy {
+    pub fn from_u8(n: u8) -> Option<Self> {
+        match n {
+            0 => Some(Self::NormalOrder),
+            1 => Some(Self::ApplicativeOrder),
+            2 => Some(Self::CallByName),
+            3 => Some(Self::CallByValue),
+            _ => None,
+        }
+    }
+
+    pub fn reduces_under_abs(self) -> bool {
+        matches!(self, Self::NormalOrder | Self::ApplicativeOrder)
+    }
+
+    /// Tries a step at the root of an application, in its function and in its argument in the
+    /// order of the strategy and returns the first that succeeds. Every way of stepping an
+    /// application goes through here, so they all pick the same redex.
+    pub fn step_app<T>(
+        self,
+        root: impl FnOnce() -> Result<T, CalcStepError>,
+        lhs: impl FnOnce() -> Result<T, CalcStepError>,
+        rhs: impl FnOnce() -> Result<T, CalcStepError>,
+    ) -> Result<T, CalcStepError> {
+        match self {
+            Self::NormalOrder => or_next(or_next(root(), lhs), rhs),
+            Self::CallByName => or_next(root(), lhs),
+            // innermost first: both sides are reduced as far as the strategy allows before the
+            // outer redex fires
+            Self::ApplicativeOrder | Self::CallByValue => or_next(or_next(lhs(), rhs), root),
+        }
+    }
+
+    /// `step_app` for an elimination whose one reducible subterm takes the place of the
+    /// function, such as a projection or a type application.
+    pub fn step_elim<T>(
+        self,
+        root: impl FnOnce() -> Result<T, CalcStepError>,
+        sub: impl FnOnce() -> Result<T, CalcStepError>,
+    ) -> Result<T, CalcStepError> {
+        self.step_app(root, sub, || Err(CalcStepError::NoRedex))
+    }
 }
 
 pub trait Sum<TERM> {
@@ -9,8 +106,21 @@ pub trait Sum<TERM> {
 }
 pub trait Expression<EXPR> {
     fn is_value(&self) -> bool;
-    fn calc_step(&self) -> Result<EXPR, CalcStepError>;
+    fn calc_step(&self, strategy: ReductionStrategy) -> Result<EXPR, CalcStepError>;
+}
+/// `calc_step` that also reports which rule was applied, for calculi with more than one. Unlike
+/// `annotation::annotated_step` for the untyped calculus, it doesn't say where the redex was or
+/// what was substituted.
+pub trait RuleStep<EXPR> {
+    fn rule_step(&self, strategy: ReductionStrategy) -> Result<(EXPR, StepRule), CalcStepError>;
 }
 pub trait Substitution<VAR, EXPR> {
     fn substitute(&self, v: &VAR, e: &EXPR) -> EXPR;
 }
+/// Replaces a type variable inside the type annotations of a term.
+pub trait TypeSubstitution<TYVAR, TY, EXPR> {
+    fn substitute_type(&self, v: &TYVAR, t: &TY) -> EXPR;
+}
+pub trait FreeVars<VAR> {
+    fn free_vars(&self) -> BTreeSet<VAR>;
+}
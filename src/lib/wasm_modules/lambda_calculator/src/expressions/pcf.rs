@@ -0,0 +1,262 @@
+use self::{fix::Fix, ifz::Ifz, nat::Nat, pred::Pred, succ::Succ};
+use super::untyped_lambda_calculus::{
+    app::{App, ApplyAbs, IsAbs, IsAtomic},
+    unty_abs::UntyAbs,
+    unty_var::UntyVar,
+};
+use super::{CalcStepError, Expression, FreeVars, ReductionStrategy, RuleStep, Substitution, Sum};
+use crate::annotation::StepRule;
+use std::collections::BTreeSet;
+use std::fmt::{Debug, Display};
+pub mod fix;
+pub mod ifz;
+pub mod nat;
+pub mod pred;
+pub mod succ;
+
+/// Expressions that may be a natural-number literal, for the δ-rules that need one.
+pub trait AsNat {
+    fn as_nat(&self) -> Option<u64>;
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub enum PcfExpr {
+    Var(UntyVar),
+    Abs(UntyAbs<Self>),
+    App(App<Self>),
+    Nat(Nat),
+    Succ(Succ<Self>),
+    Pred(Pred<Self>),
+    Ifz(Ifz<Self>),
+    Fix(Fix<Self>),
+}
+
+impl Sum<UntyVar> for PcfExpr {
+    fn pack(content: UntyVar) -> Self {
+        Self::Var(content)
+    }
+}
+impl Sum<UntyAbs<Self>> for PcfExpr {
+    fn pack(content: UntyAbs<Self>) -> Self {
+        Self::Abs(content)
+    }
+}
+impl Sum<App<Self>> for PcfExpr {
+    fn pack(content: App<Self>) -> Self {
+        Self::App(content)
+    }
+}
+impl Sum<Nat> for PcfExpr {
+    fn pack(content: Nat) -> Self {
+        Self::Nat(content)
+    }
+}
+impl Sum<Succ<Self>> for PcfExpr {
+    fn pack(content: Succ<Self>) -> Self {
+        Self::Succ(content)
+    }
+}
+impl Sum<Pred<Self>> for PcfExpr {
+    fn pack(content: Pred<Self>) -> Self {
+        Self::Pred(content)
+    }
+}
+impl Sum<Ifz<Self>> for PcfExpr {
+    fn pack(content: Ifz<Self>) -> Self {
+        Self::Ifz(content)
+    }
+}
+impl Sum<Fix<Self>> for PcfExpr {
+    fn pack(content: Fix<Self>) -> Self {
+        Self::Fix(content)
+    }
+}
+impl PcfExpr {
+    pub fn is_normal_form(&self, strategy: ReductionStrategy) -> bool {
+        self.calc_step(strategy).is_err()
+    }
+}
+impl Expression<Self> for PcfExpr {
+    fn is_value(&self) -> bool {
+        matches!(self, Self::Var(_) | Self::Abs(_) | Self::Nat(_))
+    }
+
+    fn calc_step(&self, strategy: ReductionStrategy) -> Result<Self, CalcStepError> {
+        self.rule_step(strategy).map(|(expr, _)| expr)
+    }
+}
+impl RuleStep<Self> for PcfExpr {
+    fn rule_step(&self, strategy: ReductionStrategy) -> Result<(Self, StepRule), CalcStepError> {
+        match self {
+            PcfExpr::Var(e) => e.rule_step(strategy),
+            PcfExpr::Abs(e) => e.rule_step(strategy),
+            PcfExpr::App(e) => e.rule_step(strategy),
+            PcfExpr::Nat(e) => e.rule_step(strategy),
+            PcfExpr::Succ(e) => e.rule_step(strategy),
+            PcfExpr::Pred(e) => e.rule_step(strategy),
+            PcfExpr::Ifz(e) => e.rule_step(strategy),
+            PcfExpr::Fix(e) => e.rule_step(strategy),
+        }
+    }
+}
+impl Substitution<UntyVar, Self> for PcfExpr {
+    fn substitute(&self, v: &UntyVar, e: &Self) -> Self {
+        match self {
+            PcfExpr::Var(exp) => exp.substitute(v, e),
+            PcfExpr::Abs(exp) => exp.substitute(v, e),
+            PcfExpr::App(exp) => exp.substitute(v, e),
+            PcfExpr::Nat(exp) => exp.substitute(v, e),
+            PcfExpr::Succ(exp) => exp.substitute(v, e),
+            PcfExpr::Pred(exp) => exp.substitute(v, e),
+            PcfExpr::Ifz(exp) => exp.substitute(v, e),
+            PcfExpr::Fix(exp) => exp.substitute(v, e),
+        }
+    }
+}
+impl FreeVars<UntyVar> for PcfExpr {
+    fn free_vars(&self) -> BTreeSet<UntyVar> {
+        match self {
+            PcfExpr::Var(exp) => exp.free_vars(),
+            PcfExpr::Abs(exp) => exp.free_vars(),
+            PcfExpr::App(exp) => exp.free_vars(),
+            PcfExpr::Nat(exp) => exp.free_vars(),
+            PcfExpr::Succ(exp) => exp.free_vars(),
+            PcfExpr::Pred(exp) => exp.free_vars(),
+            PcfExpr::Ifz(exp) => exp.free_vars(),
+            PcfExpr::Fix(exp) => exp.free_vars(),
+        }
+    }
+}
+
+impl AsNat for PcfExpr {
+    fn as_nat(&self) -> Option<u64> {
+        match self {
+            PcfExpr::Nat(n) => Some(n.value()),
+            _ => None,
+        }
+    }
+}
+
+// `ifz` extends as far to the right as an abstraction does.
+impl IsAbs for PcfExpr {
+    fn is_abs(&self) -> bool {
+        matches!(self, Self::Abs(_) | Self::Ifz(_))
+    }
+}
+
+impl IsAtomic for PcfExpr {
+    fn is_atomic(&self) -> bool {
+        matches!(self, Self::Var(_) | Self::Nat(_))
+    }
+}
+
+impl ApplyAbs<Self> for PcfExpr {
+    fn apply_abs(&self, arg: &Self) -> Option<Self> {
+        match self {
+            PcfExpr::Abs(abs) => Some(abs.apply(arg)),
+            _ => None,
+        }
+    }
+}
+
+impl Display for PcfExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PcfExpr::Var(e) => Display::fmt(e, f),
+            PcfExpr::Abs(e) => Display::fmt(e, f),
+            PcfExpr::App(e) => Display::fmt(e, f),
+            PcfExpr::Nat(e) => Display::fmt(e, f),
+            PcfExpr::Succ(e) => Display::fmt(e, f),
+            PcfExpr::Pred(e) => Display::fmt(e, f),
+            PcfExpr::Ifz(e) => Display::fmt(e, f),
+            PcfExpr::Fix(e) => Display::fmt(e, f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PcfExpr;
+    use crate::annotation::StepRule;
+    use crate::expressions::{CalcStepError, ReductionStrategy, RuleStep};
+    use crate::parsers::pcf::PcfParser;
+
+    const ADD: &str = "fix (λadd. λm n. ifz m then n else succ (add (pred m) n))";
+
+    fn run(input: &str, strategy: ReductionStrategy) -> (PcfExpr, Vec<StepRule>) {
+        let mut expr = PcfParser::parse(input).unwrap();
+        let mut rules = vec![];
+        while let Ok((next, rule)) = expr.rule_step(strategy) {
+            rules.push(rule);
+            expr = next;
+        }
+        (expr, rules)
+    }
+
+    #[test]
+    fn delta_rules() {
+        let (expr, rules) = run("pred (succ ((λx. x) 2))", ReductionStrategy::NormalOrder);
+        assert_eq!(expr.to_string(), "2");
+        assert_eq!(
+            rules,
+            vec![StepRule::Beta, StepRule::Delta, StepRule::Delta]
+        );
+        assert_eq!(
+            run("pred 0", ReductionStrategy::NormalOrder).0.to_string(),
+            "0"
+        );
+        assert_eq!(
+            run("ifz pred 1 then a else b", ReductionStrategy::CallByName)
+                .0
+                .to_string(),
+            "a"
+        );
+        assert_eq!(
+            run(
+                "λx. ifz x then (λy. y) 1 else 2",
+                ReductionStrategy::NormalOrder
+            )
+            .0
+            .to_string(),
+            "λ x. ifz x then 1 else 2"
+        );
+    }
+
+    #[test]
+    fn recursion() {
+        for strategy in [
+            ReductionStrategy::NormalOrder,
+            ReductionStrategy::CallByName,
+            ReductionStrategy::CallByValue,
+        ] {
+            let (expr, rules) = run(&format!("({ADD}) 2 3"), strategy);
+            assert_eq!(expr.to_string(), "5");
+            assert!(rules.contains(&StepRule::Beta) && rules.contains(&StepRule::Delta));
+            assert!(rules.contains(&StepRule::Unfold));
+        }
+    }
+
+    #[test]
+    fn overflow() {
+        let max = u64::MAX;
+        let expr = PcfParser::parse(&format!("succ {max}")).unwrap();
+        assert_eq!(
+            expr.rule_step(ReductionStrategy::NormalOrder),
+            Err(CalcStepError::Overflow)
+        );
+        // not hidden by a redex that comes later in the order
+        let expr = PcfParser::parse(&format!("f (succ {max}) ((λx. x) 1)")).unwrap();
+        assert_eq!(
+            expr.rule_step(ReductionStrategy::NormalOrder),
+            Err(CalcStepError::Overflow)
+        );
+        let expr = PcfParser::parse(&format!("ifz succ {max} then (λx. x) 0 else 1")).unwrap();
+        assert_eq!(
+            expr.rule_step(ReductionStrategy::NormalOrder),
+            Err(CalcStepError::Overflow)
+        );
+        // the overflowing argument is discarded before it is evaluated
+        let expr = PcfParser::parse(&format!("(λx. 0) (succ {max})")).unwrap();
+        assert!(expr.rule_step(ReductionStrategy::CallByName).is_ok());
+    }
+}
@@ -0,0 +1,77 @@
+use crate::annotation::StepRule;
+use crate::expressions::untyped_lambda_calculus::app::{ApplyAbs, IsAtomic};
+use crate::expressions::{CalcStepError, FreeVars, ReductionStrategy, RuleStep, Substitution, Sum};
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+/// `fix e`, the fixed point of `e`.
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub struct Fix<EXPR> {
+    pub(crate) expr: Box<EXPR>,
+}
+
+impl<EXPR> Display for Fix<EXPR>
+where
+    EXPR: Display + IsAtomic,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.expr.is_atomic() {
+            write!(f, "fix {}", self.expr)
+        } else {
+            write!(f, "fix ({})", self.expr)
+        }
+    }
+}
+
+impl<EXPR> Fix<EXPR> {
+    pub fn new(expr: EXPR) -> Self {
+        Self {
+            expr: Box::new(expr),
+        }
+    }
+    pub fn new_expr<SUM: Sum<Self>>(expr: EXPR) -> SUM {
+        SUM::pack(Self::new(expr))
+    }
+    pub fn into_expr<SUM: Sum<Self>>(self) -> SUM {
+        SUM::pack(self)
+    }
+    pub fn expr(&self) -> &EXPR {
+        &self.expr
+    }
+}
+
+// `fix (λf. e) → e[f := fix (λf. e)]` rather than `fix e → e (fix e)`: the unfolded copy ends up
+// under the abstraction `e` usually is, so call-by-value does not unfold it again right away.
+impl<EXPR> RuleStep<EXPR> for Fix<EXPR>
+where
+    EXPR: RuleStep<EXPR> + ApplyAbs<EXPR> + Sum<Self> + Clone,
+{
+    fn rule_step(&self, strategy: ReductionStrategy) -> Result<(EXPR, StepRule), CalcStepError> {
+        let this = Self::new_expr((*self.expr).clone());
+        match self.expr.apply_abs(&this) {
+            Some(unfolded) => Ok((unfolded, StepRule::Unfold)),
+            None => self
+                .expr
+                .rule_step(strategy)
+                .map(|(expr, rule)| (Self::new_expr(expr), rule)),
+        }
+    }
+}
+
+impl<VAR, EXPR> Substitution<VAR, EXPR> for Fix<EXPR>
+where
+    EXPR: Substitution<VAR, EXPR> + Sum<Self>,
+{
+    fn substitute(&self, v: &VAR, e: &EXPR) -> EXPR {
+        Self::new_expr(self.expr.substitute(v, e))
+    }
+}
+
+impl<VAR, EXPR> FreeVars<VAR> for Fix<EXPR>
+where
+    EXPR: FreeVars<VAR>,
+{
+    fn free_vars(&self) -> BTreeSet<VAR> {
+        self.expr.free_vars()
+    }
+}
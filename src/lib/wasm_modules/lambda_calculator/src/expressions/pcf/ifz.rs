@@ -0,0 +1,110 @@
+use super::AsNat;
+use crate::annotation::StepRule;
+use crate::expressions::{
+    or_next, CalcStepError, FreeVars, ReductionStrategy, RuleStep, Substitution, Sum,
+};
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+/// `ifz cond then zero else succ`: branches on whether `cond` is `0`.
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub struct Ifz<EXPR> {
+    pub(crate) cond: Box<EXPR>,
+    pub(crate) zero: Box<EXPR>,
+    pub(crate) succ: Box<EXPR>,
+}
+
+impl<EXPR> Display for Ifz<EXPR>
+where
+    EXPR: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ifz {} then {} else {}", self.cond, self.zero, self.succ)
+    }
+}
+
+impl<EXPR> Ifz<EXPR> {
+    pub fn new(cond: EXPR, zero: EXPR, succ: EXPR) -> Self {
+        Self {
+            cond: Box::new(cond),
+            zero: Box::new(zero),
+            succ: Box::new(succ),
+        }
+    }
+    pub fn new_expr<SUM: Sum<Self>>(cond: EXPR, zero: EXPR, succ: EXPR) -> SUM {
+        SUM::pack(Self::new(cond, zero, succ))
+    }
+    pub fn into_expr<SUM: Sum<Self>>(self) -> SUM {
+        SUM::pack(self)
+    }
+    pub fn cond(&self) -> &EXPR {
+        &self.cond
+    }
+    pub fn zero(&self) -> &EXPR {
+        &self.zero
+    }
+    pub fn succ(&self) -> &EXPR {
+        &self.succ
+    }
+}
+
+// The branches are only entered by strategies that also reduce under abstractions, otherwise a
+// recursive definition would unfold forever in the branch that is not taken.
+impl<EXPR> RuleStep<EXPR> for Ifz<EXPR>
+where
+    EXPR: RuleStep<EXPR> + AsNat + Sum<Self> + Clone,
+{
+    fn rule_step(&self, strategy: ReductionStrategy) -> Result<(EXPR, StepRule), CalcStepError> {
+        match self.cond.as_nat() {
+            Some(0) => return Ok(((*self.zero).clone(), StepRule::Delta)),
+            Some(_) => return Ok(((*self.succ).clone(), StepRule::Delta)),
+            None => {}
+        }
+        let step_cond = self.cond.rule_step(strategy).map(|(cond, rule)| {
+            let ifz = Self::new_expr(cond, (*self.zero).clone(), (*self.succ).clone());
+            (ifz, rule)
+        });
+        if !strategy.reduces_under_abs() {
+            return step_cond;
+        }
+        let step_zero = || {
+            self.zero.rule_step(strategy).map(|(zero, rule)| {
+                let ifz = Self::new_expr((*self.cond).clone(), zero, (*self.succ).clone());
+                (ifz, rule)
+            })
+        };
+        let step_succ = || {
+            self.succ.rule_step(strategy).map(|(succ, rule)| {
+                let ifz = Self::new_expr((*self.cond).clone(), (*self.zero).clone(), succ);
+                (ifz, rule)
+            })
+        };
+        or_next(or_next(step_cond, step_zero), step_succ)
+    }
+}
+
+impl<VAR, EXPR> Substitution<VAR, EXPR> for Ifz<EXPR>
+where
+    EXPR: Substitution<VAR, EXPR> + Sum<Self>,
+{
+    fn substitute(&self, v: &VAR, e: &EXPR) -> EXPR {
+        Self::new_expr(
+            self.cond.substitute(v, e),
+            self.zero.substitute(v, e),
+            self.succ.substitute(v, e),
+        )
+    }
+}
+
+impl<VAR, EXPR> FreeVars<VAR> for Ifz<EXPR>
+where
+    VAR: Ord,
+    EXPR: FreeVars<VAR>,
+{
+    fn free_vars(&self) -> BTreeSet<VAR> {
+        let mut vars = self.cond.free_vars();
+        vars.extend(self.zero.free_vars());
+        vars.extend(self.succ.free_vars());
+        vars
+    }
+}
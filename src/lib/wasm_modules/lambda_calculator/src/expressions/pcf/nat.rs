@@ -0,0 +1,52 @@
+use crate::annotation::StepRule;
+use crate::expressions::{CalcStepError, FreeVars, ReductionStrategy, RuleStep, Substitution, Sum};
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+/// A natural-number literal.
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone, Copy)]
+pub struct Nat {
+    value: u64,
+}
+
+impl Display for Nat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl Nat {
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+    pub fn new_expr<EXPR: Sum<Self>>(value: u64) -> EXPR {
+        EXPR::pack(Self::new(value))
+    }
+    pub fn into_expr<EXPR: Sum<Self>>(self) -> EXPR {
+        EXPR::pack(self)
+    }
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+}
+
+impl<EXPR> RuleStep<EXPR> for Nat {
+    fn rule_step(&self, _strategy: ReductionStrategy) -> Result<(EXPR, StepRule), CalcStepError> {
+        Err(CalcStepError::NoRedex)
+    }
+}
+
+impl<VAR, EXPR> Substitution<VAR, EXPR> for Nat
+where
+    EXPR: Sum<Self>,
+{
+    fn substitute(&self, _v: &VAR, _e: &EXPR) -> EXPR {
+        self.into_expr()
+    }
+}
+
+impl<VAR> FreeVars<VAR> for Nat {
+    fn free_vars(&self) -> BTreeSet<VAR> {
+        BTreeSet::new()
+    }
+}
@@ -0,0 +1,75 @@
+use super::{nat::Nat, AsNat};
+use crate::annotation::StepRule;
+use crate::expressions::untyped_lambda_calculus::app::IsAtomic;
+use crate::expressions::{CalcStepError, FreeVars, ReductionStrategy, RuleStep, Substitution, Sum};
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub struct Pred<EXPR> {
+    pub(crate) expr: Box<EXPR>,
+}
+
+impl<EXPR> Display for Pred<EXPR>
+where
+    EXPR: Display + IsAtomic,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.expr.is_atomic() {
+            write!(f, "pred {}", self.expr)
+        } else {
+            write!(f, "pred ({})", self.expr)
+        }
+    }
+}
+
+impl<EXPR> Pred<EXPR> {
+    pub fn new(expr: EXPR) -> Self {
+        Self {
+            expr: Box::new(expr),
+        }
+    }
+    pub fn new_expr<SUM: Sum<Self>>(expr: EXPR) -> SUM {
+        SUM::pack(Self::new(expr))
+    }
+    pub fn into_expr<SUM: Sum<Self>>(self) -> SUM {
+        SUM::pack(self)
+    }
+    pub fn expr(&self) -> &EXPR {
+        &self.expr
+    }
+}
+
+impl<EXPR> RuleStep<EXPR> for Pred<EXPR>
+where
+    EXPR: RuleStep<EXPR> + AsNat + Sum<Self> + Sum<Nat>,
+{
+    fn rule_step(&self, strategy: ReductionStrategy) -> Result<(EXPR, StepRule), CalcStepError> {
+        match self.expr.as_nat() {
+            // `pred 0` is `0`
+            Some(n) => Ok((Nat::new_expr(n.saturating_sub(1)), StepRule::Delta)),
+            None => self
+                .expr
+                .rule_step(strategy)
+                .map(|(expr, rule)| (Self::new_expr(expr), rule)),
+        }
+    }
+}
+
+impl<VAR, EXPR> Substitution<VAR, EXPR> for Pred<EXPR>
+where
+    EXPR: Substitution<VAR, EXPR> + Sum<Self>,
+{
+    fn substitute(&self, v: &VAR, e: &EXPR) -> EXPR {
+        Self::new_expr(self.expr.substitute(v, e))
+    }
+}
+
+impl<VAR, EXPR> FreeVars<VAR> for Pred<EXPR>
+where
+    EXPR: FreeVars<VAR>,
+{
+    fn free_vars(&self) -> BTreeSet<VAR> {
+        self.expr.free_vars()
+    }
+}
@@ -0,0 +1,78 @@
+use super::{nat::Nat, AsNat};
+use crate::annotation::StepRule;
+use crate::expressions::untyped_lambda_calculus::app::IsAtomic;
+use crate::expressions::{CalcStepError, FreeVars, ReductionStrategy, RuleStep, Substitution, Sum};
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub struct Succ<EXPR> {
+    pub(crate) expr: Box<EXPR>,
+}
+
+impl<EXPR> Display for Succ<EXPR>
+where
+    EXPR: Display + IsAtomic,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.expr.is_atomic() {
+            write!(f, "succ {}", self.expr)
+        } else {
+            write!(f, "succ ({})", self.expr)
+        }
+    }
+}
+
+impl<EXPR> Succ<EXPR> {
+    pub fn new(expr: EXPR) -> Self {
+        Self {
+            expr: Box::new(expr),
+        }
+    }
+    pub fn new_expr<SUM: Sum<Self>>(expr: EXPR) -> SUM {
+        SUM::pack(Self::new(expr))
+    }
+    pub fn into_expr<SUM: Sum<Self>>(self) -> SUM {
+        SUM::pack(self)
+    }
+    pub fn expr(&self) -> &EXPR {
+        &self.expr
+    }
+}
+
+// The argument is evaluated under every strategy, since `succ` needs a literal to fire.
+impl<EXPR> RuleStep<EXPR> for Succ<EXPR>
+where
+    EXPR: RuleStep<EXPR> + AsNat + Sum<Self> + Sum<Nat>,
+{
+    fn rule_step(&self, strategy: ReductionStrategy) -> Result<(EXPR, StepRule), CalcStepError> {
+        match self.expr.as_nat() {
+            Some(n) => n
+                .checked_add(1)
+                .map(|n| (Nat::new_expr(n), StepRule::Delta))
+                .ok_or(CalcStepError::Overflow),
+            None => self
+                .expr
+                .rule_step(strategy)
+                .map(|(expr, rule)| (Self::new_expr(expr), rule)),
+        }
+    }
+}
+
+impl<VAR, EXPR> Substitution<VAR, EXPR> for Succ<EXPR>
+where
+    EXPR: Substitution<VAR, EXPR> + Sum<Self>,
+{
+    fn substitute(&self, v: &VAR, e: &EXPR) -> EXPR {
+        Self::new_expr(self.expr.substitute(v, e))
+    }
+}
+
+impl<VAR, EXPR> FreeVars<VAR> for Succ<EXPR>
+where
+    EXPR: FreeVars<VAR>,
+{
+    fn free_vars(&self) -> BTreeSet<VAR> {
+        self.expr.free_vars()
+    }
+}
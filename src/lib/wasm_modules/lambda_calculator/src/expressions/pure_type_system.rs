@@ -0,0 +1,201 @@
+use self::{
+    pi::{binder_free_vars, step_binder, substitute_binder, Pi},
+    sort::Sort,
+};
+use super::simply_typed_lambda_calculus::typed_abs::TypedAbs;
+use super::untyped_lambda_calculus::{
+    app::{App, ApplyAbs, IsAbs, IsAtomic},
+    unty_var::UntyVar,
+};
+use super::{CalcStepError, Expression, FreeVars, ReductionStrategy, Substitution, Sum};
+use std::collections::BTreeSet;
+use std::fmt::{Debug, Display};
+pub mod pi;
+pub mod sort;
+
+// Terms, types and kinds share one syntax, so the annotation of a `λ` is a term as well.
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub enum PtsExpr {
+    Var(UntyVar),
+    Sort(Sort),
+    Pi(Pi<Self>),
+    Abs(TypedAbs<Box<Self>, Self>),
+    App(App<Self>),
+}
+
+impl PtsExpr {
+    /// Equality up to the names of bound variables.
+    pub fn alpha_eq(&self, other: &Self) -> bool {
+        self.alpha_eq_in(other, &mut vec![])
+    }
+
+    fn alpha_eq_in(&self, other: &Self, bound: &mut Vec<(UntyVar, UntyVar)>) -> bool {
+        match (self, other) {
+            (PtsExpr::Var(a), PtsExpr::Var(b)) => {
+                match bound.iter().rev().find(|(x, y)| x == a || y == b) {
+                    Some((x, y)) => x == a && y == b,
+                    None => a == b,
+                }
+            }
+            (PtsExpr::Sort(a), PtsExpr::Sort(b)) => a == b,
+            (PtsExpr::Pi(a), PtsExpr::Pi(b)) => {
+                Self::binder_eq((&a.var, &a.ty, &a.body), (&b.var, &b.ty, &b.body), bound)
+            }
+            (PtsExpr::Abs(a), PtsExpr::Abs(b)) => {
+                Self::binder_eq((&a.var, &a.ty, &a.expr), (&b.var, &b.ty, &b.expr), bound)
+            }
+            (PtsExpr::App(a), PtsExpr::App(b)) => {
+                a.lhs.alpha_eq_in(&b.lhs, bound) && a.rhs.alpha_eq_in(&b.rhs, bound)
+            }
+            _ => false,
+        }
+    }
+
+    fn binder_eq(
+        (x, ty_a, body_a): (&UntyVar, &Self, &Self),
+        (y, ty_b, body_b): (&UntyVar, &Self, &Self),
+        bound: &mut Vec<(UntyVar, UntyVar)>,
+    ) -> bool {
+        if !ty_a.alpha_eq_in(ty_b, bound) {
+            return false;
+        }
+        bound.push((x.clone(), y.clone()));
+        let eq = body_a.alpha_eq_in(body_b, bound);
+        bound.pop();
+        eq
+    }
+}
+
+impl Sum<UntyVar> for PtsExpr {
+    fn pack(content: UntyVar) -> Self {
+        Self::Var(content)
+    }
+}
+impl Sum<Sort> for PtsExpr {
+    fn pack(content: Sort) -> Self {
+        Self::Sort(content)
+    }
+}
+impl Sum<Pi<Self>> for PtsExpr {
+    fn pack(content: Pi<Self>) -> Self {
+        Self::Pi(content)
+    }
+}
+impl Sum<TypedAbs<Box<Self>, Self>> for PtsExpr {
+    fn pack(content: TypedAbs<Box<Self>, Self>) -> Self {
+        Self::Abs(content)
+    }
+}
+impl Sum<App<Self>> for PtsExpr {
+    fn pack(content: App<Self>) -> Self {
+        Self::App(content)
+    }
+}
+impl Expression<Self> for PtsExpr {
+    fn is_value(&self) -> bool {
+        match self {
+            PtsExpr::Var(e) => <UntyVar as Expression<Self>>::is_value(e),
+            PtsExpr::Sort(e) => <Sort as Expression<Self>>::is_value(e),
+            PtsExpr::Pi(e) => e.is_value(),
+            PtsExpr::Abs(e) => e.is_value(),
+            PtsExpr::App(e) => e.is_value(),
+        }
+    }
+
+    fn calc_step(&self, strategy: ReductionStrategy) -> Result<Self, CalcStepError> {
+        match self {
+            PtsExpr::Var(e) => e.calc_step(strategy),
+            PtsExpr::Sort(e) => e.calc_step(strategy),
+            PtsExpr::Pi(e) => e.calc_step(strategy),
+            PtsExpr::Abs(e) => step_binder(e.ty.as_ref(), &e.expr, strategy)
+                .map(|(ty, body)| TypedAbs::new_expr(e.var.clone(), Box::new(ty), body)),
+            PtsExpr::App(e) => e.calc_step(strategy),
+        }
+    }
+}
+impl Substitution<UntyVar, Self> for PtsExpr {
+    fn substitute(&self, v: &UntyVar, e: &Self) -> Self {
+        match self {
+            PtsExpr::Var(exp) => exp.substitute(v, e),
+            PtsExpr::Sort(exp) => exp.substitute(v, e),
+            PtsExpr::Pi(exp) => exp.substitute(v, e),
+            PtsExpr::Abs(exp) => {
+                let (var, ty, body) = substitute_binder(&exp.var, exp.ty.as_ref(), &exp.expr, v, e);
+                TypedAbs::new_expr(var, Box::new(ty), body)
+            }
+            PtsExpr::App(exp) => exp.substitute(v, e),
+        }
+    }
+}
+impl FreeVars<UntyVar> for PtsExpr {
+    fn free_vars(&self) -> BTreeSet<UntyVar> {
+        match self {
+            PtsExpr::Var(exp) => exp.free_vars(),
+            PtsExpr::Sort(exp) => exp.free_vars(),
+            PtsExpr::Pi(exp) => exp.free_vars(),
+            PtsExpr::Abs(exp) => binder_free_vars(&exp.var, exp.ty.as_ref(), &exp.expr),
+            PtsExpr::App(exp) => exp.free_vars(),
+        }
+    }
+}
+
+impl IsAbs for PtsExpr {
+    fn is_abs(&self) -> bool {
+        matches!(self, Self::Abs(_) | Self::Pi(_))
+    }
+}
+
+impl IsAtomic for PtsExpr {
+    fn is_atomic(&self) -> bool {
+        matches!(self, Self::Var(_) | Self::Sort(_))
+    }
+}
+
+impl ApplyAbs<Self> for PtsExpr {
+    fn apply_abs(&self, arg: &Self) -> Option<Self> {
+        match self {
+            PtsExpr::Abs(abs) => Some(abs.apply(arg)),
+            _ => None,
+        }
+    }
+}
+
+impl Display for PtsExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PtsExpr::Var(e) => Display::fmt(e, f),
+            PtsExpr::Sort(e) => Display::fmt(e, f),
+            PtsExpr::Pi(e) => Display::fmt(e, f),
+            PtsExpr::Abs(e) => Display::fmt(e, f),
+            PtsExpr::App(e) => Display::fmt(e, f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expressions::{Expression, ReductionStrategy};
+    use crate::parsers::pure_type_system::PureTypeSystemParser;
+
+    #[test]
+    fn reduces_annotations() {
+        let mut expr = PureTypeSystemParser::parse("λB:*. (λA:*. λx:(λT:*. T) A. x) B").unwrap();
+        let mut steps = vec![];
+        while let Ok(next) = expr.calc_step(ReductionStrategy::NormalOrder) {
+            steps.push(next.to_string());
+            expr = next;
+        }
+        assert_eq!(
+            steps,
+            vec!["λ B: *. λ x: (λ T: *. T) B. x", "λ B: *. λ x: B. x"]
+        );
+    }
+
+    #[test]
+    fn alpha_equivalence() {
+        let parse = |input| PureTypeSystemParser::parse(input).unwrap();
+        assert!(parse("Π A: *. A → A").alpha_eq(&parse("Π B: *. Π y: B. B")));
+        assert!(!parse("Π A: *. Π B: *. A").alpha_eq(&parse("Π A: *. Π B: *. B")));
+        assert!(!parse("λx:*. y").alpha_eq(&parse("λy:*. y")));
+    }
+}
@@ -0,0 +1,158 @@
+use super::super::untyped_lambda_calculus::{app::IsAbs, unty_var::UntyVar};
+use super::super::{
+    or_next, CalcStepError, Expression, FreeVars, ReductionStrategy, Substitution, Sum,
+};
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub struct Pi<EXPR> {
+    pub(crate) var: UntyVar,
+    pub(crate) ty: Box<EXPR>,
+    pub(crate) body: Box<EXPR>,
+}
+
+// A product whose variable does not occur in the body is shown as an arrow.
+impl<EXPR> Display for Pi<EXPR>
+where
+    EXPR: Display + FreeVars<UntyVar> + IsAbs,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.body.free_vars().contains(&self.var) {
+            write!(f, "Π {}: {}. {}", self.var, self.ty, self.body)
+        } else if self.ty.is_abs() {
+            write!(f, "({}) → {}", self.ty, self.body)
+        } else {
+            write!(f, "{} → {}", self.ty, self.body)
+        }
+    }
+}
+
+impl<EXPR> Pi<EXPR> {
+    pub fn new(var: UntyVar, ty: EXPR, body: EXPR) -> Self {
+        Self {
+            var,
+            ty: Box::new(ty),
+            body: Box::new(body),
+        }
+    }
+    pub fn new_expr<SUM: Sum<Self>>(var: UntyVar, ty: EXPR, body: EXPR) -> SUM {
+        SUM::pack(Self::new(var, ty, body))
+    }
+    /// `A → B`, a product the body does not depend on.
+    pub fn arrow<SUM: Sum<Self>>(ty: EXPR, body: EXPR) -> SUM {
+        Self::new_expr(UntyVar::new("_"), ty, body)
+    }
+    pub fn into_expr<SUM: Sum<Self>>(self) -> SUM {
+        SUM::pack(self)
+    }
+    pub fn var(&self) -> &UntyVar {
+        &self.var
+    }
+    pub fn ty(&self) -> &EXPR {
+        &self.ty
+    }
+    pub fn body(&self) -> &EXPR {
+        &self.body
+    }
+}
+
+impl<EXPR> Pi<EXPR>
+where
+    EXPR: Substitution<UntyVar, EXPR>,
+{
+    pub fn apply(&self, arg: &EXPR) -> EXPR {
+        self.body.substitute(&self.var, arg)
+    }
+}
+
+// Binders whose annotation is itself a term, shared by `Π` and `λ` in calculi where types and
+// terms are one syntax: substitution and reduction have to enter the annotation as well.
+
+pub(crate) fn substitute_binder<EXPR>(
+    var: &UntyVar,
+    ty: &EXPR,
+    body: &EXPR,
+    v: &UntyVar,
+    e: &EXPR,
+) -> (UntyVar, EXPR, EXPR)
+where
+    EXPR: Substitution<UntyVar, EXPR> + FreeVars<UntyVar> + Sum<UntyVar> + Clone,
+{
+    let ty = ty.substitute(v, e);
+    if var == v || !body.free_vars().contains(v) {
+        return (var.clone(), ty, body.clone());
+    }
+    let free_in_e = e.free_vars();
+    if free_in_e.contains(var) {
+        let mut avoid = free_in_e;
+        avoid.extend(body.free_vars());
+        let fresh = var.fresh(&avoid);
+        let body = body.substitute(var, &fresh.clone().into_expr());
+        let body = body.substitute(v, e);
+        (fresh, ty, body)
+    } else {
+        (var.clone(), ty, body.substitute(v, e))
+    }
+}
+
+pub(crate) fn binder_free_vars<EXPR>(var: &UntyVar, ty: &EXPR, body: &EXPR) -> BTreeSet<UntyVar>
+where
+    EXPR: FreeVars<UntyVar>,
+{
+    let mut vars = body.free_vars();
+    vars.remove(var);
+    vars.extend(ty.free_vars());
+    vars
+}
+
+/// Reduces the annotation before the body; returns the new annotation and body.
+pub(crate) fn step_binder<EXPR>(
+    ty: &EXPR,
+    body: &EXPR,
+    strategy: ReductionStrategy,
+) -> Result<(EXPR, EXPR), CalcStepError>
+where
+    EXPR: Expression<EXPR> + Clone,
+{
+    if !strategy.reduces_under_abs() {
+        return Err(CalcStepError::NoRedex);
+    }
+    or_next(ty.calc_step(strategy).map(|ty| (ty, body.clone())), || {
+        body.calc_step(strategy).map(|body| (ty.clone(), body))
+    })
+}
+
+impl<EXPR> Expression<EXPR> for Pi<EXPR>
+where
+    EXPR: Expression<EXPR> + Sum<Self> + Clone,
+{
+    fn is_value(&self) -> bool {
+        true
+    }
+
+    fn calc_step(&self, strategy: ReductionStrategy) -> Result<EXPR, CalcStepError> {
+        step_binder(self.ty.as_ref(), self.body.as_ref(), strategy)
+            .map(|(ty, body)| Self::new_expr(self.var.clone(), ty, body))
+    }
+}
+
+impl<EXPR> Substitution<UntyVar, EXPR> for Pi<EXPR>
+where
+    EXPR: Substitution<UntyVar, EXPR> + FreeVars<UntyVar> + Sum<UntyVar> + Sum<Self> + Clone,
+{
+    fn substitute(&self, v: &UntyVar, e: &EXPR) -> EXPR {
+        let (var, ty, body) =
+            substitute_binder(&self.var, self.ty.as_ref(), self.body.as_ref(), v, e);
+        Self::new_expr(var, ty, body)
+    }
+}
+
+impl<EXPR> FreeVars<UntyVar> for Pi<EXPR>
+where
+    EXPR: FreeVars<UntyVar>,
+{
+    fn free_vars(&self) -> BTreeSet<UntyVar> {
+        binder_free_vars(&self.var, self.ty.as_ref(), self.body.as_ref())
+    }
+}
@@ -0,0 +1,62 @@
+use super::super::{CalcStepError, Expression, FreeVars, ReductionStrategy, Substitution, Sum};
+use crate::expressions::untyped_lambda_calculus::unty_var::UntyVar;
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub struct Sort {
+    name: String,
+}
+
+impl Display for Sort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl Sort {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+        }
+    }
+    /// `*`, the sort of types.
+    pub fn star() -> Self {
+        Self::new("*")
+    }
+    /// `□`, the sort of kinds.
+    pub fn boxed() -> Self {
+        Self::new("□")
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn into_expr<EXPR: Sum<Self>>(self) -> EXPR {
+        EXPR::pack(self)
+    }
+}
+
+impl<EXPR> Expression<EXPR> for Sort {
+    fn is_value(&self) -> bool {
+        true
+    }
+
+    fn calc_step(&self, _strategy: ReductionStrategy) -> Result<EXPR, CalcStepError> {
+        Err(CalcStepError::NoRedex)
+    }
+}
+
+impl<EXPR> Substitution<UntyVar, EXPR> for Sort
+where
+    EXPR: Sum<Self>,
+{
+    fn substitute(&self, _v: &UntyVar, _e: &EXPR) -> EXPR {
+        self.clone().into_expr()
+    }
+}
+
+impl FreeVars<UntyVar> for Sort {
+    fn free_vars(&self) -> BTreeSet<UntyVar> {
+        BTreeSet::new()
+    }
+}
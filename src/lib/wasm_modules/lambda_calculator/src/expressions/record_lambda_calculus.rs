@@ -0,0 +1,168 @@
+use self::{
+    field::Field,
+    record::{AsRecord, Record},
+    record_type::RecordType,
+};
+use super::simply_typed_lambda_calculus::typed_abs::TypedAbs;
+use super::untyped_lambda_calculus::{
+    app::{App, ApplyAbs, IsAbs, IsAtomic},
+    unty_var::UntyVar,
+};
+use super::{CalcStepError, Expression, FreeVars, ReductionStrategy, Substitution, Sum};
+use std::collections::BTreeSet;
+use std::fmt::{Debug, Display};
+pub mod field;
+pub mod record;
+pub mod record_type;
+
+/// The simply typed lambda calculus with records, checked with structural subtyping.
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub enum RecordLamExpr {
+    Var(UntyVar),
+    Abs(TypedAbs<RecordType, Self>),
+    App(App<Self>),
+    Record(Record<Self>),
+    Field(Field<Self>),
+}
+
+impl Sum<UntyVar> for RecordLamExpr {
+    fn pack(content: UntyVar) -> Self {
+        Self::Var(content)
+    }
+}
+impl Sum<TypedAbs<RecordType, Self>> for RecordLamExpr {
+    fn pack(content: TypedAbs<RecordType, Self>) -> Self {
+        Self::Abs(content)
+    }
+}
+impl Sum<App<Self>> for RecordLamExpr {
+    fn pack(content: App<Self>) -> Self {
+        Self::App(content)
+    }
+}
+impl Sum<Record<Self>> for RecordLamExpr {
+    fn pack(content: Record<Self>) -> Self {
+        Self::Record(content)
+    }
+}
+impl Sum<Field<Self>> for RecordLamExpr {
+    fn pack(content: Field<Self>) -> Self {
+        Self::Field(content)
+    }
+}
+impl Expression<Self> for RecordLamExpr {
+    fn is_value(&self) -> bool {
+        match self {
+            RecordLamExpr::Var(e) => <UntyVar as Expression<Self>>::is_value(e),
+            RecordLamExpr::Abs(e) => e.is_value(),
+            RecordLamExpr::App(e) => e.is_value(),
+            RecordLamExpr::Record(e) => e.is_value(),
+            RecordLamExpr::Field(e) => e.is_value(),
+        }
+    }
+
+    fn calc_step(&self, strategy: ReductionStrategy) -> Result<Self, CalcStepError> {
+        match self {
+            RecordLamExpr::Var(e) => e.calc_step(strategy),
+            RecordLamExpr::Abs(e) => e.calc_step(strategy),
+            RecordLamExpr::App(e) => e.calc_step(strategy),
+            RecordLamExpr::Record(e) => e.calc_step(strategy),
+            RecordLamExpr::Field(e) => e.calc_step(strategy),
+        }
+    }
+}
+impl Substitution<UntyVar, Self> for RecordLamExpr {
+    fn substitute(&self, v: &UntyVar, e: &Self) -> Self {
+        match self {
+            RecordLamExpr::Var(exp) => exp.substitute(v, e),
+            RecordLamExpr::Abs(exp) => exp.substitute(v, e),
+            RecordLamExpr::App(exp) => exp.substitute(v, e),
+            RecordLamExpr::Record(exp) => exp.substitute(v, e),
+            RecordLamExpr::Field(exp) => exp.substitute(v, e),
+        }
+    }
+}
+impl FreeVars<UntyVar> for RecordLamExpr {
+    fn free_vars(&self) -> BTreeSet<UntyVar> {
+        match self {
+            RecordLamExpr::Var(exp) => exp.free_vars(),
+            RecordLamExpr::Abs(exp) => exp.free_vars(),
+            RecordLamExpr::App(exp) => exp.free_vars(),
+            RecordLamExpr::Record(exp) => exp.free_vars(),
+            RecordLamExpr::Field(exp) => exp.free_vars(),
+        }
+    }
+}
+
+impl AsRecord<Self> for RecordLamExpr {
+    fn as_record(&self) -> Option<&Record<Self>> {
+        match self {
+            RecordLamExpr::Record(record) => Some(record),
+            _ => None,
+        }
+    }
+}
+
+impl IsAbs for RecordLamExpr {
+    fn is_abs(&self) -> bool {
+        matches!(self, Self::Abs(_))
+    }
+}
+
+impl IsAtomic for RecordLamExpr {
+    fn is_atomic(&self) -> bool {
+        matches!(self, Self::Var(_) | Self::Record(_) | Self::Field(_))
+    }
+}
+
+impl ApplyAbs<Self> for RecordLamExpr {
+    fn apply_abs(&self, arg: &Self) -> Option<Self> {
+        match self {
+            RecordLamExpr::Abs(abs) => Some(abs.apply(arg)),
+            _ => None,
+        }
+    }
+}
+
+impl Display for RecordLamExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecordLamExpr::Var(e) => Display::fmt(e, f),
+            RecordLamExpr::Abs(e) => Display::fmt(e, f),
+            RecordLamExpr::App(e) => Display::fmt(e, f),
+            RecordLamExpr::Record(e) => Display::fmt(e, f),
+            RecordLamExpr::Field(e) => Display::fmt(e, f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expressions::{Expression, ReductionStrategy};
+    use crate::parsers::record_lambda_calculus::RecordLambdaCalculusParser;
+
+    #[test]
+    fn projections() {
+        let step = |input, strategy| {
+            let expr = RecordLambdaCalculusParser::parse(input).unwrap();
+            expr.calc_step(strategy).ok().map(|e| e.to_string())
+        };
+        let input = "{x = (λa: A. a) b, y = c}.y";
+        assert_eq!(
+            step(input, ReductionStrategy::NormalOrder),
+            Some("c".to_string())
+        );
+        assert_eq!(
+            step(input, ReductionStrategy::CallByValue),
+            Some("{x = b, y = c}.y".to_string())
+        );
+        assert_eq!(step("{x = a}.y", ReductionStrategy::NormalOrder), None);
+        assert_eq!(
+            step(
+                "(λr: {x: A}. r.x) {x = a, y = b}",
+                ReductionStrategy::CallByName
+            ),
+            Some("{x = a, y = b}.x".to_string())
+        );
+    }
+}
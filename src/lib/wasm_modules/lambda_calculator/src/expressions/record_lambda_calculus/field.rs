@@ -0,0 +1,98 @@
+use super::record::AsRecord;
+use crate::expressions::untyped_lambda_calculus::app::IsAtomic;
+use crate::expressions::{
+    CalcStepError, Expression, FreeVars, ReductionStrategy, Substitution, Sum,
+};
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+/// `e.label`, the projection of a record onto one field.
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub struct Field<EXPR> {
+    pub(crate) expr: Box<EXPR>,
+    pub(crate) label: String,
+}
+
+impl<EXPR> Display for Field<EXPR>
+where
+    EXPR: Display + IsAtomic,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.expr.is_atomic() {
+            write!(f, "{}.{}", self.expr, self.label)
+        } else {
+            write!(f, "({}).{}", self.expr, self.label)
+        }
+    }
+}
+
+impl<EXPR> Field<EXPR> {
+    pub fn new(expr: EXPR, label: &str) -> Self {
+        Self {
+            expr: Box::new(expr),
+            label: label.to_string(),
+        }
+    }
+    pub fn new_expr<SUM: Sum<Self>>(expr: EXPR, label: &str) -> SUM {
+        SUM::pack(Self::new(expr, label))
+    }
+    pub fn into_expr<SUM: Sum<Self>>(self) -> SUM {
+        SUM::pack(self)
+    }
+    pub fn expr(&self) -> &EXPR {
+        &self.expr
+    }
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+}
+
+impl<EXPR> Field<EXPR>
+where
+    EXPR: Expression<EXPR> + AsRecord<EXPR> + Sum<Self> + Clone,
+{
+    fn contract(&self) -> Result<EXPR, CalcStepError> {
+        self.expr
+            .as_record()
+            .and_then(|record| record.get(&self.label))
+            .cloned()
+            .ok_or(CalcStepError::NoRedex)
+    }
+
+    fn step_expr(&self, strategy: ReductionStrategy) -> Result<EXPR, CalcStepError> {
+        self.expr
+            .calc_step(strategy)
+            .map(|expr| Self::new_expr(expr, &self.label))
+    }
+}
+
+impl<EXPR> Expression<EXPR> for Field<EXPR>
+where
+    EXPR: Expression<EXPR> + AsRecord<EXPR> + Sum<Self> + Clone,
+{
+    fn is_value(&self) -> bool {
+        false
+    }
+
+    fn calc_step(&self, strategy: ReductionStrategy) -> Result<EXPR, CalcStepError> {
+        strategy.step_elim(|| self.contract(), || self.step_expr(strategy))
+    }
+}
+
+impl<VAR, EXPR> Substitution<VAR, EXPR> for Field<EXPR>
+where
+    EXPR: Substitution<VAR, EXPR> + Sum<Self>,
+{
+    fn substitute(&self, v: &VAR, e: &EXPR) -> EXPR {
+        Self::new_expr(self.expr.substitute(v, e), &self.label)
+    }
+}
+
+impl<VAR, EXPR> FreeVars<VAR> for Field<EXPR>
+where
+    EXPR: FreeVars<VAR>,
+{
+    fn free_vars(&self) -> BTreeSet<VAR> {
+        self.expr.free_vars()
+    }
+}
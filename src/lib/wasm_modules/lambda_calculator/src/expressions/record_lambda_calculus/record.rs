@@ -0,0 +1,100 @@
+use crate::expressions::{
+    CalcStepError, Expression, FreeVars, ReductionStrategy, Substitution, Sum,
+};
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+/// `{x = e, y = e}`. Labels are distinct; their order is the evaluation order.
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub struct Record<EXPR> {
+    pub(crate) fields: Vec<(String, EXPR)>,
+}
+
+impl<EXPR> Display for Record<EXPR>
+where
+    EXPR: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let fields: Vec<String> = self
+            .fields
+            .iter()
+            .map(|(label, e)| format!("{label} = {e}"))
+            .collect();
+        write!(f, "{{{}}}", fields.join(", "))
+    }
+}
+
+pub trait AsRecord<EXPR> {
+    fn as_record(&self) -> Option<&Record<EXPR>>;
+}
+
+impl<EXPR> Record<EXPR> {
+    pub fn new(fields: Vec<(String, EXPR)>) -> Self {
+        Self { fields }
+    }
+    pub fn new_expr<SUM: Sum<Self>>(fields: Vec<(String, EXPR)>) -> SUM {
+        SUM::pack(Self::new(fields))
+    }
+    pub fn into_expr<SUM: Sum<Self>>(self) -> SUM {
+        SUM::pack(self)
+    }
+    pub fn fields(&self) -> &[(String, EXPR)] {
+        &self.fields
+    }
+    pub fn get(&self, label: &str) -> Option<&EXPR> {
+        self.fields.iter().find(|(l, _)| l == label).map(|(_, e)| e)
+    }
+}
+
+// Like a pair: a value once every field is, and left alone by call-by-name.
+impl<EXPR> Expression<EXPR> for Record<EXPR>
+where
+    EXPR: Expression<EXPR> + Sum<Self> + Clone,
+{
+    fn is_value(&self) -> bool {
+        self.fields.iter().all(|(_, e)| e.is_value())
+    }
+
+    fn calc_step(&self, strategy: ReductionStrategy) -> Result<EXPR, CalcStepError> {
+        if strategy == ReductionStrategy::CallByName {
+            return Err(CalcStepError::NoRedex);
+        }
+        self.fields
+            .iter()
+            .enumerate()
+            .find_map(|(i, (_, e))| e.calc_step(strategy).ok().map(|e| (i, e)))
+            .map(|(i, e)| {
+                let mut fields = self.fields.clone();
+                fields[i].1 = e;
+                Self::new_expr(fields)
+            })
+            .ok_or(CalcStepError::NoRedex)
+    }
+}
+
+impl<VAR, EXPR> Substitution<VAR, EXPR> for Record<EXPR>
+where
+    EXPR: Substitution<VAR, EXPR> + Sum<Self>,
+{
+    fn substitute(&self, v: &VAR, e: &EXPR) -> EXPR {
+        Self::new_expr(
+            self.fields
+                .iter()
+                .map(|(label, field)| (label.clone(), field.substitute(v, e)))
+                .collect(),
+        )
+    }
+}
+
+impl<VAR, EXPR> FreeVars<VAR> for Record<EXPR>
+where
+    VAR: Ord,
+    EXPR: FreeVars<VAR>,
+{
+    fn free_vars(&self) -> BTreeSet<VAR> {
+        self.fields
+            .iter()
+            .flat_map(|(_, e)| e.free_vars())
+            .collect()
+    }
+}
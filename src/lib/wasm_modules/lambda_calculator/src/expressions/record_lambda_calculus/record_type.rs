@@ -0,0 +1,55 @@
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub enum RecordType {
+    Base(String),
+    /// The supertype of every type.
+    Top,
+    Arrow(Box<RecordType>, Box<RecordType>),
+    /// The order of the fields is only kept for printing.
+    Record(Vec<(String, RecordType)>),
+}
+
+impl RecordType {
+    pub fn base(name: &str) -> Self {
+        Self::Base(name.to_string())
+    }
+    pub fn arrow(from: Self, to: Self) -> Self {
+        Self::Arrow(Box::new(from), Box::new(to))
+    }
+    pub fn record(fields: Vec<(&str, Self)>) -> Self {
+        Self::Record(
+            fields
+                .into_iter()
+                .map(|(label, ty)| (label.to_string(), ty))
+                .collect(),
+        )
+    }
+
+    pub fn field(&self, label: &str) -> Option<&Self> {
+        match self {
+            RecordType::Record(fields) => fields.iter().find(|(l, _)| l == label).map(|(_, t)| t),
+            _ => None,
+        }
+    }
+}
+
+impl Display for RecordType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecordType::Base(name) => write!(f, "{name}"),
+            RecordType::Top => write!(f, "Top"),
+            RecordType::Arrow(from, to) => match **from {
+                RecordType::Arrow(_, _) => write!(f, "({from}) → {to}"),
+                _ => write!(f, "{from} → {to}"),
+            },
+            RecordType::Record(fields) => {
+                let fields: Vec<String> = fields
+                    .iter()
+                    .map(|(label, ty)| format!("{label}: {ty}"))
+                    .collect();
+                write!(f, "{{{}}}", fields.join(", "))
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use self::{simple_type::SimpleType, typed_abs::TypedAbs};
+use super::untyped_lambda_calculus::{
+    app::{App, ApplyAbs, IsAbs, IsAtomic},
+    unty_var::UntyVar,
+};
+use super::{CalcStepError, Expression, FreeVars, ReductionStrategy, Substitution, Sum};
+use std::collections::BTreeSet;
+use std::fmt::{Debug, Display};
+pub mod simple_type;
+pub mod typed_abs;
+
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub enum SimplyTypedExpr {
+    Var(UntyVar),
+    Abs(TypedAbs<SimpleType, Self>),
+    App(App<Self>),
+}
+
+impl Sum<UntyVar> for SimplyTypedExpr {
+    fn pack(content: UntyVar) -> Self {
+        Self::Var(content)
+    }
+}
+impl Sum<TypedAbs<SimpleType, Self>> for SimplyTypedExpr {
+    fn pack(content: TypedAbs<SimpleType, Self>) -> Self {
+        Self::Abs(content)
+    }
+}
+impl Sum<App<Self>> for SimplyTypedExpr {
+    fn pack(content: App<Self>) -> Self {
+        Self::App(content)
+    }
+}
+impl SimplyTypedExpr {
+    pub fn is_normal_form(&self, strategy: ReductionStrategy) -> bool {
+        self.calc_step(strategy).is_err()
+    }
+}
+impl Expression<Self> for SimplyTypedExpr {
+    fn is_value(&self) -> bool {
+        match self {
+            SimplyTypedExpr::Var(e) => <UntyVar as Expression<Self>>::is_value(e),
+            SimplyTypedExpr::Abs(e) => e.is_value(),
+            SimplyTypedExpr::App(e) => e.is_value(),
+        }
+    }
+
+    fn calc_step(&self, strategy: ReductionStrategy) -> Result<Self, CalcStepError> {
+        match self {
+            SimplyTypedExpr::Var(e) => e.calc_step(strategy),
+            SimplyTypedExpr::Abs(e) => e.calc_step(strategy),
+            SimplyTypedExpr::App(e) => e.calc_step(strategy),
+        }
+    }
+}
+impl Substitution<UntyVar, Self> for SimplyTypedExpr {
+    fn substitute(&self, v: &UntyVar, e: &Self) -> Self {
+        match self {
+            SimplyTypedExpr::Var(exp) => exp.substitute(v, e),
+            SimplyTypedExpr::Abs(exp) => exp.substitute(v, e),
+            SimplyTypedExpr::App(exp) => exp.substitute(v, e),
+        }
+    }
+}
+impl FreeVars<UntyVar> for SimplyTypedExpr {
+    fn free_vars(&self) -> BTreeSet<UntyVar> {
+        match self {
+            SimplyTypedExpr::Var(exp) => exp.free_vars(),
+            SimplyTypedExpr::Abs(exp) => exp.free_vars(),
+            SimplyTypedExpr::App(exp) => exp.free_vars(),
+        }
+    }
+}
+
+impl IsAbs for SimplyTypedExpr {
+    fn is_abs(&self) -> bool {
+        matches!(self, Self::Abs(_))
+    }
+}
+
+impl IsAtomic for SimplyTypedExpr {
+    fn is_atomic(&self) -> bool {
+        matches!(self, Self::Var(_))
+    }
+}
+
+impl ApplyAbs<Self> for SimplyTypedExpr {
+    fn apply_abs(&self, arg: &Self) -> Option<Self> {
+        match self {
+            SimplyTypedExpr::Abs(abs) => Some(abs.apply(arg)),
+            _ => None,
+        }
+    }
+}
+
+impl Display for SimplyTypedExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SimplyTypedExpr::Var(e) => Display::fmt(e, f),
+            SimplyTypedExpr::Abs(e) => Display::fmt(e, f),
+            SimplyTypedExpr::App(e) => Display::fmt(e, f),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub enum SimpleType {
+    Base(String),
+    Arrow(Box<SimpleType>, Box<SimpleType>),
+}
+
+impl SimpleType {
+    pub fn base(name: &str) -> Self {
+        Self::Base(name.to_string())
+    }
+    pub fn arrow(from: Self, to: Self) -> Self {
+        Self::Arrow(Box::new(from), Box::new(to))
+    }
+}
+
+impl Display for SimpleType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SimpleType::Base(name) => write!(f, "{name}"),
+            SimpleType::Arrow(from, to) => match **from {
+                SimpleType::Arrow(_, _) => write!(f, "({from}) → {to}"),
+                SimpleType::Base(_) => write!(f, "{from} → {to}"),
+            },
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use crate::expressions::untyped_lambda_calculus::unty_var::UntyVar;
+use crate::expressions::{FreeVars, ReductionStrategy, Substitution};
+
+use super::super::CalcStepError;
+use super::super::Expression;
+use super::super::Sum;
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub struct TypedAbs<TY, EXPR> {
+    pub(crate) var: UntyVar,
+    pub(crate) ty: TY,
+    pub(crate) expr: Box<EXPR>,
+}
+
+impl<TY, EXPR> Display for TypedAbs<TY, EXPR>
+where
+    TY: Display,
+    EXPR: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "λ {}: {}. {}", self.var, self.ty, self.expr)
+    }
+}
+
+impl<TY, EXPR> TypedAbs<TY, EXPR> {
+    pub fn new(var: UntyVar, ty: TY, expr: EXPR) -> Self {
+        Self {
+            var,
+            ty,
+            expr: Box::new(expr),
+        }
+    }
+    pub fn new_expr<SUM: Sum<Self>>(var: UntyVar, ty: TY, expr: EXPR) -> SUM {
+        SUM::pack(Self::new(var, ty, expr))
+    }
+    pub fn into_expr<SUM: Sum<Self>>(self) -> SUM {
+        SUM::pack(self)
+    }
+    pub fn var(&self) -> &UntyVar {
+        &self.var
+    }
+    pub fn ty(&self) -> &TY {
+        &self.ty
+    }
+    pub fn body(&self) -> &EXPR {
+        &self.expr
+    }
+}
+
+impl<TY, EXPR> TypedAbs<TY, EXPR>
+where
+    EXPR: Substitution<UntyVar, EXPR>,
+{
+    pub fn apply(&self, arg: &EXPR) -> EXPR {
+        self.expr.substitute(&self.var, arg)
+    }
+}
+
+impl<TY, EXPR> TypedAbs<TY, EXPR>
+where
+    TY: Clone,
+    EXPR: Substitution<UntyVar, EXPR> + FreeVars<UntyVar> + Sum<UntyVar>,
+{
+    pub fn rename(&self, avoid: &BTreeSet<UntyVar>) -> Self {
+        let mut avoid = avoid.clone();
+        avoid.extend(self.expr.free_vars());
+        let fresh = self.var.fresh(&avoid);
+        Self {
+            expr: Box::new(self.expr.substitute(&self.var, &fresh.clone().into_expr())),
+            var: fresh,
+            ty: self.ty.clone(),
+        }
+    }
+}
+
+impl<TY, EXPR> Expression<EXPR> for TypedAbs<TY, EXPR>
+where
+    TY: Clone,
+    EXPR: Expression<EXPR> + Sum<Self>,
+{
+    fn is_value(&self) -> bool {
+        true
+    }
+
+    fn calc_step(&self, strategy: ReductionStrategy) -> Result<EXPR, CalcStepError> {
+        if !strategy.reduces_under_abs() {
+            return Err(CalcStepError::NoRedex);
+        }
+        self.expr
+            .calc_step(strategy)
+            .map(|expr| Self::new_expr(self.var.clone(), self.ty.clone(), expr))
+    }
+}
+
+impl<TY, EXPR> Substitution<UntyVar, EXPR> for TypedAbs<TY, EXPR>
+where
+    TY: Clone,
+    EXPR: Substitution<UntyVar, EXPR> + FreeVars<UntyVar> + Sum<Self> + Sum<UntyVar> + Clone,
+{
+    fn substitute(&self, v: &UntyVar, e: &EXPR) -> EXPR {
+        if self.var == *v || !self.expr.free_vars().contains(v) {
+            return self.clone().into_expr();
+        }
+        let free_in_e = e.free_vars();
+        let abs = if free_in_e.contains(&self.var) {
+            self.rename(&free_in_e)
+        } else {
+            self.clone()
+        };
+        Self::new_expr(abs.var, abs.ty, abs.expr.substitute(v, e))
+    }
+}
+
+impl<TY, EXPR> FreeVars<UntyVar> for TypedAbs<TY, EXPR>
+where
+    EXPR: FreeVars<UntyVar>,
+{
+    fn free_vars(&self) -> BTreeSet<UntyVar> {
+        let mut vars = self.expr.free_vars();
+        vars.remove(&self.var);
+        vars
+    }
+}
@@ -0,0 +1,222 @@
+use self::{
+    poly_type::{PolyType, TyVar},
+    ty_abs::TyAbs,
+    ty_app::{ApplyTyAbs, TyApp},
+};
+use super::simply_typed_lambda_calculus::typed_abs::TypedAbs;
+use super::untyped_lambda_calculus::{
+    app::{App, ApplyAbs, IsAbs, IsAtomic},
+    unty_var::UntyVar,
+};
+use super::{
+    CalcStepError, Expression, FreeVars, ReductionStrategy, Substitution, Sum, TypeSubstitution,
+};
+use std::collections::BTreeSet;
+use std::fmt::{Debug, Display};
+pub mod poly_type;
+pub mod ty_abs;
+pub mod ty_app;
+
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub enum SystemFExpr {
+    Var(UntyVar),
+    Abs(TypedAbs<PolyType, Self>),
+    App(App<Self>),
+    TyAbs(TyAbs<Self>),
+    TyApp(TyApp<Self>),
+}
+
+impl Sum<UntyVar> for SystemFExpr {
+    fn pack(content: UntyVar) -> Self {
+        Self::Var(content)
+    }
+}
+impl Sum<TypedAbs<PolyType, Self>> for SystemFExpr {
+    fn pack(content: TypedAbs<PolyType, Self>) -> Self {
+        Self::Abs(content)
+    }
+}
+impl Sum<App<Self>> for SystemFExpr {
+    fn pack(content: App<Self>) -> Self {
+        Self::App(content)
+    }
+}
+impl Sum<TyAbs<Self>> for SystemFExpr {
+    fn pack(content: TyAbs<Self>) -> Self {
+        Self::TyAbs(content)
+    }
+}
+impl Sum<TyApp<Self>> for SystemFExpr {
+    fn pack(content: TyApp<Self>) -> Self {
+        Self::TyApp(content)
+    }
+}
+impl SystemFExpr {
+    pub fn is_normal_form(&self, strategy: ReductionStrategy) -> bool {
+        self.calc_step(strategy).is_err()
+    }
+}
+impl Expression<Self> for SystemFExpr {
+    fn is_value(&self) -> bool {
+        match self {
+            SystemFExpr::Var(e) => <UntyVar as Expression<Self>>::is_value(e),
+            SystemFExpr::Abs(e) => e.is_value(),
+            SystemFExpr::App(e) => e.is_value(),
+            SystemFExpr::TyAbs(e) => e.is_value(),
+            SystemFExpr::TyApp(e) => e.is_value(),
+        }
+    }
+
+    fn calc_step(&self, strategy: ReductionStrategy) -> Result<Self, CalcStepError> {
+        match self {
+            SystemFExpr::Var(e) => e.calc_step(strategy),
+            SystemFExpr::Abs(e) => e.calc_step(strategy),
+            SystemFExpr::App(e) => e.calc_step(strategy),
+            SystemFExpr::TyAbs(e) => e.calc_step(strategy),
+            SystemFExpr::TyApp(e) => e.calc_step(strategy),
+        }
+    }
+}
+impl Substitution<UntyVar, Self> for SystemFExpr {
+    fn substitute(&self, v: &UntyVar, e: &Self) -> Self {
+        match self {
+            SystemFExpr::Var(exp) => exp.substitute(v, e),
+            SystemFExpr::Abs(exp) => exp.substitute(v, e),
+            SystemFExpr::App(exp) => exp.substitute(v, e),
+            SystemFExpr::TyAbs(exp) => exp.substitute(v, e),
+            SystemFExpr::TyApp(exp) => exp.substitute(v, e),
+        }
+    }
+}
+impl TypeSubstitution<TyVar, PolyType, Self> for SystemFExpr {
+    fn substitute_type(&self, v: &TyVar, t: &PolyType) -> Self {
+        match self {
+            SystemFExpr::Var(_) => self.clone(),
+            SystemFExpr::Abs(exp) => TypedAbs::new_expr(
+                exp.var.clone(),
+                exp.ty.substitute(v, t),
+                exp.expr.substitute_type(v, t),
+            ),
+            SystemFExpr::App(exp) => {
+                App::new_expr(exp.lhs.substitute_type(v, t), exp.rhs.substitute_type(v, t))
+            }
+            SystemFExpr::TyAbs(exp) => exp.substitute_type(v, t),
+            SystemFExpr::TyApp(exp) => exp.substitute_type(v, t),
+        }
+    }
+}
+impl FreeVars<UntyVar> for SystemFExpr {
+    fn free_vars(&self) -> BTreeSet<UntyVar> {
+        match self {
+            SystemFExpr::Var(exp) => exp.free_vars(),
+            SystemFExpr::Abs(exp) => exp.free_vars(),
+            SystemFExpr::App(exp) => exp.free_vars(),
+            SystemFExpr::TyAbs(exp) => exp.expr.free_vars(),
+            SystemFExpr::TyApp(exp) => exp.free_vars(),
+        }
+    }
+}
+impl FreeVars<TyVar> for SystemFExpr {
+    fn free_vars(&self) -> BTreeSet<TyVar> {
+        match self {
+            SystemFExpr::Var(_) => BTreeSet::new(),
+            SystemFExpr::Abs(exp) => {
+                let mut vars = exp.ty.free_vars();
+                vars.extend(FreeVars::<TyVar>::free_vars(exp.expr.as_ref()));
+                vars
+            }
+            SystemFExpr::App(exp) => exp.free_vars(),
+            SystemFExpr::TyAbs(exp) => exp.free_vars(),
+            SystemFExpr::TyApp(exp) => exp.free_vars(),
+        }
+    }
+}
+
+// `e [T]` binds like an application, so only abstractions extend further to the right.
+impl IsAbs for SystemFExpr {
+    fn is_abs(&self) -> bool {
+        matches!(self, Self::Abs(_) | Self::TyAbs(_))
+    }
+}
+
+impl IsAtomic for SystemFExpr {
+    fn is_atomic(&self) -> bool {
+        matches!(self, Self::Var(_))
+    }
+}
+
+impl ApplyAbs<Self> for SystemFExpr {
+    fn apply_abs(&self, arg: &Self) -> Option<Self> {
+        match self {
+            SystemFExpr::Abs(abs) => Some(abs.apply(arg)),
+            _ => None,
+        }
+    }
+}
+
+impl ApplyTyAbs<Self> for SystemFExpr {
+    fn apply_ty_abs(&self, ty: &PolyType) -> Option<Self> {
+        match self {
+            SystemFExpr::TyAbs(abs) => Some(abs.apply(ty)),
+            _ => None,
+        }
+    }
+}
+
+impl Display for SystemFExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SystemFExpr::Var(e) => Display::fmt(e, f),
+            SystemFExpr::Abs(e) => Display::fmt(e, f),
+            SystemFExpr::App(e) => Display::fmt(e, f),
+            SystemFExpr::TyAbs(e) => Display::fmt(e, f),
+            SystemFExpr::TyApp(e) => Display::fmt(e, f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expressions::{Expression, ReductionStrategy};
+    use crate::parsers::system_f::SystemFParser;
+
+    fn normalize(input: &str) -> String {
+        let mut expr = SystemFParser::parse(input).unwrap();
+        while let Ok(next) = expr.calc_step(ReductionStrategy::NormalOrder) {
+            expr = next;
+        }
+        expr.to_string()
+    }
+
+    #[test]
+    fn type_and_term_beta() {
+        let expr = SystemFParser::parse("(Λα. λx:α. x) [β → β] y").unwrap();
+        let expr = expr.calc_step(ReductionStrategy::NormalOrder).unwrap();
+        assert_eq!(expr.to_string(), "(λ x: β → β. x) y");
+        let expr = expr.calc_step(ReductionStrategy::NormalOrder).unwrap();
+        assert_eq!(expr.to_string(), "y");
+        assert!(expr.calc_step(ReductionStrategy::NormalOrder).is_err());
+    }
+
+    #[test]
+    fn avoids_capture() {
+        assert_eq!(normalize("(Λα. Λβ. λx:α. x) [β]"), "Λ β1. λ x: β. x");
+        assert_eq!(normalize("(λy:β → β. Λβ. y) (λw:β. w)"), "Λ β1. λ w: β. w");
+        assert_eq!(
+            normalize("(λf:∀α. α → α. Λα. λx:α. f [α] x) (Λγ. λy:γ. y)"),
+            "Λ α. λ x: α. x"
+        );
+    }
+
+    #[test]
+    fn church_numerals() {
+        let nat = "∀α. (α → α) → α → α";
+        let two = "Λα. λf:α → α. λx:α. f (f x)";
+        let succ = format!("λn:{nat}. Λα. λf:α → α. λx:α. f (n [α] f x)");
+        assert_eq!(
+            normalize(&format!("({succ}) ({two})")),
+            "Λ α. λ f: α → α. λ x: α. f (f (f x))"
+        );
+        assert_eq!(normalize(&format!("({two}) [β] g")), "λ x: β. g (g x)");
+    }
+}
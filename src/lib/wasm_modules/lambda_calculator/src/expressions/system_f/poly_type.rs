@@ -0,0 +1,135 @@
+use crate::expressions::{FreeVars, Substitution};
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub struct TyVar {
+    name: String,
+}
+
+impl Display for TyVar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl TyVar {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+        }
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn fresh(&self, avoid: &BTreeSet<Self>) -> Self {
+        let base = self.name.trim_end_matches(|c: char| c.is_ascii_digit());
+        (1..)
+            .map(|i| Self::new(&format!("{base}{i}")))
+            .find(|v| !avoid.contains(v))
+            .expect("there are infinitely many fresh names")
+    }
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub enum PolyType {
+    Var(TyVar),
+    Arrow(Box<PolyType>, Box<PolyType>),
+    Forall(TyVar, Box<PolyType>),
+}
+
+impl PolyType {
+    pub fn var(name: &str) -> Self {
+        Self::Var(TyVar::new(name))
+    }
+    pub fn arrow(from: Self, to: Self) -> Self {
+        Self::Arrow(Box::new(from), Box::new(to))
+    }
+    pub fn forall(var: TyVar, ty: Self) -> Self {
+        Self::Forall(var, Box::new(ty))
+    }
+
+    /// Equality up to the names of `∀`-bound type variables.
+    pub fn alpha_eq(&self, other: &Self) -> bool {
+        self.alpha_eq_in(other, &mut vec![])
+    }
+
+    fn alpha_eq_in(&self, other: &Self, bound: &mut Vec<(TyVar, TyVar)>) -> bool {
+        match (self, other) {
+            (PolyType::Var(a), PolyType::Var(b)) => {
+                match bound.iter().rev().find(|(x, y)| x == a || y == b) {
+                    Some((x, y)) => x == a && y == b,
+                    None => a == b,
+                }
+            }
+            (PolyType::Arrow(from_a, to_a), PolyType::Arrow(from_b, to_b)) => {
+                from_a.alpha_eq_in(from_b, bound) && to_a.alpha_eq_in(to_b, bound)
+            }
+            (PolyType::Forall(a, ty_a), PolyType::Forall(b, ty_b)) => {
+                bound.push((a.clone(), b.clone()));
+                let eq = ty_a.alpha_eq_in(ty_b, bound);
+                bound.pop();
+                eq
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Substitution<TyVar, PolyType> for PolyType {
+    fn substitute(&self, v: &TyVar, t: &PolyType) -> PolyType {
+        match self {
+            PolyType::Var(w) if w == v => t.clone(),
+            PolyType::Var(_) => self.clone(),
+            PolyType::Arrow(from, to) => {
+                PolyType::arrow(from.substitute(v, t), to.substitute(v, t))
+            }
+            PolyType::Forall(w, ty) => {
+                if w == v || !ty.free_vars().contains(v) {
+                    return self.clone();
+                }
+                let free_in_t = t.free_vars();
+                if free_in_t.contains(w) {
+                    let mut avoid = free_in_t;
+                    avoid.extend(ty.free_vars());
+                    let fresh = w.fresh(&avoid);
+                    let ty = ty.substitute(w, &PolyType::Var(fresh.clone()));
+                    PolyType::forall(fresh, ty.substitute(v, t))
+                } else {
+                    PolyType::forall(w.clone(), ty.substitute(v, t))
+                }
+            }
+        }
+    }
+}
+
+impl FreeVars<TyVar> for PolyType {
+    fn free_vars(&self) -> BTreeSet<TyVar> {
+        match self {
+            PolyType::Var(v) => BTreeSet::from([v.clone()]),
+            PolyType::Arrow(from, to) => {
+                let mut vars = from.free_vars();
+                vars.extend(to.free_vars());
+                vars
+            }
+            PolyType::Forall(v, ty) => {
+                let mut vars = ty.free_vars();
+                vars.remove(v);
+                vars
+            }
+        }
+    }
+}
+
+impl Display for PolyType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PolyType::Var(v) => write!(f, "{v}"),
+            PolyType::Arrow(from, to) => match **from {
+                PolyType::Var(_) => write!(f, "{from} → {to}"),
+                _ => write!(f, "({from}) → {to}"),
+            },
+            PolyType::Forall(v, ty) => write!(f, "∀{v}. {ty}"),
+        }
+    }
+}
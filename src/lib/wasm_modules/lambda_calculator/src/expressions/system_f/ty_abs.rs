@@ -0,0 +1,137 @@
+use super::poly_type::{PolyType, TyVar};
+use crate::expressions::untyped_lambda_calculus::unty_var::UntyVar;
+use crate::expressions::{
+    CalcStepError, Expression, FreeVars, ReductionStrategy, Substitution, Sum, TypeSubstitution,
+};
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub struct TyAbs<EXPR> {
+    pub(crate) var: TyVar,
+    pub(crate) expr: Box<EXPR>,
+}
+
+impl<EXPR> Display for TyAbs<EXPR>
+where
+    EXPR: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Λ {}. {}", self.var, self.expr)
+    }
+}
+
+impl<EXPR> TyAbs<EXPR> {
+    pub fn new(var: TyVar, expr: EXPR) -> Self {
+        Self {
+            var,
+            expr: Box::new(expr),
+        }
+    }
+    pub fn new_expr<SUM: Sum<Self>>(var: TyVar, expr: EXPR) -> SUM {
+        SUM::pack(Self::new(var, expr))
+    }
+    pub fn into_expr<SUM: Sum<Self>>(self) -> SUM {
+        SUM::pack(self)
+    }
+    pub fn var(&self) -> &TyVar {
+        &self.var
+    }
+    pub fn body(&self) -> &EXPR {
+        &self.expr
+    }
+}
+
+impl<EXPR> TyAbs<EXPR>
+where
+    EXPR: TypeSubstitution<TyVar, PolyType, EXPR>,
+{
+    pub fn apply(&self, ty: &PolyType) -> EXPR {
+        self.expr.substitute_type(&self.var, ty)
+    }
+}
+
+impl<EXPR> TyAbs<EXPR>
+where
+    EXPR: TypeSubstitution<TyVar, PolyType, EXPR> + FreeVars<TyVar>,
+{
+    pub fn rename(&self, avoid: &BTreeSet<TyVar>) -> Self {
+        let mut avoid = avoid.clone();
+        avoid.extend(self.expr.free_vars());
+        let fresh = self.var.fresh(&avoid);
+        Self {
+            expr: Box::new(
+                self.expr
+                    .substitute_type(&self.var, &PolyType::Var(fresh.clone())),
+            ),
+            var: fresh,
+        }
+    }
+}
+
+impl<EXPR> Expression<EXPR> for TyAbs<EXPR>
+where
+    EXPR: Expression<EXPR> + Sum<Self>,
+{
+    fn is_value(&self) -> bool {
+        true
+    }
+
+    fn calc_step(&self, strategy: ReductionStrategy) -> Result<EXPR, CalcStepError> {
+        if !strategy.reduces_under_abs() {
+            return Err(CalcStepError::NoRedex);
+        }
+        self.expr
+            .calc_step(strategy)
+            .map(|expr| Self::new_expr(self.var.clone(), expr))
+    }
+}
+
+// A term substituted under `Λα` must not have its free `α` captured.
+impl<EXPR> Substitution<UntyVar, EXPR> for TyAbs<EXPR>
+where
+    EXPR: Substitution<UntyVar, EXPR>
+        + TypeSubstitution<TyVar, PolyType, EXPR>
+        + FreeVars<TyVar>
+        + Sum<Self>
+        + Clone,
+{
+    fn substitute(&self, v: &UntyVar, e: &EXPR) -> EXPR {
+        let free_in_e = e.free_vars();
+        let abs = if free_in_e.contains(&self.var) {
+            self.rename(&free_in_e)
+        } else {
+            self.clone()
+        };
+        Self::new_expr(abs.var, abs.expr.substitute(v, e))
+    }
+}
+
+impl<EXPR> TypeSubstitution<TyVar, PolyType, EXPR> for TyAbs<EXPR>
+where
+    EXPR: TypeSubstitution<TyVar, PolyType, EXPR> + FreeVars<TyVar> + Sum<Self> + Clone,
+{
+    fn substitute_type(&self, v: &TyVar, t: &PolyType) -> EXPR {
+        if self.var == *v || !self.expr.free_vars().contains(v) {
+            return self.clone().into_expr();
+        }
+        let free_in_t = t.free_vars();
+        let abs = if free_in_t.contains(&self.var) {
+            self.rename(&free_in_t)
+        } else {
+            self.clone()
+        };
+        Self::new_expr(abs.var, abs.expr.substitute_type(v, t))
+    }
+}
+
+impl<EXPR> FreeVars<TyVar> for TyAbs<EXPR>
+where
+    EXPR: FreeVars<TyVar>,
+{
+    fn free_vars(&self) -> BTreeSet<TyVar> {
+        let mut vars = self.expr.free_vars();
+        vars.remove(&self.var);
+        vars
+    }
+}
@@ -0,0 +1,120 @@
+use super::poly_type::{PolyType, TyVar};
+use crate::expressions::untyped_lambda_calculus::{app::IsAbs, unty_var::UntyVar};
+use crate::expressions::{
+    CalcStepError, Expression, FreeVars, ReductionStrategy, Substitution, Sum, TypeSubstitution,
+};
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub struct TyApp<EXPR> {
+    pub(crate) expr: Box<EXPR>,
+    pub(crate) ty: PolyType,
+}
+
+impl<EXPR> Display for TyApp<EXPR>
+where
+    EXPR: Display + IsAbs,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.expr.is_abs() {
+            write!(f, "({}) [{}]", self.expr, self.ty)
+        } else {
+            write!(f, "{} [{}]", self.expr, self.ty)
+        }
+    }
+}
+
+pub trait ApplyTyAbs<EXPR> {
+    fn apply_ty_abs(&self, ty: &PolyType) -> Option<EXPR>;
+}
+
+impl<EXPR> TyApp<EXPR> {
+    pub fn new(expr: EXPR, ty: PolyType) -> Self {
+        Self {
+            expr: Box::new(expr),
+            ty,
+        }
+    }
+    pub fn new_expr<SUM: Sum<Self>>(expr: EXPR, ty: PolyType) -> SUM {
+        SUM::pack(Self::new(expr, ty))
+    }
+    pub fn into_expr<SUM: Sum<Self>>(self) -> SUM {
+        SUM::pack(self)
+    }
+    pub fn expr(&self) -> &EXPR {
+        &self.expr
+    }
+    pub fn ty(&self) -> &PolyType {
+        &self.ty
+    }
+}
+
+impl<EXPR> TyApp<EXPR>
+where
+    EXPR: Expression<EXPR> + ApplyTyAbs<EXPR> + Sum<Self>,
+{
+    fn beta(&self) -> Result<EXPR, CalcStepError> {
+        self.expr
+            .apply_ty_abs(&self.ty)
+            .ok_or(CalcStepError::NoRedex)
+    }
+
+    fn step_expr(&self, strategy: ReductionStrategy) -> Result<EXPR, CalcStepError> {
+        self.expr
+            .calc_step(strategy)
+            .map(|expr| Self::new_expr(expr, self.ty.clone()))
+    }
+}
+
+// Types are never reduced, so only the function can step before the type is passed in.
+impl<EXPR> Expression<EXPR> for TyApp<EXPR>
+where
+    EXPR: Expression<EXPR> + ApplyTyAbs<EXPR> + Sum<Self>,
+{
+    fn is_value(&self) -> bool {
+        false
+    }
+
+    fn calc_step(&self, strategy: ReductionStrategy) -> Result<EXPR, CalcStepError> {
+        strategy.step_elim(|| self.beta(), || self.step_expr(strategy))
+    }
+}
+
+impl<EXPR> Substitution<UntyVar, EXPR> for TyApp<EXPR>
+where
+    EXPR: Substitution<UntyVar, EXPR> + Sum<Self>,
+{
+    fn substitute(&self, v: &UntyVar, e: &EXPR) -> EXPR {
+        Self::new_expr(self.expr.substitute(v, e), self.ty.clone())
+    }
+}
+
+impl<EXPR> TypeSubstitution<TyVar, PolyType, EXPR> for TyApp<EXPR>
+where
+    EXPR: TypeSubstitution<TyVar, PolyType, EXPR> + Sum<Self>,
+{
+    fn substitute_type(&self, v: &TyVar, t: &PolyType) -> EXPR {
+        Self::new_expr(self.expr.substitute_type(v, t), self.ty.substitute(v, t))
+    }
+}
+
+impl<EXPR> FreeVars<UntyVar> for TyApp<EXPR>
+where
+    EXPR: FreeVars<UntyVar>,
+{
+    fn free_vars(&self) -> BTreeSet<UntyVar> {
+        self.expr.free_vars()
+    }
+}
+
+impl<EXPR> FreeVars<TyVar> for TyApp<EXPR>
+where
+    EXPR: FreeVars<TyVar>,
+{
+    fn free_vars(&self) -> BTreeSet<TyVar> {
+        let mut vars = self.expr.free_vars();
+        vars.extend(self.ty.free_vars());
+        vars
+    }
+}
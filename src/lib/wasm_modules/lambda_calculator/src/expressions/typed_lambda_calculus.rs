@@ -0,0 +1,372 @@
+use self::{
+    absurd::Absurd,
+    case::Case,
+    fold::{AsFold, Fold},
+    inj::{AsInj, Inj},
+    pair::{AsPair, Pair},
+    proj::Proj,
+    ty::Type,
+    unfold::Unfold,
+    unit::Unit,
+};
+use super::simply_typed_lambda_calculus::typed_abs::TypedAbs;
+use super::untyped_lambda_calculus::{
+    app::{App, ApplyAbs, IsAbs, IsAtomic},
+    unty_var::UntyVar,
+    UntyLamExpr,
+};
+use super::{CalcStepError, Expression, FreeVars, ReductionStrategy, Substitution, Sum};
+use std::collections::BTreeSet;
+use std::fmt::{Debug, Display};
+pub mod absurd;
+pub mod case;
+pub mod fold;
+pub mod inj;
+pub mod pair;
+pub mod proj;
+pub mod ty;
+pub mod unfold;
+pub mod unit;
+
+/// Which component of a pair or which summand of a sum.
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone, Copy)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+/// The type after `as` in `inl e as T`, `absurd e as T` and `fold e as T`, which the
+/// bidirectional syntax may leave out.
+pub trait AsType {
+    fn as_type(&self) -> Option<&Type>;
+}
+
+impl AsType for Type {
+    fn as_type(&self) -> Option<&Type> {
+        Some(self)
+    }
+}
+
+impl AsType for Option<Type> {
+    fn as_type(&self) -> Option<&Type> {
+        self.as_ref()
+    }
+}
+
+/// The simply typed lambda calculus with products, sums, `Unit`, `Empty` and iso-recursive types.
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub enum TypedLamExpr {
+    Var(UntyVar),
+    Abs(TypedAbs<Type, Self>),
+    App(App<Self>),
+    Unit(Unit),
+    Pair(Pair<Self>),
+    Proj(Proj<Self>),
+    Inj(Inj<Type, Self>),
+    Case(Case<Self>),
+    Absurd(Absurd<Type, Self>),
+    Fold(Fold<Type, Self>),
+    Unfold(Unfold<Self>),
+}
+
+impl TypedLamExpr {
+    pub fn is_normal_form(&self, strategy: ReductionStrategy) -> bool {
+        self.calc_step(strategy).is_err()
+    }
+
+    /// `μα. α → α`, the type every embedded untyped term has.
+    pub fn untyped_domain() -> Type {
+        Type::rec("α", Type::arrow(Type::base("α"), Type::base("α")))
+    }
+
+    /// Embeds an untyped term: abstractions are folded into `untyped_domain()` and unfolded
+    /// again where they are applied. Every term is well typed after the embedding, even `Ω`.
+    pub fn embed(expr: &UntyLamExpr) -> Self {
+        match expr {
+            UntyLamExpr::Var(v) => v.clone().into_expr(),
+            UntyLamExpr::Abs(abs) => Fold::new_expr(
+                TypedAbs::new_expr(
+                    abs.var.clone(),
+                    Self::untyped_domain(),
+                    Self::embed(&abs.expr),
+                ),
+                Self::untyped_domain(),
+            ),
+            UntyLamExpr::App(app) => App::new_expr(
+                Unfold::new_expr(Self::embed(&app.lhs)),
+                Self::embed(&app.rhs),
+            ),
+        }
+    }
+}
+
+impl Sum<UntyVar> for TypedLamExpr {
+    fn pack(content: UntyVar) -> Self {
+        Self::Var(content)
+    }
+}
+impl Sum<TypedAbs<Type, Self>> for TypedLamExpr {
+    fn pack(content: TypedAbs<Type, Self>) -> Self {
+        Self::Abs(content)
+    }
+}
+impl Sum<App<Self>> for TypedLamExpr {
+    fn pack(content: App<Self>) -> Self {
+        Self::App(content)
+    }
+}
+impl Sum<Unit> for TypedLamExpr {
+    fn pack(content: Unit) -> Self {
+        Self::Unit(content)
+    }
+}
+impl Sum<Pair<Self>> for TypedLamExpr {
+    fn pack(content: Pair<Self>) -> Self {
+        Self::Pair(content)
+    }
+}
+impl Sum<Proj<Self>> for TypedLamExpr {
+    fn pack(content: Proj<Self>) -> Self {
+        Self::Proj(content)
+    }
+}
+impl Sum<Inj<Type, Self>> for TypedLamExpr {
+    fn pack(content: Inj<Type, Self>) -> Self {
+        Self::Inj(content)
+    }
+}
+impl Sum<Case<Self>> for TypedLamExpr {
+    fn pack(content: Case<Self>) -> Self {
+        Self::Case(content)
+    }
+}
+impl Sum<Absurd<Type, Self>> for TypedLamExpr {
+    fn pack(content: Absurd<Type, Self>) -> Self {
+        Self::Absurd(content)
+    }
+}
+impl Sum<Fold<Type, Self>> for TypedLamExpr {
+    fn pack(content: Fold<Type, Self>) -> Self {
+        Self::Fold(content)
+    }
+}
+impl Sum<Unfold<Self>> for TypedLamExpr {
+    fn pack(content: Unfold<Self>) -> Self {
+        Self::Unfold(content)
+    }
+}
+impl Expression<Self> for TypedLamExpr {
+    fn is_value(&self) -> bool {
+        match self {
+            TypedLamExpr::Var(e) => <UntyVar as Expression<Self>>::is_value(e),
+            TypedLamExpr::Abs(e) => e.is_value(),
+            TypedLamExpr::App(e) => e.is_value(),
+            TypedLamExpr::Unit(e) => <Unit as Expression<Self>>::is_value(e),
+            TypedLamExpr::Pair(e) => e.is_value(),
+            TypedLamExpr::Proj(e) => e.is_value(),
+            TypedLamExpr::Inj(e) => e.is_value(),
+            TypedLamExpr::Case(e) => e.is_value(),
+            TypedLamExpr::Absurd(e) => e.is_value(),
+            TypedLamExpr::Fold(e) => e.is_value(),
+            TypedLamExpr::Unfold(e) => e.is_value(),
+        }
+    }
+
+    fn calc_step(&self, strategy: ReductionStrategy) -> Result<Self, CalcStepError> {
+        match self {
+            TypedLamExpr::Var(e) => e.calc_step(strategy),
+            TypedLamExpr::Abs(e) => e.calc_step(strategy),
+            TypedLamExpr::App(e) => e.calc_step(strategy),
+            TypedLamExpr::Unit(e) => e.calc_step(strategy),
+            TypedLamExpr::Pair(e) => e.calc_step(strategy),
+            TypedLamExpr::Proj(e) => e.calc_step(strategy),
+            TypedLamExpr::Inj(e) => e.calc_step(strategy),
+            TypedLamExpr::Case(e) => e.calc_step(strategy),
+            TypedLamExpr::Absurd(e) => e.calc_step(strategy),
+            TypedLamExpr::Fold(e) => e.calc_step(strategy),
+            TypedLamExpr::Unfold(e) => e.calc_step(strategy),
+        }
+    }
+}
+impl Substitution<UntyVar, Self> for TypedLamExpr {
+    fn substitute(&self, v: &UntyVar, e: &Self) -> Self {
+        match self {
+            TypedLamExpr::Var(exp) => exp.substitute(v, e),
+            TypedLamExpr::Abs(exp) => exp.substitute(v, e),
+            TypedLamExpr::App(exp) => exp.substitute(v, e),
+            TypedLamExpr::Unit(exp) => exp.substitute(v, e),
+            TypedLamExpr::Pair(exp) => exp.substitute(v, e),
+            TypedLamExpr::Proj(exp) => exp.substitute(v, e),
+            TypedLamExpr::Inj(exp) => exp.substitute(v, e),
+            TypedLamExpr::Case(exp) => exp.substitute(v, e),
+            TypedLamExpr::Absurd(exp) => exp.substitute(v, e),
+            TypedLamExpr::Fold(exp) => exp.substitute(v, e),
+            TypedLamExpr::Unfold(exp) => exp.substitute(v, e),
+        }
+    }
+}
+impl FreeVars<UntyVar> for TypedLamExpr {
+    fn free_vars(&self) -> BTreeSet<UntyVar> {
+        match self {
+            TypedLamExpr::Var(exp) => exp.free_vars(),
+            TypedLamExpr::Abs(exp) => exp.free_vars(),
+            TypedLamExpr::App(exp) => exp.free_vars(),
+            TypedLamExpr::Unit(exp) => exp.free_vars(),
+            TypedLamExpr::Pair(exp) => exp.free_vars(),
+            TypedLamExpr::Proj(exp) => exp.free_vars(),
+            TypedLamExpr::Inj(exp) => exp.free_vars(),
+            TypedLamExpr::Case(exp) => exp.free_vars(),
+            TypedLamExpr::Absurd(exp) => exp.free_vars(),
+            TypedLamExpr::Fold(exp) => exp.free_vars(),
+            TypedLamExpr::Unfold(exp) => exp.free_vars(),
+        }
+    }
+}
+
+impl AsPair<Self> for TypedLamExpr {
+    fn as_pair(&self) -> Option<&Pair<Self>> {
+        match self {
+            TypedLamExpr::Pair(pair) => Some(pair),
+            _ => None,
+        }
+    }
+}
+
+impl AsFold<Self> for TypedLamExpr {
+    fn as_fold(&self) -> Option<&Self> {
+        match self {
+            TypedLamExpr::Fold(fold) => Some(&fold.expr),
+            _ => None,
+        }
+    }
+}
+
+impl AsInj<Self> for TypedLamExpr {
+    fn as_inj(&self) -> Option<(Side, &Self)> {
+        match self {
+            TypedLamExpr::Inj(inj) => Some((inj.side, &inj.expr)),
+            _ => None,
+        }
+    }
+}
+
+// Injections, `absurd` and `fold` end in a type, which extends to the right like an abstraction
+// body.
+impl IsAbs for TypedLamExpr {
+    fn is_abs(&self) -> bool {
+        matches!(
+            self,
+            Self::Abs(_) | Self::Inj(_) | Self::Case(_) | Self::Absurd(_) | Self::Fold(_)
+        )
+    }
+}
+
+impl IsAtomic for TypedLamExpr {
+    fn is_atomic(&self) -> bool {
+        matches!(self, Self::Var(_) | Self::Unit(_) | Self::Pair(_))
+    }
+}
+
+impl ApplyAbs<Self> for TypedLamExpr {
+    fn apply_abs(&self, arg: &Self) -> Option<Self> {
+        match self {
+            TypedLamExpr::Abs(abs) => Some(abs.apply(arg)),
+            _ => None,
+        }
+    }
+}
+
+impl Display for TypedLamExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TypedLamExpr::Var(e) => Display::fmt(e, f),
+            TypedLamExpr::Abs(e) => Display::fmt(e, f),
+            TypedLamExpr::App(e) => Display::fmt(e, f),
+            TypedLamExpr::Unit(e) => Display::fmt(e, f),
+            TypedLamExpr::Pair(e) => Display::fmt(e, f),
+            TypedLamExpr::Proj(e) => Display::fmt(e, f),
+            TypedLamExpr::Inj(e) => Display::fmt(e, f),
+            TypedLamExpr::Case(e) => Display::fmt(e, f),
+            TypedLamExpr::Absurd(e) => Display::fmt(e, f),
+            TypedLamExpr::Fold(e) => Display::fmt(e, f),
+            TypedLamExpr::Unfold(e) => Display::fmt(e, f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TypedLamExpr;
+    use crate::expressions::{Expression, ReductionStrategy};
+    use crate::parsers::typed_lambda_calculus::TypedLambdaCalculusParser;
+    use crate::parsers::untyped_lambda_calculus::UntypedLambdaCalculusParser;
+
+    fn normalize(input: &str, strategy: ReductionStrategy) -> String {
+        let mut expr: TypedLamExpr = TypedLambdaCalculusParser::parse(input).unwrap();
+        while let Ok(next) = expr.calc_step(strategy) {
+            expr = next;
+        }
+        expr.to_string()
+    }
+
+    #[test]
+    fn products_and_sums() {
+        let swap = "(λp: A × B. (snd p, fst p)) (a, b)";
+        assert_eq!(normalize(swap, ReductionStrategy::NormalOrder), "(b, a)");
+        assert_eq!(normalize(swap, ReductionStrategy::CallByValue), "(b, a)");
+        let case = "case inr ((λx: A. x) b) as A + B of inl x ⇒ (x, ()) | inr y ⇒ (y, y)";
+        assert_eq!(normalize(case, ReductionStrategy::NormalOrder), "(b, b)");
+        assert_eq!(normalize(case, ReductionStrategy::CallByValue), "(b, b)");
+    }
+
+    #[test]
+    fn strategies() {
+        let step = |input, strategy| {
+            let expr = TypedLambdaCalculusParser::parse(input).unwrap();
+            expr.calc_step(strategy).ok().map(|e| e.to_string())
+        };
+        let input = "fst (a, (λx: A. x) b)";
+        assert_eq!(
+            step(input, ReductionStrategy::NormalOrder),
+            Some("a".to_string())
+        );
+        assert_eq!(
+            step(input, ReductionStrategy::CallByValue),
+            Some("fst (a, b)".to_string())
+        );
+        assert_eq!(
+            step("(a, (λx: A. x) b)", ReductionStrategy::CallByName),
+            None
+        );
+        // branches are substituted without capturing the scrutinee's variables
+        assert_eq!(
+            step(
+                "(λz: A. case w of inl x ⇒ z | inr z ⇒ x) x",
+                ReductionStrategy::NormalOrder
+            ),
+            Some("case w of inl x1 ⇒ x | inr z ⇒ x".to_string())
+        );
+    }
+
+    #[test]
+    fn embedded_untyped_terms() {
+        let embed =
+            |input| TypedLamExpr::embed(&UntypedLambdaCalculusParser::parse(input).unwrap());
+        assert_eq!(
+            embed("λx. x y").to_string(),
+            "fold (λ x: μα. α → α. unfold x y) as μα. α → α"
+        );
+        let mut expr = embed("(λx. x) y");
+        while let Ok(next) = expr.calc_step(ReductionStrategy::NormalOrder) {
+            expr = next;
+        }
+        assert_eq!(expr.to_string(), "y");
+        // Ω keeps reducing to itself, one unfold and one β-step per round
+        let omega = embed("(λx. x x) (λx. x x)");
+        let once = omega
+            .calc_step(ReductionStrategy::CallByValue)
+            .and_then(|e| e.calc_step(ReductionStrategy::CallByValue))
+            .unwrap();
+        assert_eq!(once, omega);
+    }
+}
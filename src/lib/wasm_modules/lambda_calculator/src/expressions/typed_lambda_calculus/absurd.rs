@@ -0,0 +1,89 @@
+use super::AsType;
+use crate::expressions::untyped_lambda_calculus::app::IsAtomic;
+use crate::expressions::{
+    CalcStepError, Expression, FreeVars, ReductionStrategy, Substitution, Sum,
+};
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+/// `absurd e as T`: eliminates a value of type `Empty` into any type `T`. Since there are no
+/// such values, it never reduces itself.
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub struct Absurd<TY, EXPR> {
+    pub(crate) expr: Box<EXPR>,
+    pub(crate) ty: TY,
+}
+
+impl<TY, EXPR> Display for Absurd<TY, EXPR>
+where
+    TY: AsType,
+    EXPR: Display + IsAtomic,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.expr.is_atomic() {
+            write!(f, "absurd {}", self.expr)?;
+        } else {
+            write!(f, "absurd ({})", self.expr)?;
+        }
+        match self.ty.as_type() {
+            Some(ty) => write!(f, " as {ty}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<TY, EXPR> Absurd<TY, EXPR> {
+    pub fn new(expr: EXPR, ty: TY) -> Self {
+        Self {
+            expr: Box::new(expr),
+            ty,
+        }
+    }
+    pub fn new_expr<SUM: Sum<Self>>(expr: EXPR, ty: TY) -> SUM {
+        SUM::pack(Self::new(expr, ty))
+    }
+    pub fn into_expr<SUM: Sum<Self>>(self) -> SUM {
+        SUM::pack(self)
+    }
+    pub fn expr(&self) -> &EXPR {
+        &self.expr
+    }
+    pub fn ty(&self) -> &TY {
+        &self.ty
+    }
+}
+
+impl<TY, EXPR> Expression<EXPR> for Absurd<TY, EXPR>
+where
+    TY: Clone,
+    EXPR: Expression<EXPR> + Sum<Self>,
+{
+    fn is_value(&self) -> bool {
+        false
+    }
+
+    fn calc_step(&self, strategy: ReductionStrategy) -> Result<EXPR, CalcStepError> {
+        self.expr
+            .calc_step(strategy)
+            .map(|expr| Self::new_expr(expr, self.ty.clone()))
+    }
+}
+
+impl<VAR, TY, EXPR> Substitution<VAR, EXPR> for Absurd<TY, EXPR>
+where
+    TY: Clone,
+    EXPR: Substitution<VAR, EXPR> + Sum<Self>,
+{
+    fn substitute(&self, v: &VAR, e: &EXPR) -> EXPR {
+        Self::new_expr(self.expr.substitute(v, e), self.ty.clone())
+    }
+}
+
+impl<VAR, TY, EXPR> FreeVars<VAR> for Absurd<TY, EXPR>
+where
+    EXPR: FreeVars<VAR>,
+{
+    fn free_vars(&self) -> BTreeSet<VAR> {
+        self.expr.free_vars()
+    }
+}
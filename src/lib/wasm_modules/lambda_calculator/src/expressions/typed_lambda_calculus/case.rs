@@ -0,0 +1,153 @@
+use super::{inj::AsInj, Side};
+use crate::expressions::untyped_lambda_calculus::{
+    app::IsAbs, unty_abs::UntyAbs, unty_var::UntyVar,
+};
+use crate::expressions::{
+    or_next, CalcStepError, Expression, FreeVars, ReductionStrategy, Substitution, Sum,
+};
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+/// `case e of inl x ⇒ t | inr y ⇒ u`. Each branch binds the injected value like an abstraction.
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub struct Case<EXPR> {
+    pub(crate) expr: Box<EXPR>,
+    pub(crate) left: UntyAbs<EXPR>,
+    pub(crate) right: UntyAbs<EXPR>,
+}
+
+// The left branch is parenthesized when it extends to the right, so that a `|` inside it
+// can't be mistaken for the start of the right branch.
+impl<EXPR> Display for Case<EXPR>
+where
+    EXPR: Display + IsAbs,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "case {} of inl {} ⇒ ", self.expr, self.left.var)?;
+        if self.left.expr.is_abs() {
+            write!(f, "({})", self.left.expr)?;
+        } else {
+            write!(f, "{}", self.left.expr)?;
+        }
+        write!(f, " | inr {} ⇒ {}", self.right.var, self.right.expr)
+    }
+}
+
+impl<EXPR> Case<EXPR> {
+    pub fn new(expr: EXPR, left: UntyAbs<EXPR>, right: UntyAbs<EXPR>) -> Self {
+        Self {
+            expr: Box::new(expr),
+            left,
+            right,
+        }
+    }
+    pub fn new_expr<SUM: Sum<Self>>(expr: EXPR, left: UntyAbs<EXPR>, right: UntyAbs<EXPR>) -> SUM {
+        SUM::pack(Self::new(expr, left, right))
+    }
+    pub fn into_expr<SUM: Sum<Self>>(self) -> SUM {
+        SUM::pack(self)
+    }
+    pub fn expr(&self) -> &EXPR {
+        &self.expr
+    }
+    pub fn branch(&self, side: Side) -> &UntyAbs<EXPR> {
+        match side {
+            Side::Left => &self.left,
+            Side::Right => &self.right,
+        }
+    }
+}
+
+impl<EXPR> Case<EXPR>
+where
+    EXPR: Expression<EXPR> + Substitution<UntyVar, EXPR> + AsInj<EXPR> + Sum<Self> + Clone,
+{
+    fn contract(&self) -> Result<EXPR, CalcStepError> {
+        self.expr
+            .as_inj()
+            .map(|(side, value)| self.branch(side).apply(value))
+            .ok_or(CalcStepError::NoRedex)
+    }
+
+    fn step_expr(&self, strategy: ReductionStrategy) -> Result<EXPR, CalcStepError> {
+        self.expr
+            .calc_step(strategy)
+            .map(|expr| Self::new_expr(expr, self.left.clone(), self.right.clone()))
+    }
+
+    fn step_branches(&self, strategy: ReductionStrategy) -> Result<EXPR, CalcStepError> {
+        if !strategy.reduces_under_abs() {
+            return Err(CalcStepError::NoRedex);
+        }
+        let with_body = |branch: &UntyAbs<EXPR>, body| UntyAbs::new(branch.var.clone(), body);
+        or_next(
+            self.left.expr.calc_step(strategy).map(|body| {
+                let left = with_body(&self.left, body);
+                Self::new_expr((*self.expr).clone(), left, self.right.clone())
+            }),
+            || {
+                self.right.expr.calc_step(strategy).map(|body| {
+                    let right = with_body(&self.right, body);
+                    Self::new_expr((*self.expr).clone(), self.left.clone(), right)
+                })
+            },
+        )
+    }
+}
+
+impl<EXPR> Expression<EXPR> for Case<EXPR>
+where
+    EXPR: Expression<EXPR> + Substitution<UntyVar, EXPR> + AsInj<EXPR> + Sum<Self> + Clone,
+{
+    fn is_value(&self) -> bool {
+        false
+    }
+
+    fn calc_step(&self, strategy: ReductionStrategy) -> Result<EXPR, CalcStepError> {
+        strategy.step_app(
+            || self.contract(),
+            || self.step_expr(strategy),
+            || self.step_branches(strategy),
+        )
+    }
+}
+
+impl<EXPR> Substitution<UntyVar, EXPR> for Case<EXPR>
+where
+    EXPR: Substitution<UntyVar, EXPR> + FreeVars<UntyVar> + Sum<Self> + Sum<UntyVar> + Clone,
+{
+    fn substitute(&self, v: &UntyVar, e: &EXPR) -> EXPR {
+        let branch = |branch: &UntyAbs<EXPR>| {
+            if branch.var == *v || !branch.expr.free_vars().contains(v) {
+                return branch.clone();
+            }
+            let free_in_e = e.free_vars();
+            let branch = if free_in_e.contains(&branch.var) {
+                branch.rename(&free_in_e)
+            } else {
+                branch.clone()
+            };
+            UntyAbs::new(branch.var, branch.expr.substitute(v, e))
+        };
+        Self::new_expr(
+            self.expr.substitute(v, e),
+            branch(&self.left),
+            branch(&self.right),
+        )
+    }
+}
+
+impl<EXPR> FreeVars<UntyVar> for Case<EXPR>
+where
+    EXPR: FreeVars<UntyVar>,
+{
+    fn free_vars(&self) -> BTreeSet<UntyVar> {
+        let mut vars = self.expr.free_vars();
+        for branch in [&self.left, &self.right] {
+            let mut in_branch = branch.expr.free_vars();
+            in_branch.remove(&branch.var);
+            vars.extend(in_branch);
+        }
+        vars
+    }
+}
@@ -0,0 +1,97 @@
+use super::AsType;
+use crate::expressions::untyped_lambda_calculus::app::IsAtomic;
+use crate::expressions::{
+    CalcStepError, Expression, FreeVars, ReductionStrategy, Substitution, Sum,
+};
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+/// `fold e as T`, which turns a value of the unfolding of the recursive type `T` into a `T`.
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub struct Fold<TY, EXPR> {
+    pub(crate) expr: Box<EXPR>,
+    pub(crate) ty: TY,
+}
+
+impl<TY, EXPR> Display for Fold<TY, EXPR>
+where
+    TY: AsType,
+    EXPR: Display + IsAtomic,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.expr.is_atomic() {
+            write!(f, "fold {}", self.expr)?;
+        } else {
+            write!(f, "fold ({})", self.expr)?;
+        }
+        match self.ty.as_type() {
+            Some(ty) => write!(f, " as {ty}"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The folded value, if the expression is a `fold`.
+pub trait AsFold<EXPR> {
+    fn as_fold(&self) -> Option<&EXPR>;
+}
+
+impl<TY, EXPR> Fold<TY, EXPR> {
+    pub fn new(expr: EXPR, ty: TY) -> Self {
+        Self {
+            expr: Box::new(expr),
+            ty,
+        }
+    }
+    pub fn new_expr<SUM: Sum<Self>>(expr: EXPR, ty: TY) -> SUM {
+        SUM::pack(Self::new(expr, ty))
+    }
+    pub fn into_expr<SUM: Sum<Self>>(self) -> SUM {
+        SUM::pack(self)
+    }
+    pub fn expr(&self) -> &EXPR {
+        &self.expr
+    }
+    pub fn ty(&self) -> &TY {
+        &self.ty
+    }
+}
+
+// Like an injection: a constructor that call-by-name does not look into.
+impl<TY, EXPR> Expression<EXPR> for Fold<TY, EXPR>
+where
+    TY: Clone,
+    EXPR: Expression<EXPR> + Sum<Self>,
+{
+    fn is_value(&self) -> bool {
+        self.expr.is_value()
+    }
+
+    fn calc_step(&self, strategy: ReductionStrategy) -> Result<EXPR, CalcStepError> {
+        if strategy == ReductionStrategy::CallByName {
+            return Err(CalcStepError::NoRedex);
+        }
+        self.expr
+            .calc_step(strategy)
+            .map(|expr| Self::new_expr(expr, self.ty.clone()))
+    }
+}
+
+impl<VAR, TY, EXPR> Substitution<VAR, EXPR> for Fold<TY, EXPR>
+where
+    TY: Clone,
+    EXPR: Substitution<VAR, EXPR> + Sum<Self>,
+{
+    fn substitute(&self, v: &VAR, e: &EXPR) -> EXPR {
+        Self::new_expr(self.expr.substitute(v, e), self.ty.clone())
+    }
+}
+
+impl<VAR, TY, EXPR> FreeVars<VAR> for Fold<TY, EXPR>
+where
+    EXPR: FreeVars<VAR>,
+{
+    fn free_vars(&self) -> BTreeSet<VAR> {
+        self.expr.free_vars()
+    }
+}
@@ -0,0 +1,105 @@
+use super::{AsType, Side};
+use crate::expressions::untyped_lambda_calculus::app::IsAtomic;
+use crate::expressions::{
+    CalcStepError, Expression, FreeVars, ReductionStrategy, Substitution, Sum,
+};
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+/// `inl e as T` or `inr e as T`, where `T` is the whole sum type.
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub struct Inj<TY, EXPR> {
+    pub(crate) side: Side,
+    pub(crate) expr: Box<EXPR>,
+    pub(crate) ty: TY,
+}
+
+impl<TY, EXPR> Display for Inj<TY, EXPR>
+where
+    TY: AsType,
+    EXPR: Display + IsAtomic,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self.side {
+            Side::Left => "inl",
+            Side::Right => "inr",
+        };
+        if self.expr.is_atomic() {
+            write!(f, "{name} {}", self.expr)?;
+        } else {
+            write!(f, "{name} ({})", self.expr)?;
+        }
+        match self.ty.as_type() {
+            Some(ty) => write!(f, " as {ty}"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The side and the injected value, if the expression is an injection.
+pub trait AsInj<EXPR> {
+    fn as_inj(&self) -> Option<(Side, &EXPR)>;
+}
+
+impl<TY, EXPR> Inj<TY, EXPR> {
+    pub fn new(side: Side, expr: EXPR, ty: TY) -> Self {
+        Self {
+            side,
+            expr: Box::new(expr),
+            ty,
+        }
+    }
+    pub fn new_expr<SUM: Sum<Self>>(side: Side, expr: EXPR, ty: TY) -> SUM {
+        SUM::pack(Self::new(side, expr, ty))
+    }
+    pub fn into_expr<SUM: Sum<Self>>(self) -> SUM {
+        SUM::pack(self)
+    }
+    pub fn side(&self) -> Side {
+        self.side
+    }
+    pub fn expr(&self) -> &EXPR {
+        &self.expr
+    }
+    pub fn ty(&self) -> &TY {
+        &self.ty
+    }
+}
+
+impl<TY, EXPR> Expression<EXPR> for Inj<TY, EXPR>
+where
+    TY: Clone,
+    EXPR: Expression<EXPR> + Sum<Self>,
+{
+    fn is_value(&self) -> bool {
+        self.expr.is_value()
+    }
+
+    fn calc_step(&self, strategy: ReductionStrategy) -> Result<EXPR, CalcStepError> {
+        if strategy == ReductionStrategy::CallByName {
+            return Err(CalcStepError::NoRedex);
+        }
+        self.expr
+            .calc_step(strategy)
+            .map(|expr| Self::new_expr(self.side, expr, self.ty.clone()))
+    }
+}
+
+impl<VAR, TY, EXPR> Substitution<VAR, EXPR> for Inj<TY, EXPR>
+where
+    TY: Clone,
+    EXPR: Substitution<VAR, EXPR> + Sum<Self>,
+{
+    fn substitute(&self, v: &VAR, e: &EXPR) -> EXPR {
+        Self::new_expr(self.side, self.expr.substitute(v, e), self.ty.clone())
+    }
+}
+
+impl<VAR, TY, EXPR> FreeVars<VAR> for Inj<TY, EXPR>
+where
+    EXPR: FreeVars<VAR>,
+{
+    fn free_vars(&self) -> BTreeSet<VAR> {
+        self.expr.free_vars()
+    }
+}
@@ -0,0 +1,99 @@
+use super::Side;
+use crate::expressions::{
+    or_next, CalcStepError, Expression, FreeVars, ReductionStrategy, Substitution, Sum,
+};
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub struct Pair<EXPR> {
+    pub(crate) fst: Box<EXPR>,
+    pub(crate) snd: Box<EXPR>,
+}
+
+impl<EXPR> Display for Pair<EXPR>
+where
+    EXPR: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.fst, self.snd)
+    }
+}
+
+pub trait AsPair<EXPR> {
+    fn as_pair(&self) -> Option<&Pair<EXPR>>;
+}
+
+impl<EXPR> Pair<EXPR> {
+    pub fn new(fst: EXPR, snd: EXPR) -> Self {
+        Self {
+            fst: Box::new(fst),
+            snd: Box::new(snd),
+        }
+    }
+    pub fn new_expr<SUM: Sum<Self>>(fst: EXPR, snd: EXPR) -> SUM {
+        SUM::pack(Self::new(fst, snd))
+    }
+    pub fn into_expr<SUM: Sum<Self>>(self) -> SUM {
+        SUM::pack(self)
+    }
+    pub fn fst(&self) -> &EXPR {
+        &self.fst
+    }
+    pub fn snd(&self) -> &EXPR {
+        &self.snd
+    }
+    pub fn get(&self, side: Side) -> &EXPR {
+        match side {
+            Side::Left => &self.fst,
+            Side::Right => &self.snd,
+        }
+    }
+}
+
+// A pair is a weak head normal form, so call-by-name leaves its components alone.
+impl<EXPR> Expression<EXPR> for Pair<EXPR>
+where
+    EXPR: Expression<EXPR> + Sum<Self> + Clone,
+{
+    fn is_value(&self) -> bool {
+        self.fst.is_value() && self.snd.is_value()
+    }
+
+    fn calc_step(&self, strategy: ReductionStrategy) -> Result<EXPR, CalcStepError> {
+        if strategy == ReductionStrategy::CallByName {
+            return Err(CalcStepError::NoRedex);
+        }
+        or_next(
+            self.fst
+                .calc_step(strategy)
+                .map(|fst| Self::new_expr(fst, (*self.snd).clone())),
+            || {
+                self.snd
+                    .calc_step(strategy)
+                    .map(|snd| Self::new_expr((*self.fst).clone(), snd))
+            },
+        )
+    }
+}
+
+impl<VAR, EXPR> Substitution<VAR, EXPR> for Pair<EXPR>
+where
+    EXPR: Substitution<VAR, EXPR> + Sum<Self>,
+{
+    fn substitute(&self, v: &VAR, e: &EXPR) -> EXPR {
+        Self::new_expr(self.fst.substitute(v, e), self.snd.substitute(v, e))
+    }
+}
+
+impl<VAR, EXPR> FreeVars<VAR> for Pair<EXPR>
+where
+    VAR: Ord,
+    EXPR: FreeVars<VAR>,
+{
+    fn free_vars(&self) -> BTreeSet<VAR> {
+        let mut vars = self.fst.free_vars();
+        vars.extend(self.snd.free_vars());
+        vars
+    }
+}
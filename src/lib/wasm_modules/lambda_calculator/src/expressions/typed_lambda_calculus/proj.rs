@@ -0,0 +1,101 @@
+use super::{pair::AsPair, Side};
+use crate::expressions::untyped_lambda_calculus::app::IsAtomic;
+use crate::expressions::{
+    CalcStepError, Expression, FreeVars, ReductionStrategy, Substitution, Sum,
+};
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+/// `fst e` or `snd e`.
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub struct Proj<EXPR> {
+    pub(crate) side: Side,
+    pub(crate) expr: Box<EXPR>,
+}
+
+impl<EXPR> Display for Proj<EXPR>
+where
+    EXPR: Display + IsAtomic,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self.side {
+            Side::Left => "fst",
+            Side::Right => "snd",
+        };
+        if self.expr.is_atomic() {
+            write!(f, "{name} {}", self.expr)
+        } else {
+            write!(f, "{name} ({})", self.expr)
+        }
+    }
+}
+
+impl<EXPR> Proj<EXPR> {
+    pub fn new(side: Side, expr: EXPR) -> Self {
+        Self {
+            side,
+            expr: Box::new(expr),
+        }
+    }
+    pub fn new_expr<SUM: Sum<Self>>(side: Side, expr: EXPR) -> SUM {
+        SUM::pack(Self::new(side, expr))
+    }
+    pub fn into_expr<SUM: Sum<Self>>(self) -> SUM {
+        SUM::pack(self)
+    }
+    pub fn side(&self) -> Side {
+        self.side
+    }
+    pub fn expr(&self) -> &EXPR {
+        &self.expr
+    }
+}
+
+impl<EXPR> Proj<EXPR>
+where
+    EXPR: Expression<EXPR> + AsPair<EXPR> + Sum<Self> + Clone,
+{
+    fn contract(&self) -> Result<EXPR, CalcStepError> {
+        self.expr
+            .as_pair()
+            .map(|pair| pair.get(self.side).clone())
+            .ok_or(CalcStepError::NoRedex)
+    }
+
+    fn step_expr(&self, strategy: ReductionStrategy) -> Result<EXPR, CalcStepError> {
+        self.expr
+            .calc_step(strategy)
+            .map(|expr| Self::new_expr(self.side, expr))
+    }
+}
+
+impl<EXPR> Expression<EXPR> for Proj<EXPR>
+where
+    EXPR: Expression<EXPR> + AsPair<EXPR> + Sum<Self> + Clone,
+{
+    fn is_value(&self) -> bool {
+        false
+    }
+
+    fn calc_step(&self, strategy: ReductionStrategy) -> Result<EXPR, CalcStepError> {
+        strategy.step_elim(|| self.contract(), || self.step_expr(strategy))
+    }
+}
+
+impl<VAR, EXPR> Substitution<VAR, EXPR> for Proj<EXPR>
+where
+    EXPR: Substitution<VAR, EXPR> + Sum<Self>,
+{
+    fn substitute(&self, v: &VAR, e: &EXPR) -> EXPR {
+        Self::new_expr(self.side, self.expr.substitute(v, e))
+    }
+}
+
+impl<VAR, EXPR> FreeVars<VAR> for Proj<EXPR>
+where
+    EXPR: FreeVars<VAR>,
+{
+    fn free_vars(&self) -> BTreeSet<VAR> {
+        self.expr.free_vars()
+    }
+}
@@ -0,0 +1,200 @@
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub enum Type {
+    Base(String),
+    Arrow(Box<Type>, Box<Type>),
+    Product(Box<Type>, Box<Type>),
+    Sum(Box<Type>, Box<Type>),
+    Unit,
+    Empty,
+    /// `μα. T`, whose values are `fold`ed values of `T[α := μα. T]`. Occurrences of `α` in `T`
+    /// are base types with that name.
+    Rec(String, Box<Type>),
+}
+
+impl Type {
+    pub fn base(name: &str) -> Self {
+        Self::Base(name.to_string())
+    }
+    pub fn arrow(from: Self, to: Self) -> Self {
+        Self::Arrow(Box::new(from), Box::new(to))
+    }
+    pub fn product(fst: Self, snd: Self) -> Self {
+        Self::Product(Box::new(fst), Box::new(snd))
+    }
+    pub fn sum(left: Self, right: Self) -> Self {
+        Self::Sum(Box::new(left), Box::new(right))
+    }
+    pub fn rec(var: &str, body: Self) -> Self {
+        Self::Rec(var.to_string(), Box::new(body))
+    }
+
+    /// `T[α := μα. T]` for `μα. T`, `None` for every other type.
+    pub fn unfold(&self) -> Option<Self> {
+        match self {
+            Type::Rec(var, body) => Some(body.substitute(var, self)),
+            _ => None,
+        }
+    }
+
+    pub fn free_names(&self) -> BTreeSet<String> {
+        match self {
+            Type::Base(name) => BTreeSet::from([name.clone()]),
+            Type::Arrow(a, b) | Type::Product(a, b) | Type::Sum(a, b) => {
+                let mut names = a.free_names();
+                names.extend(b.free_names());
+                names
+            }
+            Type::Unit | Type::Empty => BTreeSet::new(),
+            Type::Rec(var, body) => {
+                let mut names = body.free_names();
+                names.remove(var);
+                names
+            }
+        }
+    }
+
+    /// Replaces the base type `name` by `ty`, renaming `μ` binders that would capture it.
+    pub fn substitute(&self, name: &str, ty: &Self) -> Self {
+        match self {
+            Type::Base(n) if n == name => ty.clone(),
+            Type::Base(_) | Type::Unit | Type::Empty => self.clone(),
+            Type::Arrow(a, b) => Type::arrow(a.substitute(name, ty), b.substitute(name, ty)),
+            Type::Product(a, b) => Type::product(a.substitute(name, ty), b.substitute(name, ty)),
+            Type::Sum(a, b) => Type::sum(a.substitute(name, ty), b.substitute(name, ty)),
+            Type::Rec(var, _) if var == name => self.clone(),
+            Type::Rec(var, body) => {
+                let free_in_ty = ty.free_names();
+                if !free_in_ty.contains(var) {
+                    return Type::rec(var, body.substitute(name, ty));
+                }
+                let mut avoid = free_in_ty;
+                avoid.extend(body.free_names());
+                avoid.insert(name.to_string());
+                let base = var.trim_end_matches(|c: char| c.is_ascii_digit());
+                let fresh = (1..)
+                    .map(|i| format!("{base}{i}"))
+                    .find(|n| !avoid.contains(n))
+                    .expect("there are infinitely many fresh names");
+                let body = body.substitute(var, &Type::base(&fresh));
+                Type::rec(&fresh, body.substitute(name, ty))
+            }
+        }
+    }
+
+    /// Equality up to the names of `μ`-bound variables.
+    pub fn alpha_eq(&self, other: &Self) -> bool {
+        self.alpha_eq_in(other, &mut vec![])
+    }
+
+    fn alpha_eq_in(&self, other: &Self, bound: &mut Vec<(String, String)>) -> bool {
+        match (self, other) {
+            (Type::Base(a), Type::Base(b)) => {
+                match bound.iter().rev().find(|(x, y)| x == a || y == b) {
+                    Some((x, y)) => x == a && y == b,
+                    None => a == b,
+                }
+            }
+            (Type::Arrow(a1, b1), Type::Arrow(a2, b2))
+            | (Type::Product(a1, b1), Type::Product(a2, b2))
+            | (Type::Sum(a1, b1), Type::Sum(a2, b2)) => {
+                a1.alpha_eq_in(a2, bound) && b1.alpha_eq_in(b2, bound)
+            }
+            (Type::Unit, Type::Unit) | (Type::Empty, Type::Empty) => true,
+            (Type::Rec(x, a), Type::Rec(y, b)) => {
+                bound.push((x.clone(), y.clone()));
+                let eq = a.alpha_eq_in(b, bound);
+                bound.pop();
+                eq
+            }
+            _ => false,
+        }
+    }
+
+    pub fn as_proposition(&self) -> Proposition<'_> {
+        Proposition(self)
+    }
+
+    fn fmt_as(&self, f: &mut std::fmt::Formatter<'_>, logic: bool) -> std::fmt::Result {
+        match self {
+            Type::Base(name) => write!(f, "{name}"),
+            Type::Arrow(from, to) if logic && **to == Type::Empty => {
+                write!(f, "¬")?;
+                from.fmt_operand(f, 3, logic)
+            }
+            Type::Arrow(from, to) => Self::fmt_binary(f, from, "→", to, 0, logic),
+            Type::Sum(left, right) => {
+                Self::fmt_binary(f, left, if logic { "∨" } else { "+" }, right, 1, logic)
+            }
+            Type::Product(fst, snd) => {
+                Self::fmt_binary(f, fst, if logic { "∧" } else { "×" }, snd, 2, logic)
+            }
+            Type::Unit if logic => write!(f, "⊤"),
+            Type::Unit => write!(f, "Unit"),
+            Type::Empty if logic => write!(f, "⊥"),
+            Type::Empty => write!(f, "Empty"),
+            Type::Rec(var, body) => {
+                write!(f, "μ{var}. ")?;
+                body.fmt_as(f, logic)
+            }
+        }
+    }
+
+    // `→` binds loosest, then `+`, then `×`; all three associate to the right. A `μ` extends as
+    // far to the right as it can, like an arrow. Negations are atomic.
+    fn precedence(&self, logic: bool) -> u8 {
+        match self {
+            Type::Arrow(_, to) if logic && **to == Type::Empty => 3,
+            Type::Arrow(_, _) | Type::Rec(_, _) => 0,
+            Type::Sum(_, _) => 1,
+            Type::Product(_, _) => 2,
+            Type::Base(_) | Type::Unit | Type::Empty => 3,
+        }
+    }
+
+    fn fmt_operand(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        min: u8,
+        logic: bool,
+    ) -> std::fmt::Result {
+        if self.precedence(logic) < min {
+            write!(f, "(")?;
+            self.fmt_as(f, logic)?;
+            write!(f, ")")
+        } else {
+            self.fmt_as(f, logic)
+        }
+    }
+
+    fn fmt_binary(
+        f: &mut std::fmt::Formatter<'_>,
+        lhs: &Self,
+        op: &str,
+        rhs: &Self,
+        precedence: u8,
+        logic: bool,
+    ) -> std::fmt::Result {
+        lhs.fmt_operand(f, precedence + 1, logic)?;
+        write!(f, " {op} ")?;
+        rhs.fmt_operand(f, precedence, logic)
+    }
+}
+
+impl Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_as(f, false)
+    }
+}
+
+/// A type read as a proposition, displayed with `∧`, `∨`, `⊤`, `⊥` and `¬`.
+#[derive(Debug, Clone, Copy)]
+pub struct Proposition<'a>(&'a Type);
+
+impl Display for Proposition<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt_as(f, true)
+    }
+}
@@ -0,0 +1,88 @@
+use super::fold::AsFold;
+use crate::expressions::untyped_lambda_calculus::app::IsAtomic;
+use crate::expressions::{
+    CalcStepError, Expression, FreeVars, ReductionStrategy, Substitution, Sum,
+};
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+/// `unfold e`; its type follows from the recursive type of `e`.
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
+pub struct Unfold<EXPR> {
+    pub(crate) expr: Box<EXPR>,
+}
+
+impl<EXPR> Display for Unfold<EXPR>
+where
+    EXPR: Display + IsAtomic,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.expr.is_atomic() {
+            write!(f, "unfold {}", self.expr)
+        } else {
+            write!(f, "unfold ({})", self.expr)
+        }
+    }
+}
+
+impl<EXPR> Unfold<EXPR> {
+    pub fn new(expr: EXPR) -> Self {
+        Self {
+            expr: Box::new(expr),
+        }
+    }
+    pub fn new_expr<SUM: Sum<Self>>(expr: EXPR) -> SUM {
+        SUM::pack(Self::new(expr))
+    }
+    pub fn into_expr<SUM: Sum<Self>>(self) -> SUM {
+        SUM::pack(self)
+    }
+    pub fn expr(&self) -> &EXPR {
+        &self.expr
+    }
+}
+
+impl<EXPR> Unfold<EXPR>
+where
+    EXPR: Expression<EXPR> + AsFold<EXPR> + Sum<Self> + Clone,
+{
+    fn contract(&self) -> Result<EXPR, CalcStepError> {
+        self.expr.as_fold().cloned().ok_or(CalcStepError::NoRedex)
+    }
+
+    fn step_expr(&self, strategy: ReductionStrategy) -> Result<EXPR, CalcStepError> {
+        self.expr.calc_step(strategy).map(Self::new_expr)
+    }
+}
+
+// `unfold (fold v as T) → v`
+impl<EXPR> Expression<EXPR> for Unfold<EXPR>
+where
+    EXPR: Expression<EXPR> + AsFold<EXPR> + Sum<Self> + Clone,
+{
+    fn is_value(&self) -> bool {
+        false
+    }
+
+    fn calc_step(&self, strategy: ReductionStrategy) -> Result<EXPR, CalcStepError> {
+        strategy.step_elim(|| self.contract(), || self.step_expr(strategy))
+    }
+}
+
+impl<VAR, EXPR> Substitution<VAR, EXPR> for Unfold<EXPR>
+where
+    EXPR: Substitution<VAR, EXPR> + Sum<Self>,
+{
+    fn substitute(&self, v: &VAR, e: &EXPR) -> EXPR {
+        Self::new_expr(self.expr.substitute(v, e))
+    }
+}
+
+impl<VAR, EXPR> FreeVars<VAR> for Unfold<EXPR>
+where
+    EXPR: FreeVars<VAR>,
+{
+    fn free_vars(&self) -> BTreeSet<VAR> {
+        self.expr.free_vars()
+    }
+}
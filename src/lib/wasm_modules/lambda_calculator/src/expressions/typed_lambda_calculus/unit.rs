@@ -0,0 +1,46 @@
+use crate::expressions::{
+    CalcStepError, Expression, FreeVars, ReductionStrategy, Substitution, Sum,
+};
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+/// `()`, the only value of type `Unit`.
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone, Copy)]
+pub struct Unit;
+
+impl Display for Unit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "()")
+    }
+}
+
+impl Unit {
+    pub fn new_expr<EXPR: Sum<Self>>() -> EXPR {
+        EXPR::pack(Self)
+    }
+}
+
+impl<EXPR> Expression<EXPR> for Unit {
+    fn is_value(&self) -> bool {
+        true
+    }
+
+    fn calc_step(&self, _strategy: ReductionStrategy) -> Result<EXPR, CalcStepError> {
+        Err(CalcStepError::NoRedex)
+    }
+}
+
+impl<VAR, EXPR> Substitution<VAR, EXPR> for Unit
+where
+    EXPR: Sum<Self>,
+{
+    fn substitute(&self, _v: &VAR, _e: &EXPR) -> EXPR {
+        Self::new_expr()
+    }
+}
+
+impl<VAR> FreeVars<VAR> for Unit {
+    fn free_vars(&self) -> BTreeSet<VAR> {
+        BTreeSet::new()
+    }
+}
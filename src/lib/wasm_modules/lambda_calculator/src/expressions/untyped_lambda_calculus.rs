@@ -1,9 +1,10 @@
 use self::{
-    app::{App, IsAbs},
+    app::{App, ApplyAbs, IsAbs, IsAtomic},
     unty_abs::UntyAbs,
     unty_var::UntyVar,
 };
-use super::{Expression, Substitution, Sum};
+use super::{CalcStepError, Expression, FreeVars, ReductionStrategy, Substitution, Sum};
+use std::collections::BTreeSet;
 use std::fmt::{Debug, Display};
 pub mod app;
 pub mod unty_abs;
@@ -16,6 +17,74 @@ pub enum UntyLamExpr {
     App(App<Self>),
 }
 
+impl UntyLamExpr {
+    pub fn is_normal_form(&self, strategy: ReductionStrategy) -> bool {
+        self.calc_step(strategy).is_err()
+    }
+
+    pub fn size(&self) -> u32 {
+        match self {
+            UntyLamExpr::Var(_) => 1,
+            UntyLamExpr::Abs(abs) => 1 + abs.expr.size(),
+            UntyLamExpr::App(app) => 1 + app.lhs.size() + app.rhs.size(),
+        }
+    }
+
+    /// Equality up to the names of bound variables.
+    pub fn alpha_eq(&self, other: &Self) -> bool {
+        self.alpha_eq_in(other, &mut vec![])
+    }
+
+    fn alpha_eq_in(&self, other: &Self, bound: &mut Vec<(UntyVar, UntyVar)>) -> bool {
+        match (self, other) {
+            (UntyLamExpr::Var(a), UntyLamExpr::Var(b)) => {
+                match bound.iter().rev().find(|(x, y)| x == a || y == b) {
+                    Some((x, y)) => x == a && y == b,
+                    None => a == b,
+                }
+            }
+            (UntyLamExpr::Abs(a), UntyLamExpr::Abs(b)) => {
+                bound.push((a.var.clone(), b.var.clone()));
+                let eq = a.expr.alpha_eq_in(&b.expr, bound);
+                bound.pop();
+                eq
+            }
+            (UntyLamExpr::App(a), UntyLamExpr::App(b)) => {
+                a.lhs.alpha_eq_in(&b.lhs, bound) && a.rhs.alpha_eq_in(&b.rhs, bound)
+            }
+            _ => false,
+        }
+    }
+
+    /// Contracts every `λx. M x` with `x` not free in `M` to `M`, innermost first.
+    pub fn eta_reduce(&self) -> Self {
+        match self {
+            UntyLamExpr::Var(_) => self.clone(),
+            UntyLamExpr::Abs(abs) => {
+                let body = abs.expr.eta_reduce();
+                match &body {
+                    UntyLamExpr::App(app)
+                        if *app.rhs == UntyLamExpr::Var(abs.var.clone())
+                            && !app.lhs.free_vars().contains(&abs.var) =>
+                    {
+                        (*app.lhs).clone()
+                    }
+                    _ => UntyAbs::new_expr(abs.var.clone(), body),
+                }
+            }
+            UntyLamExpr::App(app) => App::new_expr(app.lhs.eta_reduce(), app.rhs.eta_reduce()),
+        }
+    }
+
+    pub fn depth(&self) -> u32 {
+        match self {
+            UntyLamExpr::Var(_) => 1,
+            UntyLamExpr::Abs(abs) => 1 + abs.expr.depth(),
+            UntyLamExpr::App(app) => 1 + app.lhs.depth().max(app.rhs.depth()),
+        }
+    }
+}
+
 impl Sum<UntyVar> for UntyLamExpr {
     fn pack(content: UntyVar) -> Self {
         Self::Var(content)
@@ -40,11 +109,11 @@ impl Expression<Self> for UntyLamExpr {
         }
     }
 
-    fn calc_step(&self) -> Result<Self, super::CalcStepError> {
+    fn calc_step(&self, strategy: ReductionStrategy) -> Result<Self, CalcStepError> {
         match self {
-            UntyLamExpr::Var(e) => e.calc_step(),
-            UntyLamExpr::Abs(e) => e.calc_step(),
-            UntyLamExpr::App(e) => e.calc_step(),
+            UntyLamExpr::Var(e) => e.calc_step(strategy),
+            UntyLamExpr::Abs(e) => e.calc_step(strategy),
+            UntyLamExpr::App(e) => e.calc_step(strategy),
         }
     }
 }
@@ -57,6 +126,15 @@ impl Substitution<UntyVar, Self> for UntyLamExpr {
         }
     }
 }
+impl FreeVars<UntyVar> for UntyLamExpr {
+    fn free_vars(&self) -> BTreeSet<UntyVar> {
+        match self {
+            UntyLamExpr::Var(exp) => exp.free_vars(),
+            UntyLamExpr::Abs(exp) => exp.free_vars(),
+            UntyLamExpr::App(exp) => exp.free_vars(),
+        }
+    }
+}
 
 impl IsAbs for UntyLamExpr {
     fn is_abs(&self) -> bool {
@@ -64,6 +142,21 @@ impl IsAbs for UntyLamExpr {
     }
 }
 
+impl IsAtomic for UntyLamExpr {
+    fn is_atomic(&self) -> bool {
+        matches!(self, Self::Var(_))
+    }
+}
+
+impl ApplyAbs<Self> for UntyLamExpr {
+    fn apply_abs(&self, arg: &Self) -> Option<Self> {
+        match self {
+            UntyLamExpr::Abs(abs) => Some(abs.apply(arg)),
+            _ => None,
+        }
+    }
+}
+
 impl Display for UntyLamExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -73,3 +166,102 @@ impl Display for UntyLamExpr {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::UntyLamExpr;
+    use crate::expressions::{Expression, ReductionStrategy};
+    use crate::parsers::untyped_lambda_calculus::UntypedLambdaCalculusParser;
+
+    fn parse(input: &str) -> UntyLamExpr {
+        UntypedLambdaCalculusParser::parse(input).unwrap()
+    }
+
+    fn step(input: &str, strategy: ReductionStrategy) -> Option<UntyLamExpr> {
+        parse(input).calc_step(strategy).ok()
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for input in [
+            "x",
+            "λ x. x",
+            "(λ x. x) y",
+            "x (λ y. y) z",
+            "x (y z)",
+            "(λ x. x x) (λ x. x x)",
+            "λ f. (λ x. f (x x)) (λ x. f (x x))",
+        ] {
+            assert_eq!(parse(input).to_string(), input);
+        }
+    }
+
+    #[test]
+    fn alpha_and_eta() {
+        assert!(parse("λx y. x (λz. y z)").alpha_eq(&parse("λa b. a (λc. b c)")));
+        assert!(!parse("λx y. x").alpha_eq(&parse("λx y. y")));
+        assert!(!parse("λx. y").alpha_eq(&parse("λy. y")));
+        assert_eq!(
+            parse("λx y. x (λz. y z)").eta_reduce().to_string(),
+            "λ x. x"
+        );
+        assert_eq!(parse("λx. x x").eta_reduce().to_string(), "λ x. x x");
+    }
+
+    #[test]
+    fn substitution_avoids_capture() {
+        assert_eq!(
+            step("(λx y. x) y", ReductionStrategy::NormalOrder),
+            Some(parse("λy1. y"))
+        );
+        assert_eq!(
+            step("(λx y. x y1) (y y1)", ReductionStrategy::NormalOrder),
+            Some(parse("λy2. y y1 y1"))
+        );
+        assert_eq!(
+            step("(λx. λx. x) y", ReductionStrategy::NormalOrder),
+            Some(parse("λx. x"))
+        );
+        assert_eq!(
+            step("(λx. λy. z) y", ReductionStrategy::NormalOrder),
+            Some(parse("λy. z"))
+        );
+    }
+
+    #[test]
+    fn strategies() {
+        let input = "(λx. z) ((λy. y) w)";
+        assert_eq!(
+            step(input, ReductionStrategy::NormalOrder),
+            Some(parse("z"))
+        );
+        assert_eq!(step(input, ReductionStrategy::CallByName), Some(parse("z")));
+        assert_eq!(
+            step(input, ReductionStrategy::ApplicativeOrder),
+            Some(parse("(λx. z) w"))
+        );
+        assert_eq!(
+            step(input, ReductionStrategy::CallByValue),
+            Some(parse("(λx. z) w"))
+        );
+
+        let input = "λa. (λx. x) a";
+        assert_eq!(
+            step(input, ReductionStrategy::NormalOrder),
+            Some(parse("λa. a"))
+        );
+        assert_eq!(
+            step(input, ReductionStrategy::ApplicativeOrder),
+            Some(parse("λa. a"))
+        );
+        assert_eq!(step(input, ReductionStrategy::CallByName), None);
+        assert_eq!(step(input, ReductionStrategy::CallByValue), None);
+
+        let input = "x ((λy. y) z)";
+        assert_eq!(
+            step(input, ReductionStrategy::NormalOrder),
+            Some(parse("x z"))
+        );
+        assert_eq!(step(input, ReductionStrategy::CallByName), None);
+    }
+}
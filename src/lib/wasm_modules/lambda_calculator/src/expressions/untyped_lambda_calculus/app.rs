@@ -1,7 +1,10 @@
-use crate::expressions::Substitution;
+use crate::annotation::StepRule;
+use crate::expressions::{FreeVars, ReductionStrategy, RuleStep, Substitution};
 
+use super::super::CalcStepError;
 use super::super::Expression;
 use super::super::Sum;
+use std::collections::BTreeSet;
 use std::fmt::Display;
 
 #[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
@@ -12,10 +15,19 @@ pub struct App<EXPR> {
 
 impl<EXPR> Display for App<EXPR>
 where
-    EXPR: Display,
+    EXPR: Display + IsAbs + IsAtomic,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} {}", self.lhs, self.rhs)
+        if self.lhs.is_abs() {
+            write!(f, "({})", self.lhs)?;
+        } else {
+            write!(f, "{}", self.lhs)?;
+        }
+        if self.rhs.is_atomic() {
+            write!(f, " {}", self.rhs)
+        } else {
+            write!(f, " ({})", self.rhs)
+        }
     }
 }
 
@@ -23,6 +35,14 @@ pub trait IsAbs {
     fn is_abs(&self) -> bool;
 }
 
+pub trait IsAtomic {
+    fn is_atomic(&self) -> bool;
+}
+
+pub trait ApplyAbs<EXPR> {
+    fn apply_abs(&self, arg: &EXPR) -> Option<EXPR>;
+}
+
 impl<EXPR> App<EXPR>
 where
     EXPR: Sum<Self>,
@@ -44,13 +64,76 @@ where
     }
 }
 
-impl<EXPR> Expression<EXPR> for App<EXPR> {
+impl<EXPR> App<EXPR> {
+    pub fn lhs(&self) -> &EXPR {
+        &self.lhs
+    }
+    pub fn rhs(&self) -> &EXPR {
+        &self.rhs
+    }
+}
+
+impl<EXPR> App<EXPR>
+where
+    EXPR: Expression<EXPR> + ApplyAbs<EXPR> + Sum<Self> + Clone,
+{
+    fn beta(&self) -> Result<EXPR, CalcStepError> {
+        self.lhs.apply_abs(&self.rhs).ok_or(CalcStepError::NoRedex)
+    }
+
+    fn step_lhs(&self, strategy: ReductionStrategy) -> Result<EXPR, CalcStepError> {
+        self.lhs
+            .calc_step(strategy)
+            .map(|lhs| Self::new_expr(lhs, (*self.rhs).clone()))
+    }
+
+    fn step_rhs(&self, strategy: ReductionStrategy) -> Result<EXPR, CalcStepError> {
+        self.rhs
+            .calc_step(strategy)
+            .map(|rhs| Self::new_expr((*self.lhs).clone(), rhs))
+    }
+}
+
+impl<EXPR> Expression<EXPR> for App<EXPR>
+where
+    EXPR: Expression<EXPR> + ApplyAbs<EXPR> + Sum<Self> + Clone,
+{
     fn is_value(&self) -> bool {
-        todo!()
+        false
+    }
+
+    fn calc_step(&self, strategy: ReductionStrategy) -> Result<EXPR, CalcStepError> {
+        strategy.step_app(
+            || self.beta(),
+            || self.step_lhs(strategy),
+            || self.step_rhs(strategy),
+        )
     }
+}
 
-    fn calc_step(&self) -> Result<EXPR, crate::expressions::CalcStepError> {
-        todo!()
+// Only the beta step at the root is attributed to this node.
+impl<EXPR> RuleStep<EXPR> for App<EXPR>
+where
+    EXPR: RuleStep<EXPR> + ApplyAbs<EXPR> + Sum<Self> + Clone,
+{
+    fn rule_step(&self, strategy: ReductionStrategy) -> Result<(EXPR, StepRule), CalcStepError> {
+        let beta = || {
+            self.lhs
+                .apply_abs(&self.rhs)
+                .map(|e| (e, StepRule::Beta))
+                .ok_or(CalcStepError::NoRedex)
+        };
+        let step_lhs = || {
+            self.lhs
+                .rule_step(strategy)
+                .map(|(lhs, rule)| (Self::new_expr(lhs, (*self.rhs).clone()), rule))
+        };
+        let step_rhs = || {
+            self.rhs
+                .rule_step(strategy)
+                .map(|(rhs, rule)| (Self::new_expr((*self.lhs).clone(), rhs), rule))
+        };
+        strategy.step_app(beta, step_lhs, step_rhs)
     }
 }
 
@@ -66,3 +149,15 @@ where
         .into_expr()
     }
 }
+
+impl<VAR, EXPR> FreeVars<VAR> for App<EXPR>
+where
+    VAR: Ord,
+    EXPR: FreeVars<VAR>,
+{
+    fn free_vars(&self) -> BTreeSet<VAR> {
+        let mut vars = self.lhs.free_vars();
+        vars.extend(self.rhs.free_vars());
+        vars
+    }
+}
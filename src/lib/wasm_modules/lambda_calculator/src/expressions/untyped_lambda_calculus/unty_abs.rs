@@ -1,8 +1,11 @@
-use crate::expressions::Substitution;
+use crate::annotation::StepRule;
+use crate::expressions::{FreeVars, ReductionStrategy, RuleStep, Substitution};
 
+use super::super::CalcStepError;
 use super::super::Expression;
 use super::super::Sum;
 use super::UntyVar;
+use std::collections::BTreeSet;
 use std::fmt::Display;
 
 #[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
@@ -36,31 +39,125 @@ impl<EXPR> UntyAbs<EXPR> {
     pub fn into_expr<SUM: Sum<Self>>(self) -> SUM {
         SUM::pack(self)
     }
+    pub fn var(&self) -> &UntyVar {
+        &self.var
+    }
+    pub fn body(&self) -> &EXPR {
+        &self.expr
+    }
 }
 
-impl<EXPR> Expression<EXPR> for UntyAbs<EXPR> {
-    fn is_value(&self) -> bool {
-        todo!()
+impl<EXPR> UntyAbs<EXPR>
+where
+    EXPR: Substitution<UntyVar, EXPR>,
+{
+    pub fn apply(&self, arg: &EXPR) -> EXPR {
+        self.expr.substitute(&self.var, arg)
+    }
+}
+
+impl<EXPR> UntyAbs<EXPR>
+where
+    EXPR: FreeVars<UntyVar>,
+{
+    // a variable for the binder that is neither in `avoid` nor free in the body
+    fn fresh_var(&self, avoid: &BTreeSet<UntyVar>) -> UntyVar {
+        let mut avoid = avoid.clone();
+        avoid.extend(self.expr.free_vars());
+        self.var.fresh(&avoid)
     }
 
-    fn calc_step(&self) -> Result<EXPR, crate::expressions::CalcStepError> {
-        todo!()
+    /// The binder of the abstraction after substituting `v` by a term with the free variables
+    /// `free_in_e`: a fresh one if the current one would capture them, and `None` if `v` isn't
+    /// free in the abstraction so that it stays unchanged.
+    pub fn substitution_binder(
+        &self,
+        v: &UntyVar,
+        free_in_e: &BTreeSet<UntyVar>,
+    ) -> Option<UntyVar> {
+        if self.var == *v || !self.expr.free_vars().contains(v) {
+            None
+        } else if free_in_e.contains(&self.var) {
+            Some(self.fresh_var(free_in_e))
+        } else {
+            Some(self.var.clone())
+        }
     }
 }
 
-impl<EXPR> Substitution<UntyVar, EXPR> for UntyAbs<EXPR>
+impl<EXPR> UntyAbs<EXPR>
 where
-    EXPR: Substitution<UntyVar, EXPR> + Sum<Self> + Clone,
+    EXPR: Substitution<UntyVar, EXPR> + FreeVars<UntyVar> + Sum<UntyVar>,
 {
-    fn substitute(&self, v: &UntyVar, e: &EXPR) -> EXPR {
-        if self.var == *v {
-            self.clone().into_expr()
-        } else {
+    pub fn rename(&self, avoid: &BTreeSet<UntyVar>) -> Self {
+        let fresh = self.fresh_var(avoid);
+        Self {
+            expr: Box::new(self.expr.substitute(&self.var, &fresh.clone().into_expr())),
+            var: fresh,
+        }
+    }
+}
+
+impl<EXPR> Expression<EXPR> for UntyAbs<EXPR>
+where
+    EXPR: Expression<EXPR> + Sum<Self>,
+{
+    fn is_value(&self) -> bool {
+        true
+    }
+
+    fn calc_step(&self, strategy: ReductionStrategy) -> Result<EXPR, CalcStepError> {
+        if !strategy.reduces_under_abs() {
+            return Err(CalcStepError::NoRedex);
+        }
+        self.expr.calc_step(strategy).map(|expr| {
             Self {
-                var: v.clone(),
-                expr: Box::new(self.expr.substitute(v, e)),
+                var: self.var.clone(),
+                expr: Box::new(expr),
             }
             .into_expr()
+        })
+    }
+}
+
+impl<EXPR> RuleStep<EXPR> for UntyAbs<EXPR>
+where
+    EXPR: RuleStep<EXPR> + Sum<Self>,
+{
+    fn rule_step(&self, strategy: ReductionStrategy) -> Result<(EXPR, StepRule), CalcStepError> {
+        if !strategy.reduces_under_abs() {
+            return Err(CalcStepError::NoRedex);
         }
+        self.expr
+            .rule_step(strategy)
+            .map(|(expr, rule)| (Self::new_expr(self.var.clone(), expr), rule))
+    }
+}
+
+impl<EXPR> Substitution<UntyVar, EXPR> for UntyAbs<EXPR>
+where
+    EXPR: Substitution<UntyVar, EXPR> + FreeVars<UntyVar> + Sum<Self> + Sum<UntyVar> + Clone,
+{
+    fn substitute(&self, v: &UntyVar, e: &EXPR) -> EXPR {
+        let Some(var) = self.substitution_binder(v, &e.free_vars()) else {
+            return self.clone().into_expr();
+        };
+        let body = if var == self.var {
+            (*self.expr).clone()
+        } else {
+            self.expr.substitute(&self.var, &var.clone().into_expr())
+        };
+        Self::new_expr(var, body.substitute(v, e))
+    }
+}
+
+impl<EXPR> FreeVars<UntyVar> for UntyAbs<EXPR>
+where
+    EXPR: FreeVars<UntyVar>,
+{
+    fn free_vars(&self) -> BTreeSet<UntyVar> {
+        let mut vars = self.expr.free_vars();
+        vars.remove(&self.var);
+        vars
     }
 }
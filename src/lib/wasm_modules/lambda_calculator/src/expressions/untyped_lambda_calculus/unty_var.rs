@@ -1,8 +1,10 @@
-use crate::expressions::Substitution;
+use crate::annotation::StepRule;
+use crate::expressions::{FreeVars, ReductionStrategy, RuleStep, Substitution};
 
 use super::super::CalcStepError;
 use super::super::Expression;
 use super::super::Sum;
+use std::collections::BTreeSet;
 use std::fmt::Display;
 
 #[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Clone)]
@@ -22,6 +24,9 @@ impl UntyVar {
             name: name.to_string(),
         }
     }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
     pub fn new_expr<EXPR: Sum<Self>>(name: &str) -> EXPR {
         EXPR::pack(Self {
             name: name.to_string(),
@@ -30,6 +35,13 @@ impl UntyVar {
     pub fn into_expr<EXPR: Sum<Self>>(self) -> EXPR {
         EXPR::pack(self)
     }
+    pub fn fresh(&self, avoid: &BTreeSet<Self>) -> Self {
+        let base = self.name.trim_end_matches(|c: char| c.is_ascii_digit());
+        (1..)
+            .map(|i| Self::new(&format!("{base}{i}")))
+            .find(|v| !avoid.contains(v))
+            .expect("there are infinitely many fresh names")
+    }
 }
 
 impl<EXPR> Expression<EXPR> for UntyVar {
@@ -37,8 +49,14 @@ impl<EXPR> Expression<EXPR> for UntyVar {
         true
     }
 
-    fn calc_step(&self) -> Result<EXPR, CalcStepError> {
-        todo!()
+    fn calc_step(&self, _strategy: ReductionStrategy) -> Result<EXPR, CalcStepError> {
+        Err(CalcStepError::NoRedex)
+    }
+}
+
+impl<EXPR> RuleStep<EXPR> for UntyVar {
+    fn rule_step(&self, _strategy: ReductionStrategy) -> Result<(EXPR, StepRule), CalcStepError> {
+        Err(CalcStepError::NoRedex)
     }
 }
 
@@ -54,3 +72,9 @@ where
         }
     }
 }
+
+impl FreeVars<Self> for UntyVar {
+    fn free_vars(&self) -> BTreeSet<Self> {
+        BTreeSet::from([self.clone()])
+    }
+}
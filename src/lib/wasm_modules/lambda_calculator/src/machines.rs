@@ -0,0 +1,182 @@
+use crate::expressions::untyped_lambda_calculus::{
+    app::App, unty_abs::UntyAbs, unty_var::UntyVar, UntyLamExpr,
+};
+use crate::expressions::FreeVars;
+use std::collections::BTreeSet;
+use std::fmt::{Debug, Display};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+pub mod krivine;
+pub mod secd;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub enum AbstractMachine {
+    /// Call-by-name evaluation to weak head normal form.
+    #[default]
+    Krivine = 0,
+    /// Landin's machine for call-by-value evaluation of compiled terms.
+    Secd = 1,
+}
+
+impl Display for AbstractMachine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AbstractMachine::Krivine => write!(f, "Krivine machine"),
+            AbstractMachine::Secd => write!(f, "SECD machine"),
+        }
+    }
+}
+
+/// An abstract machine that evaluates lambda terms by transitions between states.
+pub trait Machine: Clone + Debug {
+    type Rule: Display + Debug + Clone;
+
+    /// The next state and the rule leading to it, `None` in a final state.
+    fn step(&self) -> Option<(Self, Self::Rule)>;
+    /// Name and contents of every register, for display.
+    fn registers(&self) -> Vec<(&'static str, String)>;
+    /// Whether `step` returns `None`, without building the next state.
+    fn is_final(&self) -> bool;
+    /// The term the state stands for, if the machine can read it back in this state.
+    fn read_back(&self) -> Option<UntyLamExpr>;
+}
+
+/// The states a machine went through, with the rule that led to each of them. Stepping
+/// after an undo drops the undone states.
+#[derive(Debug, Clone)]
+pub struct Trace<M: Machine> {
+    states: Vec<(M, Option<M::Rule>)>,
+    index: usize,
+}
+
+impl<M: Machine> Trace<M> {
+    pub fn new(initial: M) -> Self {
+        Self {
+            states: vec![(initial, None)],
+            index: 0,
+        }
+    }
+
+    pub fn current(&self) -> &M {
+        &self.states[self.index].0
+    }
+
+    /// The rule of the last transition, `None` in the initial state.
+    pub fn rule(&self) -> Option<&M::Rule> {
+        self.states[self.index].1.as_ref()
+    }
+
+    /// Transitions from the current state, `None` if it is final.
+    pub fn step(&mut self) -> Option<&M> {
+        let (next, rule) = self.current().step()?;
+        self.states.truncate(self.index + 1);
+        self.states.push((next, Some(rule)));
+        self.index += 1;
+        Some(self.current())
+    }
+
+    /// Takes at most `max_steps` transitions and returns how many were taken.
+    pub fn run(&mut self, max_steps: u32) -> u32 {
+        let mut steps = 0;
+        while steps < max_steps && self.step().is_some() {
+            steps += 1;
+        }
+        steps
+    }
+
+    /// Transitions taken to reach the current state.
+    pub fn steps(&self) -> usize {
+        self.index
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.index > 0
+    }
+
+    pub fn can_redo(&self) -> bool {
+        self.index + 1 < self.states.len()
+    }
+
+    pub fn undo(&mut self) -> bool {
+        let can_undo = self.can_undo();
+        if can_undo {
+            self.index -= 1;
+        }
+        can_undo
+    }
+
+    pub fn redo(&mut self) -> bool {
+        let can_redo = self.can_redo();
+        if can_redo {
+            self.index += 1;
+        }
+        can_redo
+    }
+}
+
+// the newest binding of `var`
+fn lookup<'a, T>(env: &'a [(UntyVar, T)], var: &UntyVar) -> Option<&'a T> {
+    env.iter()
+        .rev()
+        .find(|(x, _)| x == var)
+        .map(|(_, value)| value)
+}
+
+struct EnvDisplay<'a, T>(&'a [(UntyVar, T)]);
+
+impl<T: Display> Display for EnvDisplay<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[")?;
+        for (i, (var, value)) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{var} ↦ {value}")?;
+        }
+        write!(f, "]")
+    }
+}
+
+/// `term` with the variables bound in `env` replaced by their values read back as terms.
+fn read_back_in<T>(
+    term: &UntyLamExpr,
+    env: &[(UntyVar, T)],
+    read_back: impl Fn(&T) -> UntyLamExpr,
+) -> UntyLamExpr {
+    let values: Vec<_> = term
+        .free_vars()
+        .into_iter()
+        .filter_map(|var| Some((var.clone(), read_back(lookup(env, &var)?))))
+        .collect();
+    substitute_all(term, &values)
+}
+
+// Substitutes all `values` at once, renaming binders that would capture a free variable of a
+// value.
+fn substitute_all(term: &UntyLamExpr, values: &[(UntyVar, UntyLamExpr)]) -> UntyLamExpr {
+    match term {
+        UntyLamExpr::Var(var) => lookup(values, var).unwrap_or(term).clone(),
+        UntyLamExpr::App(app) => App::new_expr(
+            substitute_all(&app.lhs, values),
+            substitute_all(&app.rhs, values),
+        ),
+        UntyLamExpr::Abs(abs) => {
+            let free = abs.expr.free_vars();
+            let inserted: BTreeSet<_> = free
+                .iter()
+                .filter(|var| **var != abs.var)
+                .filter_map(|var| lookup(values, var))
+                .flat_map(|value| value.free_vars())
+                .collect();
+            let binder = if inserted.contains(&abs.var) {
+                abs.var.fresh(&inserted.union(&free).cloned().collect())
+            } else {
+                abs.var.clone()
+            };
+            let mut values = values.to_vec();
+            values.push((abs.var.clone(), binder.clone().into_expr()));
+            UntyAbs::new_expr(binder, substitute_all(&abs.expr, &values))
+        }
+    }
+}
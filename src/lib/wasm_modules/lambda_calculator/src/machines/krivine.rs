@@ -0,0 +1,206 @@
+use super::{lookup, read_back_in, EnvDisplay, Machine};
+use crate::expressions::untyped_lambda_calculus::{app::App, unty_var::UntyVar, UntyLamExpr};
+use std::fmt::Display;
+use std::rc::Rc;
+
+/// Bindings from the oldest to the newest, later bindings shadow earlier ones.
+pub type Environment = Vec<(UntyVar, Rc<Closure>)>;
+
+/// A term together with the values of its free variables.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Closure {
+    pub term: UntyLamExpr,
+    pub env: Environment,
+}
+
+impl Closure {
+    pub fn new(term: UntyLamExpr, env: Environment) -> Self {
+        Self { term, env }
+    }
+
+    /// The term with the environment substituted into it.
+    pub fn read_back(&self) -> UntyLamExpr {
+        read_back_in(&self.term, &self.env, |closure| closure.read_back())
+    }
+}
+
+impl Display for Closure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "⟨{}, {}⟩", self.term, EnvDisplay(&self.env))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum KrivineRule {
+    /// `(M N, e, s) → (M, e, ⟨N, e⟩ :: s)`
+    Push,
+    /// `(λx. M, e, c :: s) → (M, e[x ↦ c], s)`
+    Grab,
+    /// `(x, e, s) → (M, e', s)` where `e(x) = ⟨M, e'⟩`
+    Access,
+}
+
+impl Display for KrivineRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KrivineRule::Push => write!(f, "push"),
+            KrivineRule::Grab => write!(f, "grab"),
+            KrivineRule::Access => write!(f, "access"),
+        }
+    }
+}
+
+/// Krivine's machine for call-by-name evaluation to weak head normal form. It stops at an
+/// abstraction with an empty stack or at a free variable.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct KrivineMachine {
+    closure: Closure,
+    /// Arguments with the next one last.
+    stack: Vec<Rc<Closure>>,
+}
+
+impl KrivineMachine {
+    pub fn new(term: UntyLamExpr) -> Self {
+        Self {
+            closure: Closure::new(term, vec![]),
+            stack: vec![],
+        }
+    }
+
+    pub fn term(&self) -> &UntyLamExpr {
+        &self.closure.term
+    }
+
+    pub fn env(&self) -> &Environment {
+        &self.closure.env
+    }
+
+    /// The arguments, the next one first.
+    pub fn stack(&self) -> impl Iterator<Item = &Closure> {
+        self.stack.iter().rev().map(|c| &**c)
+    }
+}
+
+impl Machine for KrivineMachine {
+    type Rule = KrivineRule;
+
+    fn step(&self) -> Option<(Self, KrivineRule)> {
+        let env = &self.closure.env;
+        match &self.closure.term {
+            UntyLamExpr::App(app) => {
+                let mut stack = self.stack.clone();
+                stack.push(Rc::new(Closure::new((*app.rhs).clone(), env.clone())));
+                let closure = Closure::new((*app.lhs).clone(), env.clone());
+                Some((Self { closure, stack }, KrivineRule::Push))
+            }
+            UntyLamExpr::Abs(abs) => {
+                let mut stack = self.stack.clone();
+                let arg = stack.pop()?;
+                let mut env = env.clone();
+                env.push((abs.var.clone(), arg));
+                let closure = Closure::new((*abs.expr).clone(), env);
+                Some((Self { closure, stack }, KrivineRule::Grab))
+            }
+            UntyLamExpr::Var(var) => {
+                let closure = (**lookup(env, var)?).clone();
+                let stack = self.stack.clone();
+                Some((Self { closure, stack }, KrivineRule::Access))
+            }
+        }
+    }
+
+    fn is_final(&self) -> bool {
+        match &self.closure.term {
+            UntyLamExpr::App(_) => false,
+            UntyLamExpr::Abs(_) => self.stack.is_empty(),
+            UntyLamExpr::Var(var) => lookup(&self.closure.env, var).is_none(),
+        }
+    }
+
+    fn registers(&self) -> Vec<(&'static str, String)> {
+        let stack: Vec<_> = self.stack().map(|c| c.to_string()).collect();
+        vec![
+            ("term", self.closure.term.to_string()),
+            ("environment", EnvDisplay(&self.closure.env).to_string()),
+            ("stack", format!("[{}]", stack.join(", "))),
+        ]
+    }
+
+    // the current closure applied to the arguments on the stack
+    fn read_back(&self) -> Option<UntyLamExpr> {
+        Some(self.stack().fold(self.closure.read_back(), |acc, arg| {
+            App::new_expr(acc, arg.read_back())
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KrivineMachine, KrivineRule};
+    use crate::machines::{Machine, Trace};
+    use crate::parsers::untyped_lambda_calculus::UntypedLambdaCalculusParser;
+
+    fn run(input: &str) -> (String, Vec<KrivineRule>) {
+        let mut machine = KrivineMachine::new(UntypedLambdaCalculusParser::parse(input).unwrap());
+        let mut rules = vec![];
+        while let Some((next, rule)) = machine.step() {
+            machine = next;
+            rules.push(rule);
+        }
+        (machine.read_back().unwrap().to_string(), rules)
+    }
+
+    #[test]
+    fn transitions() {
+        use KrivineRule::*;
+        assert_eq!(
+            run("(λx. x) y"),
+            ("y".to_string(), vec![Push, Grab, Access])
+        );
+        assert_eq!(
+            run("(λx y. x) a ((λx. x x) (λx. x x))"),
+            ("a".to_string(), vec![Push, Push, Grab, Grab, Access])
+        );
+        assert_eq!(run("λx. (λy. y) x").1, vec![]);
+    }
+
+    #[test]
+    fn weak_head_normal_forms() {
+        assert_eq!(run("x ((λy. y) z)").0, "x ((λ y. y) z)");
+        assert_eq!(run("(λx y. x) y").0, "λ y1. y");
+        assert_eq!(run("(λf x. f (f x)) (λy. y) z").0, "z");
+        assert_eq!(run("(λx. λy. x y) (λz. z)").0, "λ y. (λ z. z) y");
+    }
+
+    #[test]
+    fn registers() {
+        let e = UntypedLambdaCalculusParser::parse("(λx. x x) (λy. y)").unwrap();
+        let mut trace = Trace::new(KrivineMachine::new(e));
+        trace.run(2);
+        assert_eq!(trace.rule(), Some(&KrivineRule::Grab));
+        assert_eq!(
+            trace.current().registers(),
+            vec![
+                ("term", "x x".to_string()),
+                ("environment", "[x ↦ ⟨λ y. y, []⟩]".to_string()),
+                ("stack", "[]".to_string()),
+            ]
+        );
+        trace.step();
+        assert_eq!(
+            trace.current().registers()[2].1,
+            "[⟨x, [x ↦ ⟨λ y. y, []⟩]⟩]"
+        );
+        assert!(!trace.current().is_final());
+        assert_eq!(trace.run(100), 4);
+        assert!(trace.current().is_final());
+        assert_eq!(trace.current().read_back().unwrap().to_string(), "λ y. y");
+        assert!(trace.undo() && trace.can_redo());
+        // a free variable in head position stops the machine with arguments left on the stack
+        let e = UntypedLambdaCalculusParser::parse("x ((λy. y) z)").unwrap();
+        let mut trace = Trace::new(KrivineMachine::new(e));
+        assert!(!trace.current().is_final());
+        assert_eq!(trace.run(100), 1);
+        assert!(trace.current().is_final());
+    }
+}
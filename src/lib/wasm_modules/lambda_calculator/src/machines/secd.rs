@@ -0,0 +1,345 @@
+use super::{lookup, read_back_in, EnvDisplay, Machine};
+use crate::expressions::untyped_lambda_calculus::{
+    app::App, unty_abs::UntyAbs, unty_var::UntyVar, UntyLamExpr,
+};
+use std::fmt::Display;
+use std::rc::Rc;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Instruction {
+    /// `LD x` pushes the value of `x`.
+    Load(UntyVar),
+    /// `LDF x [c]` pushes a closure of the function with parameter `x` and code `c`.
+    Closure(UntyVar, Rc<[Instruction]>),
+    /// `AP` applies the function on top of the stack to the value below it.
+    Apply,
+    /// `RTN` returns the value on top of the stack to the caller.
+    Return,
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Instruction::Load(var) => write!(f, "LD {var}"),
+            Instruction::Closure(var, code) => write!(f, "LDF {var} {}", ListDisplay(code)),
+            Instruction::Apply => write!(f, "AP"),
+            Instruction::Return => write!(f, "RTN"),
+        }
+    }
+}
+
+struct ListDisplay<'a, T>(&'a [T]);
+
+impl<T: Display> Display for ListDisplay<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[")?;
+        for (i, item) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{item}")?;
+        }
+        write!(f, "]")
+    }
+}
+
+/// Compiles `e` so that running the code leaves the value of `e` on the stack. The argument is
+/// evaluated before the function.
+pub fn compile(e: &UntyLamExpr) -> Vec<Instruction> {
+    let mut code = vec![];
+    compile_into(e, &mut code);
+    code
+}
+
+fn compile_into(e: &UntyLamExpr, code: &mut Vec<Instruction>) {
+    match e {
+        UntyLamExpr::Var(var) => code.push(Instruction::Load(var.clone())),
+        UntyLamExpr::Abs(abs) => {
+            let mut body = compile(&abs.expr);
+            body.push(Instruction::Return);
+            code.push(Instruction::Closure(abs.var.clone(), body.into()));
+        }
+        UntyLamExpr::App(app) => {
+            compile_into(&app.rhs, code);
+            compile_into(&app.lhs, code);
+            code.push(Instruction::Apply);
+        }
+    }
+}
+
+// The inverse of `compile` on code it produced, up to the final `RTN` of function bodies.
+fn decompile(code: &[Instruction]) -> UntyLamExpr {
+    let mut stack = vec![];
+    for instruction in code {
+        match instruction {
+            Instruction::Load(var) => stack.push(var.clone().into_expr()),
+            Instruction::Closure(var, body) => {
+                stack.push(UntyAbs::new_expr(var.clone(), decompile(body)))
+            }
+            Instruction::Apply => {
+                let function = stack.pop().expect("compiled code pushes the function");
+                let arg = stack.pop().expect("compiled code pushes the argument");
+                stack.push(App::new_expr(function, arg));
+            }
+            Instruction::Return => break,
+        }
+    }
+    stack.pop().expect("compiled code leaves a term")
+}
+
+pub type Environment = Vec<(UntyVar, Rc<Value>)>;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Value {
+    Closure {
+        var: UntyVar,
+        code: Rc<[Instruction]>,
+        env: Environment,
+    },
+    /// A free variable applied to values, which can't be reduced any further.
+    Neutral(UntyLamExpr),
+}
+
+impl Value {
+    pub fn read_back(&self) -> UntyLamExpr {
+        match self {
+            Value::Closure { var, code, env } => read_back_in(
+                &UntyAbs::new_expr(var.clone(), decompile(code)),
+                env,
+                |value| value.read_back(),
+            ),
+            Value::Neutral(e) => e.clone(),
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Closure { var, code, env } => {
+                write!(f, "⟨{var}, {}, {}⟩", ListDisplay(code), EnvDisplay(env))
+            }
+            Value::Neutral(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// The registers saved by `AP` and restored by `RTN`, on top of the frames saved before.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Frame {
+    stack: Vec<Rc<Value>>,
+    env: Environment,
+    control: Vec<Instruction>,
+    below: Option<Rc<Frame>>,
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let stack: Vec<_> = self.stack.iter().rev().collect();
+        let control: Vec<_> = self.control.iter().rev().collect();
+        write!(
+            f,
+            "({}, {}, {})",
+            ListDisplay(&stack),
+            EnvDisplay(&self.env),
+            ListDisplay(&control)
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SecdRule {
+    Load,
+    Closure,
+    /// Calls a closure or extends a neutral value.
+    Apply,
+    Return,
+}
+
+impl Display for SecdRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SecdRule::Load => write!(f, "LD"),
+            SecdRule::Closure => write!(f, "LDF"),
+            SecdRule::Apply => write!(f, "AP"),
+            SecdRule::Return => write!(f, "RTN"),
+        }
+    }
+}
+
+/// Landin's SECD machine running the code `compile` produces. It stops once the control and
+/// the dump are empty, with the value of the term on the stack.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SecdMachine {
+    // the top of the stacks and the next instruction are last
+    stack: Vec<Rc<Value>>,
+    env: Environment,
+    control: Vec<Instruction>,
+    // shared with the states before, so that a step doesn't copy the saved frames
+    dump: Option<Rc<Frame>>,
+}
+
+impl SecdMachine {
+    pub fn new(e: &UntyLamExpr) -> Self {
+        Self {
+            stack: vec![],
+            env: vec![],
+            control: compile(e).into_iter().rev().collect(),
+            dump: None,
+        }
+    }
+
+    /// The saved frames, the last saved one first.
+    fn frames(&self) -> impl Iterator<Item = &Frame> {
+        std::iter::successors(self.dump.as_deref(), |frame| frame.below.as_deref())
+    }
+}
+
+impl Machine for SecdMachine {
+    type Rule = SecdRule;
+
+    fn step(&self) -> Option<(Self, SecdRule)> {
+        let mut next = self.clone();
+        let rule = match next.control.pop() {
+            Some(Instruction::Load(var)) => {
+                let value = match lookup(&next.env, &var) {
+                    Some(value) => value.clone(),
+                    None => Rc::new(Value::Neutral(var.into_expr())),
+                };
+                next.stack.push(value);
+                SecdRule::Load
+            }
+            Some(Instruction::Closure(var, code)) => {
+                let env = next.env.clone();
+                next.stack.push(Rc::new(Value::Closure { var, code, env }));
+                SecdRule::Closure
+            }
+            Some(Instruction::Apply) => {
+                let function = next.stack.pop()?;
+                let arg = next.stack.pop()?;
+                match &*function {
+                    Value::Closure { var, code, env } => {
+                        let mut env = env.clone();
+                        env.push((var.clone(), arg));
+                        next.dump = Some(Rc::new(Frame {
+                            stack: std::mem::take(&mut next.stack),
+                            env: std::mem::replace(&mut next.env, env),
+                            control: std::mem::replace(
+                                &mut next.control,
+                                code.iter().rev().cloned().collect(),
+                            ),
+                            below: next.dump.take(),
+                        }));
+                    }
+                    Value::Neutral(e) => next.stack.push(Rc::new(Value::Neutral(App::new_expr(
+                        e.clone(),
+                        arg.read_back(),
+                    )))),
+                }
+                SecdRule::Apply
+            }
+            Some(Instruction::Return) => {
+                let value = next.stack.pop()?;
+                let frame = Rc::unwrap_or_clone(next.dump.take()?);
+                next.stack = frame.stack;
+                next.stack.push(value);
+                next.env = frame.env;
+                next.control = frame.control;
+                next.dump = frame.below;
+                SecdRule::Return
+            }
+            None => return None,
+        };
+        Some((next, rule))
+    }
+
+    fn registers(&self) -> Vec<(&'static str, String)> {
+        let stack: Vec<_> = self.stack.iter().rev().collect();
+        let control: Vec<_> = self.control.iter().rev().collect();
+        let dump: Vec<_> = self.frames().collect();
+        vec![
+            ("stack", ListDisplay(&stack).to_string()),
+            ("environment", EnvDisplay(&self.env).to_string()),
+            ("control", ListDisplay(&control).to_string()),
+            ("dump", ListDisplay(&dump).to_string()),
+        ]
+    }
+
+    // Compiled code only stops when it has run out of instructions.
+    fn is_final(&self) -> bool {
+        self.control.is_empty()
+    }
+
+    // the value left on the stack, once the machine has stopped
+    fn read_back(&self) -> Option<UntyLamExpr> {
+        match (&self.stack[..], self.is_final()) {
+            ([value], true) => Some(value.read_back()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compile, decompile, SecdMachine, SecdRule};
+    use crate::machines::{Machine, Trace};
+    use crate::parsers::untyped_lambda_calculus::UntypedLambdaCalculusParser;
+
+    fn run(input: &str, max_steps: u32) -> Option<String> {
+        let e = UntypedLambdaCalculusParser::parse(input).unwrap();
+        let mut trace = Trace::new(SecdMachine::new(&e));
+        trace.run(max_steps);
+        trace.current().read_back().map(|e| e.to_string())
+    }
+
+    #[test]
+    fn compilation() {
+        let e = UntypedLambdaCalculusParser::parse("(λx. x) (f y)").unwrap();
+        let code: Vec<_> = compile(&e).iter().map(|i| i.to_string()).collect();
+        assert_eq!(code, vec!["LD y", "LD f", "AP", "LDF x [LD x; RTN]", "AP"]);
+        for input in ["λ x. x", "(λ f. f (λ x. f x)) (λ y. y) z", "x (y z)"] {
+            let e = UntypedLambdaCalculusParser::parse(input).unwrap();
+            assert_eq!(decompile(&compile(&e)).to_string(), input);
+        }
+    }
+
+    #[test]
+    fn evaluation() {
+        assert_eq!(run("(λx. x) y", 100).as_deref(), Some("y"));
+        assert_eq!(run("(λx y. x) a b", 100).as_deref(), Some("a"));
+        assert_eq!(run("x ((λy. y) z)", 100).as_deref(), Some("x z"));
+        assert_eq!(
+            run("(λx. λy. x) (λz. z)", 100).as_deref(),
+            Some("λ y. λ z. z")
+        );
+        assert_eq!(run("(λx y. x) y", 100).as_deref(), Some("λ y1. y"));
+        // call-by-value evaluates the argument that call-by-name would discard
+        assert_eq!(run("(λx. z) ((λx. x x) (λx. x x))", 1000), None);
+        assert_eq!(
+            run("λx. (λy. y) x", 100).as_deref(),
+            Some("λ x. (λ y. y) x")
+        );
+    }
+
+    #[test]
+    fn registers() {
+        let e = UntypedLambdaCalculusParser::parse("(λx. x) y").unwrap();
+        let mut trace = Trace::new(SecdMachine::new(&e));
+        assert_eq!(trace.run(3), 3);
+        assert_eq!(trace.rule(), Some(&SecdRule::Apply));
+        assert_eq!(
+            trace.current().registers(),
+            vec![
+                ("stack", "[]".to_string()),
+                ("environment", "[x ↦ y]".to_string()),
+                ("control", "[LD x; RTN]".to_string()),
+                ("dump", "[([], [], [])]".to_string()),
+            ]
+        );
+        assert_eq!(trace.current().read_back(), None);
+        assert!(!trace.current().is_final());
+        trace.run(10);
+        assert_eq!(trace.rule(), Some(&SecdRule::Return));
+        assert!(trace.current().is_final());
+    }
+}
@@ -0,0 +1,139 @@
+use crate::expressions::bidirectional_lambda_calculus::{ann::Ann, hole::Hole, BidirExpr};
+use crate::expressions::simply_typed_lambda_calculus::typed_abs::TypedAbs;
+use crate::expressions::typed_lambda_calculus::{
+    absurd::Absurd, case::Case, fold::Fold, inj::Inj, pair::Pair, proj::Proj, ty::Type,
+    unfold::Unfold, unit::Unit, Side,
+};
+use crate::expressions::untyped_lambda_calculus::{app::App, unty_abs::UntyAbs, unty_var::UntyVar};
+use crate::parsers::typed_lambda_calculus::TypeSyntax;
+use std::iter;
+peg::parser! {
+    grammar bidirectional_lambda_calculus_parser() for str {
+        pub rule expression() -> BidirExpr
+            = lam_abs_expr() / inj_expr() / case_expr() / absurd_expr() / fold_expr() / app_expr()
+        rule lam_abs_expr() -> BidirExpr
+            = lambda() ws()* bs:(binder() ++ (ws()+)) ws()* "." ws()* e:expression() {
+                bs.into_iter().rev().fold(e, |acc, (v, t)| match t {
+                    Some(t) => TypedAbs::new_expr(v, t, acc),
+                    None => UntyAbs::new_expr(v, acc),
+                })
+            }
+        rule binder() -> (UntyVar, Option<Type>)
+            = v:var() ws()* ":" ws()* t:ty() {(v, Some(t))}
+            / v:var() {(v, None)}
+            / "(" ws()* b:binder() ws()* ")" {b}
+        rule inj_expr() -> BidirExpr
+            = s:inj() ws()+ e:atom() t:as_type()? {Inj::new_expr(s, e, t)}
+        rule inj() -> Side
+            = "inl" {Side::Left} / "inr" {Side::Right}
+        rule case_expr() -> BidirExpr
+            = "case" ws()+ e:expression() ws()+ "of" ws()+
+              "inl" ws()+ x:var() ws()* to() ws()* l:expression() ws()* "|" ws()*
+              "inr" ws()+ y:var() ws()* to() ws()* r:expression() {
+                Case::new_expr(e, UntyAbs::new(x, l), UntyAbs::new(y, r))
+            }
+        rule absurd_expr() -> BidirExpr
+            = "absurd" ws()+ e:atom() t:as_type()? {Absurd::new_expr(e, t)}
+        rule fold_expr() -> BidirExpr
+            = "fold" ws()+ e:atom() t:as_type()? {Fold::new_expr(e, t)}
+        // checking mode knows the type of an injection, `absurd` or `fold`, so `as T` is optional
+        rule as_type() -> Type
+            = ws()+ "as" ws()+ t:ty() {t}
+        rule app_expr() -> BidirExpr
+            = head:operand() rest:(ws()+ a:atom() {a})* {
+                iter::once(head).chain(rest).reduce(App::new_expr).unwrap()
+            }
+        rule operand() -> BidirExpr
+            = "fst" ws()+ e:atom() {Proj::new_expr(Side::Left, e)}
+            / "snd" ws()+ e:atom() {Proj::new_expr(Side::Right, e)}
+            / "unfold" ws()+ e:atom() {Unfold::new_expr(e)}
+            / atom()
+        rule atom() -> BidirExpr
+            = "(" ws()* ")" {Unit::new_expr()}
+            / "(" ws()* a:expression() ws()* "," ws()* b:expression() ws()* ")" {Pair::new_expr(a, b)}
+            / "(" ws()* e:expression() ws()* ":" ws()* t:ty() ws()* ")" {Ann::new_expr(e, t)}
+            / "(" ws()* e:expression() ws()* ")" {e}
+            / "?" n:$(['0'..='9']*) {Hole::new_expr(n.parse().unwrap_or(0))}
+            / v:var() {v.into_expr()}
+        rule var() -> UntyVar
+            = !keyword() v:ident() {UntyVar::new(v)}
+        rule keyword()
+            = ("fst" / "snd" / "inl" / "inr" / "as" / "case" / "of" / "absurd" / "fold" / "unfold") !['a'..='z' | 'A'..='Z' | '0'..='9']
+        rule ident() -> &'input str
+            = $([ 'a'..='z' | 'A'..='Z']['a'..='z' | 'A'..='Z' | '0'..='9' ]*)
+        rule ty() -> Type
+            = ##typed_lambda_calculus_type()
+        rule to()
+            = "⇒" / "=>"
+        rule lambda()
+            = "λ"/ "\\" / "lam" ws()+ / "lambda" ws()+ / "fun" ws()+
+        rule ws() = quiet!{[' ' | '\n' | '\t']+}
+
+    }
+}
+
+/// The syntax of `TypedLambdaCalculusParser`, where binders may leave out their type, as may
+/// injections, `absurd` and `fold` their `as T`, and `(e : T)` ascribes a type. `?` is a hole,
+/// which only `ProofChecker` accepts.
+pub struct BidirectionalLambdaCalculusParser;
+impl BidirectionalLambdaCalculusParser {
+    pub fn parse(input: &str) -> Result<BidirExpr, peg::error::ParseError<peg::str::LineCol>> {
+        bidirectional_lambda_calculus_parser::expression(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BidirectionalLambdaCalculusParser;
+    use crate::expressions::bidirectional_lambda_calculus::{ann::Ann, BidirExpr};
+    use crate::expressions::simply_typed_lambda_calculus::typed_abs::TypedAbs;
+    use crate::expressions::typed_lambda_calculus::ty::Type;
+    use crate::expressions::untyped_lambda_calculus::{
+        app::App, unty_abs::UntyAbs, unty_var::UntyVar,
+    };
+
+    #[test]
+    fn ascriptions() {
+        let expr =
+            BidirectionalLambdaCalculusParser::parse("(λf x: A. f x : (A → B) → A → B) g").unwrap();
+        let a = || Type::base("A");
+        let expected: BidirExpr = App::new_expr(
+            Ann::new_expr(
+                UntyAbs::new_expr(
+                    UntyVar::new("f"),
+                    TypedAbs::new_expr(
+                        UntyVar::new("x"),
+                        a(),
+                        App::new_expr(UntyVar::new_expr("f"), UntyVar::new_expr("x")),
+                    ),
+                ),
+                Type::arrow(
+                    Type::arrow(a(), Type::base("B")),
+                    Type::arrow(a(), Type::base("B")),
+                ),
+            ),
+            UntyVar::new_expr("g"),
+        );
+        assert_eq!(expr, expected);
+        assert!(BidirectionalLambdaCalculusParser::parse("(x :)").is_err());
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for input in [
+            "(λ x. x : A → A) a",
+            "λ p. (snd p, fst p)",
+            "λ x: A. λ y. (x, y)",
+            "case s of inl a ⇒ (λ y. y) | inr b ⇒ λ y. b",
+            "f ((a, b) : A × B)",
+            "λ x. (?0, x ?1)",
+            "λ s. case s of inl a ⇒ (inr a) | inr b ⇒ inl b as B + A",
+            "(λ x. absurd x : Empty → A)",
+            "fold (λ y. y) as μα. α → α",
+            "fold ()",
+        ] {
+            let expr = BidirectionalLambdaCalculusParser::parse(input).unwrap();
+            assert_eq!(expr.to_string(), input);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use crate::expressions::combinatory_logic::{combinator::Combinator, CombExpr};
+use crate::expressions::untyped_lambda_calculus::{app::App, unty_var::UntyVar};
+use std::iter;
+peg::parser! {
+    grammar combinatory_logic_parser() for str {
+        pub rule expression() -> CombExpr
+            = ws()* e:app_expr() ws()* {e}
+        // combinators need no space between them, so `SKK` is `S K K`
+        rule app_expr() -> CombExpr
+            = head:atom() rest:(ws()* a:atom() {a})* {
+                iter::once(head).chain(rest).reduce(App::new_expr).unwrap()
+            }
+        rule atom() -> CombExpr
+            = c:combinator() {c.new_expr()}
+            / v:var() {v.into_expr()}
+            / "(" ws()* e:app_expr() ws()* ")" {e}
+        rule combinator() -> Combinator
+            = "S" {Combinator::S}
+            / "K" {Combinator::K}
+            / "I" {Combinator::I}
+            / "B" {Combinator::B}
+            / "C" {Combinator::C}
+            / "W" {Combinator::W}
+            / "ι" {Combinator::Iota}
+        rule var() -> UntyVar
+            = v:$(['a'..='z']['a'..='z' | 'A'..='Z' | '0'..='9']*) {UntyVar::new(v)}
+        rule ws() = quiet!{[' ' | '\n' | '\t']+}
+    }
+}
+
+/// Applications of the combinators `S`, `K`, `I`, `B`, `C`, `W`, `ι` and lowercase variables.
+pub struct CombinatoryLogicParser;
+impl CombinatoryLogicParser {
+    pub fn parse(input: &str) -> Result<CombExpr, peg::error::ParseError<peg::str::LineCol>> {
+        combinatory_logic_parser::expression(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CombinatoryLogicParser;
+    use crate::expressions::combinatory_logic::{combinator::Combinator, CombExpr};
+    use crate::expressions::untyped_lambda_calculus::{app::App, unty_var::UntyVar};
+
+    #[test]
+    fn juxtaposed_combinators() {
+        let expected: CombExpr = App::new_expr(
+            App::new_expr(Combinator::S.new_expr(), Combinator::K.new_expr()),
+            App::new_expr(Combinator::K.new_expr(), UntyVar::new_expr("x1")),
+        );
+        assert_eq!(CombinatoryLogicParser::parse("SK(Kx1)").unwrap(), expected);
+        assert_eq!(
+            CombinatoryLogicParser::parse(" S K (K x1) ").unwrap(),
+            expected
+        );
+        assert!(CombinatoryLogicParser::parse("S X").is_err());
+        assert!(CombinatoryLogicParser::parse("λx. x").is_err());
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for input in [
+            "S K K",
+            "S (K (S I)) K x y",
+            "B f (g x)",
+            "W (C f)",
+            "ι (ι ι)",
+        ] {
+            let expr = CombinatoryLogicParser::parse(input).unwrap();
+            assert_eq!(expr.to_string(), input);
+        }
+    }
+}
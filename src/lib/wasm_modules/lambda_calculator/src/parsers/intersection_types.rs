@@ -0,0 +1,81 @@
+use crate::expressions::untyped_lambda_calculus::unty_var::UntyVar;
+use crate::type_checkers::intersection_types::{IntersectionContext, IntersectionType};
+peg::parser! {
+    grammar intersection_types_parser() for str {
+        // `→` binds looser than `∧`, and both group to the right
+        pub rule ty() -> IntersectionType
+            = from:and_ty() ws()* arrow() ws()* to:ty() {IntersectionType::arrow(from, to)}
+            / and_ty()
+        rule and_ty() -> IntersectionType
+            = l:type_atom() ws()* and() ws()* r:and_ty() {IntersectionType::and(l, r)}
+            / type_atom()
+        rule type_atom() -> IntersectionType
+            = n:type_name() {IntersectionType::atom(n)}
+            / "(" ws()* t:ty() ws()* ")" {t}
+        pub rule context() -> IntersectionContext
+            = ws()* c:(binding() ** (ws()* "," ws()*)) ws()* {c}
+        rule binding() -> (UntyVar, IntersectionType)
+            = v:var() ws()* ":" ws()* t:ty() {(v, t)}
+        rule var() -> UntyVar
+            = v:$([ 'a'..='z' | 'A'..='Z']['a'..='z' | 'A'..='Z' | '0'..='9' ]*) {UntyVar::new(v)}
+        // type variables may also be Greek letters, except for `λ`
+        rule type_name() -> &'input str
+            = $(['a'..='z' | 'A'..='Z' | 'α'..='κ' | 'μ'..='ω']['a'..='z' | 'A'..='Z' | '0'..='9']*)
+        rule arrow()
+            = "→" / "->"
+        rule and()
+            = "∧" / "/\\" / "&"
+        rule ws() = quiet!{[' ' | '\n' | '\t']+}
+    }
+}
+
+pub struct IntersectionTypeParser;
+impl IntersectionTypeParser {
+    pub fn parse_type(
+        input: &str,
+    ) -> Result<IntersectionType, peg::error::ParseError<peg::str::LineCol>> {
+        intersection_types_parser::ty(input)
+    }
+    /// A comma separated list of `x: T`, possibly empty.
+    pub fn parse_context(
+        input: &str,
+    ) -> Result<IntersectionContext, peg::error::ParseError<peg::str::LineCol>> {
+        intersection_types_parser::context(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IntersectionTypeParser;
+    use crate::type_checkers::intersection_types::IntersectionType;
+
+    #[test]
+    fn types() {
+        let a = || IntersectionType::atom("a");
+        let b = || IntersectionType::atom("b");
+        assert_eq!(
+            IntersectionTypeParser::parse_type("(a -> b) /\\ a -> b").unwrap(),
+            IntersectionType::arrow(
+                IntersectionType::and(IntersectionType::arrow(a(), b()), a()),
+                b()
+            )
+        );
+        for input in [
+            "(a → b) ∧ a → b",
+            "a → b ∧ a",
+            "(a → a) → a",
+            "a ∧ (b ∧ a → a)",
+        ] {
+            let ty = IntersectionTypeParser::parse_type(input).unwrap();
+            assert_eq!(ty.to_string(), input);
+        }
+    }
+
+    #[test]
+    fn contexts() {
+        assert_eq!(IntersectionTypeParser::parse_context("").unwrap(), vec![]);
+        let ctx = IntersectionTypeParser::parse_context("x: a ∧ b, f: a → a").unwrap();
+        assert_eq!(ctx.len(), 2);
+        assert_eq!(ctx[1].1.to_string(), "a → a");
+    }
+}
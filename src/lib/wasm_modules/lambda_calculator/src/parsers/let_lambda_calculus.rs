@@ -0,0 +1,88 @@
+use crate::expressions::let_lambda_calculus::{let_in::LetIn, LetLamExpr};
+use crate::expressions::untyped_lambda_calculus::{app::App, unty_abs::UntyAbs, unty_var::UntyVar};
+use std::iter;
+peg::parser! {
+    grammar let_lambda_calculus_parser() for str {
+        pub rule expression() -> LetLamExpr
+            = let_expr() / lam_abs_expr() / lam_app_expr() / lam_var_expr() / "(" ws()* e:expression() ws()* ")" {e}
+        rule let_expr() -> LetLamExpr
+            = "let" ws()+ v:var() ws()* "=" ws()* e1:expression() ws()+ "in" ws()+ e2:expression() {
+                LetIn::new_expr(v, e1, e2)
+            }
+        rule lam_abs_expr() -> LetLamExpr
+            = lambda() ws()* vs:(var() ++ (ws()+)) ws()* "." ws()* e:expression() {
+                vs.into_iter().rev().fold(e, |acc, v| UntyAbs::new_expr(v, acc))
+            }
+        rule lam_app_expr() -> LetLamExpr
+            = lhs:lam_app_opp() ws()+ rhs:(lam_app_opp() ++ (ws()+)) {
+                iter::once(lhs).chain(rhs).reduce(App::new_expr).unwrap()
+            }
+        rule lam_var_expr() -> LetLamExpr
+            = v:var() {v.into_expr()}
+        rule lam_app_opp() -> LetLamExpr
+            = "(" ws()* e:expression() ws()* ")" {e}  / lam_var()
+        rule lam_var() -> LetLamExpr
+            = v:var() {v.into_expr()}
+        rule var() -> UntyVar
+            = !keyword() v:$([ 'a'..='z' | 'A'..='Z']['a'..='z' | 'A'..='Z' | '0'..='9' ]*) {UntyVar::new(v)}
+        rule keyword()
+            = ("let" / "in") !['a'..='z' | 'A'..='Z' | '0'..='9']
+        rule lambda()
+            = "λ"/ "\\" / "lam" ws()+ / "lambda" ws()+ / "fun" ws()+
+        rule ws() = quiet!{[' ' | '\n' | '\t']+}
+
+    }
+}
+
+pub struct LetLambdaCalculusParser;
+impl LetLambdaCalculusParser {
+    pub fn parse(input: &str) -> Result<LetLamExpr, peg::error::ParseError<peg::str::LineCol>> {
+        let_lambda_calculus_parser::expression(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LetLambdaCalculusParser;
+    use crate::expressions::let_lambda_calculus::{let_in::LetIn, LetLamExpr};
+    use crate::expressions::untyped_lambda_calculus::{
+        app::App, unty_abs::UntyAbs, unty_var::UntyVar,
+    };
+    use crate::parsers::untyped_lambda_calculus::UntypedLambdaCalculusParser;
+
+    #[test]
+    fn lets() {
+        let expr = LetLambdaCalculusParser::parse("let id = λx. x in id id").unwrap();
+        let expected: LetLamExpr = LetIn::new_expr(
+            UntyVar::new("id"),
+            UntyAbs::new_expr(UntyVar::new("x"), UntyVar::new_expr("x")),
+            App::new_expr(UntyVar::new_expr("id"), UntyVar::new_expr("id")),
+        );
+        assert_eq!(expr, expected);
+        assert_eq!(expr.to_string(), "let id = λ x. x in id id");
+        assert_eq!(
+            LetLambdaCalculusParser::parse("(let x = a in x) b")
+                .unwrap()
+                .to_string(),
+            "(let x = a in x) b"
+        );
+    }
+
+    #[test]
+    fn desugars_to_applied_abstractions() {
+        for (input, desugared) in [
+            ("λx y. x", "λx y. x"),
+            ("let id = λx. x in id id", "(λid. id id) (λx. x)"),
+            (
+                "let x = a in let y = x in λz. y z",
+                "(λx. (λy. λz. y z) x) a",
+            ),
+            ("f (let x = a in x)", "f ((λx. x) a)"),
+        ] {
+            assert_eq!(
+                LetLambdaCalculusParser::parse(input).unwrap().desugar(),
+                UntypedLambdaCalculusParser::parse(desugared).unwrap()
+            );
+        }
+    }
+}
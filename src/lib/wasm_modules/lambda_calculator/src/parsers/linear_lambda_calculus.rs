@@ -0,0 +1,70 @@
+use super::untyped_lambda_calculus::UntypedLambdaCalculusParser;
+use crate::expressions::untyped_lambda_calculus::UntyLamExpr;
+use crate::usage::{BinderUsage, Discipline, UsageReport};
+use peg::{error::ParseError, str::LineCol};
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum LinearParseError {
+    Parse(ParseError<LineCol>),
+    /// The binders that aren't used exactly once.
+    NotLinear(Vec<BinderUsage>),
+}
+
+impl Display for LinearParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LinearParseError::Parse(err) => write!(f, "{err}"),
+            LinearParseError::NotLinear(binders) => {
+                write!(f, "the term is not linear: ")?;
+                for (i, binder) in binders.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{binder}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for LinearParseError {}
+
+/// Accepts the untyped syntax, but only terms binding every variable exactly once.
+pub struct LinearLambdaCalculusParser;
+impl LinearLambdaCalculusParser {
+    pub fn parse(input: &str) -> Result<UntyLamExpr, LinearParseError> {
+        let expr = UntypedLambdaCalculusParser::parse(input).map_err(LinearParseError::Parse)?;
+        let violations = UsageReport::new(&expr).violations(Discipline::Linear);
+        if violations.is_empty() {
+            Ok(expr)
+        } else {
+            Err(LinearParseError::NotLinear(violations))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LinearLambdaCalculusParser, LinearParseError};
+
+    #[test]
+    fn refuses_non_linear_terms() {
+        assert_eq!(
+            LinearLambdaCalculusParser::parse("λf x. f x")
+                .unwrap()
+                .to_string(),
+            "λ f. λ x. f x"
+        );
+        assert!(matches!(
+            LinearLambdaCalculusParser::parse("λx."),
+            Err(LinearParseError::Parse(_))
+        ));
+        let err = LinearLambdaCalculusParser::parse("λx y. x x").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the term is not linear: x (binder 1) is used 2 times, y (binder 2) is never used"
+        );
+    }
+}
@@ -1 +1,12 @@
+pub mod bidirectional_lambda_calculus;
+pub mod combinatory_logic;
+pub mod intersection_types;
+pub mod let_lambda_calculus;
+pub mod linear_lambda_calculus;
+pub mod pcf;
+pub mod pure_type_system;
+pub mod record_lambda_calculus;
+pub mod simply_typed_lambda_calculus;
+pub mod system_f;
+pub mod typed_lambda_calculus;
 pub mod untyped_lambda_calculus;
@@ -0,0 +1,83 @@
+use crate::expressions::pcf::{fix::Fix, ifz::Ifz, nat::Nat, pred::Pred, succ::Succ, PcfExpr};
+use crate::expressions::untyped_lambda_calculus::{app::App, unty_abs::UntyAbs, unty_var::UntyVar};
+use std::iter;
+peg::parser! {
+    grammar pcf_parser() for str {
+        pub rule expression() -> PcfExpr
+            = lam_abs_expr() / ifz_expr() / app_expr()
+        rule lam_abs_expr() -> PcfExpr
+            = lambda() ws()* vs:(var() ++ (ws()+)) ws()* "." ws()* e:expression() {
+                vs.into_iter().rev().fold(e, |acc, v| UntyAbs::new_expr(v, acc))
+            }
+        rule ifz_expr() -> PcfExpr
+            = "ifz" ws()+ c:expression() ws()+ "then" ws()+ z:expression() ws()+ "else" ws()+ s:expression() {
+                Ifz::new_expr(c, z, s)
+            }
+        rule app_expr() -> PcfExpr
+            = head:operand() rest:(ws()+ a:atom() {a})* {
+                iter::once(head).chain(rest).reduce(App::new_expr).unwrap()
+            }
+        // `succ`, `pred` and `fix` take a single atom, so `succ f x` is `(succ f) x`
+        rule operand() -> PcfExpr
+            = "succ" ws()+ e:atom() {Succ::new_expr(e)}
+            / "pred" ws()+ e:atom() {Pred::new_expr(e)}
+            / "fix" ws()+ e:atom() {Fix::new_expr(e)}
+            / atom()
+        rule atom() -> PcfExpr
+            = "(" ws()* e:expression() ws()* ")" {e}
+            / n:$(['0'..='9']+) {? n.parse().map(Nat::new_expr).or(Err("natural number")) }
+            / v:var() {v.into_expr()}
+        rule var() -> UntyVar
+            = !keyword() v:$([ 'a'..='z' | 'A'..='Z']['a'..='z' | 'A'..='Z' | '0'..='9' ]*) {UntyVar::new(v)}
+        rule keyword()
+            = ("succ" / "pred" / "fix" / "ifz" / "then" / "else") !['a'..='z' | 'A'..='Z' | '0'..='9']
+        rule lambda()
+            = "λ"/ "\\" / "lam" ws()+ / "lambda" ws()+ / "fun" ws()+
+        rule ws() = quiet!{[' ' | '\n' | '\t']+}
+
+    }
+}
+
+pub struct PcfParser;
+impl PcfParser {
+    pub fn parse(input: &str) -> Result<PcfExpr, peg::error::ParseError<peg::str::LineCol>> {
+        pcf_parser::expression(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PcfParser;
+    use crate::expressions::pcf::{ifz::Ifz, nat::Nat, succ::Succ, PcfExpr};
+    use crate::expressions::untyped_lambda_calculus::{app::App, unty_var::UntyVar};
+
+    #[test]
+    fn constants() {
+        let expr = PcfParser::parse("ifz n then 1 else succ f n").unwrap();
+        let expected: PcfExpr = Ifz::new_expr(
+            UntyVar::new_expr("n"),
+            Nat::new_expr(1),
+            App::new_expr(
+                Succ::new_expr(UntyVar::new_expr("f")),
+                UntyVar::new_expr("n"),
+            ),
+        );
+        assert_eq!(expr, expected);
+        assert!(PcfParser::parse("λsucc. succ").is_err());
+        assert!(PcfParser::parse("succ").is_err());
+        assert!(PcfParser::parse("99999999999999999999999").is_err());
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for input in [
+            "succ (pred 3)",
+            "fix (λ f. λ n. ifz n then 0 else f (pred n)) 4",
+            "(ifz x then f else g) 1",
+            "f (succ x) 0",
+            "succ x y",
+        ] {
+            assert_eq!(PcfParser::parse(input).unwrap().to_string(), input);
+        }
+    }
+}
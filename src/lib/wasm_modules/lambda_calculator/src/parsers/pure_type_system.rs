@@ -0,0 +1,115 @@
+use crate::expressions::pure_type_system::{pi::Pi, sort::Sort, PtsExpr};
+use crate::expressions::simply_typed_lambda_calculus::typed_abs::TypedAbs;
+use crate::expressions::untyped_lambda_calculus::{app::App, unty_var::UntyVar};
+use std::iter;
+peg::parser! {
+    grammar pure_type_system_parser() for str {
+        pub rule expression() -> PtsExpr
+            = pi_expr() / lam_abs_expr() / arrow_expr() / app_expr()
+        rule pi_expr() -> PtsExpr
+            = pi() ws()* bs:binders() ws()* "." ws()* e:expression() {
+                bs.into_iter().rev().fold(e, |acc, (v, t)| Pi::new_expr(v, t, acc))
+            }
+        rule lam_abs_expr() -> PtsExpr
+            = lambda() ws()* bs:binders() ws()* "." ws()* e:expression() {
+                bs.into_iter().rev().fold(e, |acc, (v, t)| TypedAbs::new_expr(v, Box::new(t), acc))
+            }
+        // several binders need parentheses, since `x: A y: B` would read `A y` as an application
+        rule binders() -> Vec<(UntyVar, PtsExpr)>
+            = b:binder() {vec![b]}
+            / ("(" ws()* b:binder() ws()* ")" {b}) ++ (ws()*)
+        rule binder() -> (UntyVar, PtsExpr)
+            = v:var() ws()* ":" ws()* t:expression() {(v, t)}
+        rule arrow_expr() -> PtsExpr
+            = from:app_expr() ws()* arrow() ws()* to:expression() {Pi::arrow(from, to)}
+        rule app_expr() -> PtsExpr
+            = head:atom() rest:(ws()+ a:atom() {a})* {
+                iter::once(head).chain(rest).reduce(App::new_expr).unwrap()
+            }
+        rule atom() -> PtsExpr
+            = "(" ws()* e:expression() ws()* ")" {e}
+            / s:sort() {s.into_expr()}
+            / v:var() {v.into_expr()}
+        rule sort() -> Sort
+            = "*" {Sort::star()}
+            / ("□" / "[]") {Sort::boxed()}
+        rule var() -> UntyVar
+            = v:$([ 'a'..='z' | 'A'..='Z']['a'..='z' | 'A'..='Z' | '0'..='9' ]*) {UntyVar::new(v)}
+        rule arrow()
+            = "→" / "->"
+        rule pi()
+            = "Π" / "Pi" ws()+ / "forall" ws()+
+        rule lambda()
+            = "λ"/ "\\" / "lam" ws()+ / "lambda" ws()+ / "fun" ws()+
+        rule ws() = quiet!{[' ' | '\n' | '\t']+}
+
+    }
+}
+
+pub struct PureTypeSystemParser;
+impl PureTypeSystemParser {
+    pub fn parse(input: &str) -> Result<PtsExpr, peg::error::ParseError<peg::str::LineCol>> {
+        pure_type_system_parser::expression(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PureTypeSystemParser;
+    use crate::expressions::pure_type_system::{pi::Pi, sort::Sort, PtsExpr};
+    use crate::expressions::simply_typed_lambda_calculus::typed_abs::TypedAbs;
+    use crate::expressions::untyped_lambda_calculus::unty_var::UntyVar;
+
+    #[test]
+    fn products() {
+        let expr = PureTypeSystemParser::parse("Π A: *. A → A").unwrap();
+        let expected: PtsExpr = Pi::new_expr(
+            UntyVar::new("A"),
+            Sort::star().into_expr(),
+            Pi::arrow(UntyVar::new_expr("A"), UntyVar::new_expr("A")),
+        );
+        assert_eq!(expr, expected);
+        assert_eq!(
+            PureTypeSystemParser::parse("Pi (A: *) (x: A). A").unwrap(),
+            PureTypeSystemParser::parse("Π A: *. Π x: A. A").unwrap()
+        );
+        assert_eq!(
+            PureTypeSystemParser::parse("(* -> *) -> []").unwrap(),
+            Pi::arrow(
+                Pi::arrow(Sort::star().into_expr(), Sort::star().into_expr()),
+                Sort::boxed().into_expr()
+            )
+        );
+        assert!(PureTypeSystemParser::parse("Π A. A").is_err());
+    }
+
+    #[test]
+    fn abstractions() {
+        let expr = PureTypeSystemParser::parse("λ(A: *) (x: A). x").unwrap();
+        let expected: PtsExpr = TypedAbs::new_expr(
+            UntyVar::new("A"),
+            Box::new(Sort::star().into_expr()),
+            TypedAbs::new_expr(
+                UntyVar::new("x"),
+                Box::new(UntyVar::new_expr("A")),
+                UntyVar::new_expr("x"),
+            ),
+        );
+        assert_eq!(expr, expected);
+        assert!(PureTypeSystemParser::parse("λx. x").is_err());
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for input in [
+            "Π A: *. A → A",
+            "λ A: *. λ x: A. x",
+            "Π A: *. Π P: A → *. Π x: A. P x → P x",
+            "(* → *) → □",
+            "(λ F: * → *. F) (λ A: *. A → A)",
+        ] {
+            let expr = PureTypeSystemParser::parse(input).unwrap();
+            assert_eq!(expr.to_string(), input);
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use crate::expressions::record_lambda_calculus::{
+    field::Field, record::Record, record_type::RecordType, RecordLamExpr,
+};
+use crate::expressions::simply_typed_lambda_calculus::typed_abs::TypedAbs;
+use crate::expressions::untyped_lambda_calculus::{app::App, unty_var::UntyVar};
+use std::collections::BTreeSet;
+use std::iter;
+
+fn distinct_labels<T>(fields: &[(String, T)]) -> bool {
+    let labels: BTreeSet<&String> = fields.iter().map(|(label, _)| label).collect();
+    labels.len() == fields.len()
+}
+
+peg::parser! {
+    grammar record_lambda_calculus_parser() for str {
+        pub rule expression() -> RecordLamExpr
+            = lam_abs_expr() / app_expr()
+        rule lam_abs_expr() -> RecordLamExpr
+            = lambda() ws()* bs:(binder() ++ (ws()+)) ws()* "." ws()* e:expression() {
+                bs.into_iter().rev().fold(e, |acc, (v, t)| TypedAbs::new_expr(v, t, acc))
+            }
+        rule binder() -> (UntyVar, RecordType)
+            = v:var() ws()* ":" ws()* t:ty() {(v, t)}
+            / "(" ws()* b:binder() ws()* ")" {b}
+        rule app_expr() -> RecordLamExpr
+            = head:atom() rest:(ws()+ a:atom() {a})* {
+                iter::once(head).chain(rest).reduce(App::new_expr).unwrap()
+            }
+        // projections bind tighter than application: `f r.x` is `f (r.x)`
+        rule atom() -> RecordLamExpr
+            = e:primary() labels:("." l:ident() {l})* {
+                labels.into_iter().fold(e, Field::new_expr)
+            }
+        rule primary() -> RecordLamExpr
+            = "(" ws()* e:expression() ws()* ")" {e}
+            / r:record() {r}
+            / v:var() {v.into_expr()}
+        rule record() -> RecordLamExpr
+            = "{" ws()* fs:(field() ** (ws()* "," ws()*)) ws()* "}" {?
+                if distinct_labels(&fs) {
+                    Ok(Record::new_expr(fs))
+                } else {
+                    Err("distinct field labels")
+                }
+            }
+        rule field() -> (String, RecordLamExpr)
+            = l:ident() ws()* "=" ws()* e:expression() {(l.to_string(), e)}
+        rule var() -> UntyVar
+            = v:ident() {UntyVar::new(v)}
+        rule ident() -> &'input str
+            = $([ 'a'..='z' | 'A'..='Z']['a'..='z' | 'A'..='Z' | '0'..='9' ]*)
+        pub rule ty() -> RecordType
+            = from:type_atom() ws()* arrow() ws()* to:ty() {RecordType::arrow(from, to)}
+            / type_atom()
+        rule type_atom() -> RecordType
+            = "{" ws()* fs:(field_type() ** (ws()* "," ws()*)) ws()* "}" {?
+                if distinct_labels(&fs) {
+                    Ok(RecordType::Record(fs))
+                } else {
+                    Err("distinct field labels")
+                }
+            }
+            / "(" ws()* t:ty() ws()* ")" {t}
+            / n:ident() {
+                match n {
+                    "Top" => RecordType::Top,
+                    _ => RecordType::base(n),
+                }
+            }
+        rule field_type() -> (String, RecordType)
+            = l:ident() ws()* ":" ws()* t:ty() {(l.to_string(), t)}
+        rule arrow()
+            = "→" / "->"
+        rule lambda()
+            = "λ"/ "\\" / "lam" ws()+ / "lambda" ws()+ / "fun" ws()+
+        rule ws() = quiet!{[' ' | '\n' | '\t']+}
+
+    }
+}
+
+pub struct RecordLambdaCalculusParser;
+impl RecordLambdaCalculusParser {
+    pub fn parse(input: &str) -> Result<RecordLamExpr, peg::error::ParseError<peg::str::LineCol>> {
+        record_lambda_calculus_parser::expression(input)
+    }
+    pub fn parse_type(
+        input: &str,
+    ) -> Result<RecordType, peg::error::ParseError<peg::str::LineCol>> {
+        record_lambda_calculus_parser::ty(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RecordLambdaCalculusParser;
+    use crate::expressions::record_lambda_calculus::{
+        field::Field, record::Record, record_type::RecordType, RecordLamExpr,
+    };
+    use crate::expressions::untyped_lambda_calculus::{app::App, unty_var::UntyVar};
+
+    #[test]
+    fn records() {
+        let expr = RecordLambdaCalculusParser::parse("f {x = a, y = {}}.x.y").unwrap();
+        let record: RecordLamExpr = Record::new_expr(vec![
+            ("x".to_string(), UntyVar::new_expr("a")),
+            ("y".to_string(), Record::new_expr(vec![])),
+        ]);
+        let expected: RecordLamExpr = App::new_expr(
+            UntyVar::new_expr("f"),
+            Field::new_expr(Field::new_expr(record, "x"), "y"),
+        );
+        assert_eq!(expr, expected);
+        assert_eq!(
+            RecordLambdaCalculusParser::parse_type("{x: A, f: Top → A} → {}").unwrap(),
+            RecordType::arrow(
+                RecordType::record(vec![
+                    ("x", RecordType::base("A")),
+                    (
+                        "f",
+                        RecordType::arrow(RecordType::Top, RecordType::base("A"))
+                    )
+                ]),
+                RecordType::record(vec![])
+            )
+        );
+        assert!(RecordLambdaCalculusParser::parse("{x = a, x = b}").is_err());
+        assert!(RecordLambdaCalculusParser::parse_type("{x: A, x: B}").is_err());
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for input in [
+            "λ r: {x: A, y: {z: B}}. r.y.z",
+            "(λ r: {x: A} → Top. r) (λ s: {x: A}. s)",
+            "{x = a, y = b}.x",
+            "(f a).x",
+        ] {
+            let expr = RecordLambdaCalculusParser::parse(input).unwrap();
+            assert_eq!(expr.to_string(), input);
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use crate::expressions::simply_typed_lambda_calculus::{
+    simple_type::SimpleType, typed_abs::TypedAbs, SimplyTypedExpr,
+};
+use crate::expressions::untyped_lambda_calculus::{app::App, unty_var::UntyVar};
+use std::iter;
+peg::parser! {
+    grammar simply_typed_lambda_calculus_parser() for str {
+        pub rule expression() -> SimplyTypedExpr
+            = lam_abs_expr() / lam_app_expr() / lam_var_expr() / "(" ws()* e:expression() ws()* ")" {e}
+        rule lam_abs_expr() -> SimplyTypedExpr
+            = lambda() ws()* bs:(binder() ++ (ws()+)) ws()* "." ws()* e:expression() {
+                bs.into_iter().rev().fold(e, |acc, (v, t)| TypedAbs::new_expr(v, t, acc))
+            }
+        rule binder() -> (UntyVar, SimpleType)
+            = v:var() ws()* ":" ws()* t:simple_type() {(v, t)}
+            / "(" ws()* b:binder() ws()* ")" {b}
+        rule lam_app_expr() -> SimplyTypedExpr
+            = lhs:lam_app_opp() ws()+ rhs:(lam_app_opp() ++ (ws()+)) {
+                iter::once(lhs).chain(rhs).reduce(App::new_expr).unwrap()
+            }
+        rule lam_var_expr() -> SimplyTypedExpr
+            = v:var() {v.into_expr()}
+        rule lam_app_opp() -> SimplyTypedExpr
+            = "(" ws()* e:expression() ws()* ")" {e}  / lam_var()
+        rule lam_var() -> SimplyTypedExpr
+            = v:var() {v.into_expr()}
+        rule var() -> UntyVar
+            = v:ident() {UntyVar::new(v)}
+        rule ident() -> &'input str
+            = $([ 'a'..='z' | 'A'..='Z']['a'..='z' | 'A'..='Z' | '0'..='9' ]*)
+        pub rule simple_type() -> SimpleType
+            = from:type_atom() ws()* arrow() ws()* to:simple_type() {SimpleType::arrow(from, to)}
+            / type_atom()
+        rule type_atom() -> SimpleType
+            = n:ident() {SimpleType::base(n)}
+            / "(" ws()* t:simple_type() ws()* ")" {t}
+        rule arrow()
+            = "→" / "->"
+        rule lambda()
+            = "λ"/ "\\" / "lam" ws()+ / "lambda" ws()+ / "fun" ws()+
+        rule ws() = quiet!{[' ' | '\n' | '\t']+}
+
+    }
+}
+
+pub struct SimplyTypedLambdaCalculusParser;
+impl SimplyTypedLambdaCalculusParser {
+    pub fn parse(
+        input: &str,
+    ) -> Result<SimplyTypedExpr, peg::error::ParseError<peg::str::LineCol>> {
+        simply_typed_lambda_calculus_parser::expression(input)
+    }
+    pub fn parse_type(
+        input: &str,
+    ) -> Result<SimpleType, peg::error::ParseError<peg::str::LineCol>> {
+        simply_typed_lambda_calculus_parser::simple_type(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SimplyTypedLambdaCalculusParser;
+    use crate::expressions::simply_typed_lambda_calculus::{
+        simple_type::SimpleType, typed_abs::TypedAbs, SimplyTypedExpr,
+    };
+    use crate::expressions::untyped_lambda_calculus::{app::App, unty_var::UntyVar};
+
+    #[test]
+    fn types() {
+        let a = || SimpleType::base("A");
+        let b = || SimpleType::base("B");
+        assert_eq!(
+            SimplyTypedLambdaCalculusParser::parse_type("A").unwrap(),
+            a()
+        );
+        assert_eq!(
+            SimplyTypedLambdaCalculusParser::parse_type("A → B").unwrap(),
+            SimpleType::arrow(a(), b())
+        );
+        assert_eq!(
+            SimplyTypedLambdaCalculusParser::parse_type("A->B->A").unwrap(),
+            SimpleType::arrow(a(), SimpleType::arrow(b(), a()))
+        );
+        assert_eq!(
+            SimplyTypedLambdaCalculusParser::parse_type("(A → B) → A").unwrap(),
+            SimpleType::arrow(SimpleType::arrow(a(), b()), a())
+        );
+        assert!(SimplyTypedLambdaCalculusParser::parse_type("A →").is_err());
+        assert!(SimplyTypedLambdaCalculusParser::parse_type("→ A").is_err());
+    }
+
+    #[test]
+    fn typed_abs() {
+        let expr = SimplyTypedLambdaCalculusParser::parse("λx:A. x").unwrap();
+        assert_eq!(
+            expr,
+            TypedAbs::new_expr(
+                UntyVar::new("x"),
+                SimpleType::base("A"),
+                UntyVar::new_expr("x")
+            )
+        );
+        let expr = SimplyTypedLambdaCalculusParser::parse("λ f : A → B. λ x : A. f x").unwrap();
+        let expected: SimplyTypedExpr = TypedAbs::new_expr(
+            UntyVar::new("f"),
+            SimpleType::arrow(SimpleType::base("A"), SimpleType::base("B")),
+            TypedAbs::new_expr(
+                UntyVar::new("x"),
+                SimpleType::base("A"),
+                App::new_expr(UntyVar::new_expr("f"), UntyVar::new_expr("x")),
+            ),
+        );
+        assert_eq!(expr, expected);
+        assert_eq!(
+            SimplyTypedLambdaCalculusParser::parse("λf:A->B x:A. f x").unwrap(),
+            expected
+        );
+        assert_eq!(
+            SimplyTypedLambdaCalculusParser::parse("λ(f: A → B) (x: A). f x").unwrap(),
+            expected
+        );
+        assert!(SimplyTypedLambdaCalculusParser::parse("λx. x").is_err());
+        assert!(SimplyTypedLambdaCalculusParser::parse("λx:. x").is_err());
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for input in [
+            "λ x: A. x",
+            "λ f: (A → B) → C. λ x: A. f (λ y: A. x)",
+            "(λ x: A → A. x) (λ y: A. y) z",
+        ] {
+            let expr = SimplyTypedLambdaCalculusParser::parse(input).unwrap();
+            assert_eq!(expr.to_string(), input);
+        }
+    }
+}
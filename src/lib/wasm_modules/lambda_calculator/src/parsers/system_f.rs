@@ -0,0 +1,152 @@
+use crate::expressions::simply_typed_lambda_calculus::typed_abs::TypedAbs;
+use crate::expressions::system_f::{
+    poly_type::{PolyType, TyVar},
+    ty_abs::TyAbs,
+    ty_app::TyApp,
+    SystemFExpr,
+};
+use crate::expressions::untyped_lambda_calculus::{app::App, unty_var::UntyVar};
+
+enum Arg {
+    Term(SystemFExpr),
+    Type(PolyType),
+}
+
+peg::parser! {
+    grammar system_f_parser() for str {
+        pub rule expression() -> SystemFExpr
+            = ty_abs_expr() / lam_abs_expr() / app_expr()
+        rule ty_abs_expr() -> SystemFExpr
+            = big_lambda() ws()* vs:(ty_var() ++ (ws()+)) ws()* "." ws()* e:expression() {
+                vs.into_iter().rev().fold(e, |acc, v| TyAbs::new_expr(v, acc))
+            }
+        rule lam_abs_expr() -> SystemFExpr
+            = lambda() ws()* bs:(binder() ++ (ws()+)) ws()* "." ws()* e:expression() {
+                bs.into_iter().rev().fold(e, |acc, (v, t)| TypedAbs::new_expr(v, t, acc))
+            }
+        rule binder() -> (UntyVar, PolyType)
+            = v:var() ws()* ":" ws()* t:poly_type() {(v, t)}
+            / "(" ws()* b:binder() ws()* ")" {b}
+        // term and type arguments can be mixed freely, as in `f [A] x [B]`
+        rule app_expr() -> SystemFExpr
+            = head:atom() args:arg()* {
+                args.into_iter().fold(head, |acc, arg| match arg {
+                    Arg::Term(e) => App::new_expr(acc, e),
+                    Arg::Type(t) => TyApp::new_expr(acc, t),
+                })
+            }
+        rule arg() -> Arg
+            = ws()* "[" ws()* t:poly_type() ws()* "]" {Arg::Type(t)}
+            / ws()+ e:atom() {Arg::Term(e)}
+        rule atom() -> SystemFExpr
+            = "(" ws()* e:expression() ws()* ")" {e}
+            / v:var() {v.into_expr()}
+        rule var() -> UntyVar
+            = v:ident() {UntyVar::new(v)}
+        rule ident() -> &'input str
+            = $([ 'a'..='z' | 'A'..='Z']['a'..='z' | 'A'..='Z' | '0'..='9' ]*)
+        // Greek letters other than `λ` can name type variables
+        rule ty_var() -> TyVar
+            = v:$(ident() / ['α'..='κ' | 'μ'..='ω']['a'..='z' | 'A'..='Z' | '0'..='9' ]*) {TyVar::new(v)}
+        pub rule poly_type() -> PolyType
+            = forall() ws()* vs:(ty_var() ++ (ws()+)) ws()* "." ws()* t:poly_type() {
+                vs.into_iter().rev().fold(t, |acc, v| PolyType::forall(v, acc))
+            }
+            / from:type_atom() ws()* arrow() ws()* to:poly_type() {PolyType::arrow(from, to)}
+            / type_atom()
+        rule type_atom() -> PolyType
+            = v:ty_var() {PolyType::Var(v)}
+            / "(" ws()* t:poly_type() ws()* ")" {t}
+        rule arrow()
+            = "→" / "->"
+        rule forall()
+            = "∀" / "forall" ws()+
+        rule lambda()
+            = "λ"/ "\\" / "lam" ws()+ / "lambda" ws()+ / "fun" ws()+
+        rule big_lambda()
+            = "Λ" / "/\\" / "Lam" ws()+ / "tyfun" ws()+
+        rule ws() = quiet!{[' ' | '\n' | '\t']+}
+
+    }
+}
+
+pub struct SystemFParser;
+impl SystemFParser {
+    pub fn parse(input: &str) -> Result<SystemFExpr, peg::error::ParseError<peg::str::LineCol>> {
+        system_f_parser::expression(input)
+    }
+    pub fn parse_type(input: &str) -> Result<PolyType, peg::error::ParseError<peg::str::LineCol>> {
+        system_f_parser::poly_type(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SystemFParser;
+    use crate::expressions::simply_typed_lambda_calculus::typed_abs::TypedAbs;
+    use crate::expressions::system_f::{
+        poly_type::{PolyType, TyVar},
+        ty_abs::TyAbs,
+        ty_app::TyApp,
+        SystemFExpr,
+    };
+    use crate::expressions::untyped_lambda_calculus::{app::App, unty_var::UntyVar};
+
+    #[test]
+    fn types() {
+        let a = || PolyType::var("α");
+        assert_eq!(
+            SystemFParser::parse_type("∀α. α → α").unwrap(),
+            PolyType::forall(TyVar::new("α"), PolyType::arrow(a(), a()))
+        );
+        assert_eq!(
+            SystemFParser::parse_type("forall a b. a -> b").unwrap(),
+            SystemFParser::parse_type("∀a. ∀b. a → b").unwrap()
+        );
+        assert_eq!(
+            SystemFParser::parse_type("(∀α. α) → α").unwrap(),
+            PolyType::arrow(PolyType::forall(TyVar::new("α"), a()), a())
+        );
+        assert!(SystemFParser::parse_type("∀. α").is_err());
+        assert!(SystemFParser::parse_type("λ → λ").is_err());
+    }
+
+    #[test]
+    fn type_abstraction_and_application() {
+        let expr = SystemFParser::parse("Λα. λx:α. x").unwrap();
+        let expected: SystemFExpr = TyAbs::new_expr(
+            TyVar::new("α"),
+            TypedAbs::new_expr(
+                UntyVar::new("x"),
+                PolyType::var("α"),
+                UntyVar::new_expr("x"),
+            ),
+        );
+        assert_eq!(expr, expected);
+        assert_eq!(SystemFParser::parse("/\\α. \\x:α. x").unwrap(), expected);
+
+        let expr = SystemFParser::parse("f [A] x [B → B]").unwrap();
+        let expected: SystemFExpr = TyApp::new_expr(
+            App::new_expr(
+                TyApp::new_expr(UntyVar::new_expr("f"), PolyType::var("A")),
+                UntyVar::new_expr("x"),
+            ),
+            PolyType::arrow(PolyType::var("B"), PolyType::var("B")),
+        );
+        assert_eq!(expr, expected);
+        assert!(SystemFParser::parse("f []").is_err());
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for input in [
+            "Λ α. λ x: α. x",
+            "(Λ α. λ x: α. x) [∀β. β → β] (Λ β. λ y: β. y)",
+            "λ n: ∀α. (α → α) → α → α. Λ α. λ f: α → α. λ x: α. f (n [α] f x)",
+            "f (g [A]) x",
+        ] {
+            let expr = SystemFParser::parse(input).unwrap();
+            assert_eq!(expr.to_string(), input);
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use crate::expressions::simply_typed_lambda_calculus::typed_abs::TypedAbs;
+use crate::expressions::typed_lambda_calculus::{
+    absurd::Absurd, case::Case, fold::Fold, inj::Inj, pair::Pair, proj::Proj, ty::Type,
+    unfold::Unfold, unit::Unit, Side, TypedLamExpr,
+};
+use crate::expressions::untyped_lambda_calculus::{app::App, unty_abs::UntyAbs, unty_var::UntyVar};
+use std::iter;
+peg::parser! {
+    grammar typed_lambda_calculus_parser() for str {
+        pub rule expression() -> TypedLamExpr
+            = lam_abs_expr() / inj_expr() / case_expr() / absurd_expr() / fold_expr() / app_expr()
+        rule lam_abs_expr() -> TypedLamExpr
+            = lambda() ws()* bs:(binder() ++ (ws()+)) ws()* "." ws()* e:expression() {
+                bs.into_iter().rev().fold(e, |acc, (v, t)| TypedAbs::new_expr(v, t, acc))
+            }
+        rule binder() -> (UntyVar, Type)
+            = v:var() ws()* ":" ws()* t:ty() {(v, t)}
+            / "(" ws()* b:binder() ws()* ")" {b}
+        rule inj_expr() -> TypedLamExpr
+            = s:inj() ws()+ e:atom() ws()+ "as" ws()+ t:ty() {Inj::new_expr(s, e, t)}
+        rule inj() -> Side
+            = "inl" {Side::Left} / "inr" {Side::Right}
+        rule case_expr() -> TypedLamExpr
+            = "case" ws()+ e:expression() ws()+ "of" ws()+
+              "inl" ws()+ x:var() ws()* to() ws()* l:expression() ws()* "|" ws()*
+              "inr" ws()+ y:var() ws()* to() ws()* r:expression() {
+                Case::new_expr(e, UntyAbs::new(x, l), UntyAbs::new(y, r))
+            }
+        rule absurd_expr() -> TypedLamExpr
+            = "absurd" ws()+ e:atom() ws()+ "as" ws()+ t:ty() {Absurd::new_expr(e, t)}
+        rule fold_expr() -> TypedLamExpr
+            = "fold" ws()+ e:atom() ws()+ "as" ws()+ t:ty() {Fold::new_expr(e, t)}
+        rule app_expr() -> TypedLamExpr
+            = head:operand() rest:(ws()+ a:atom() {a})* {
+                iter::once(head).chain(rest).reduce(App::new_expr).unwrap()
+            }
+        rule operand() -> TypedLamExpr
+            = "fst" ws()+ e:atom() {Proj::new_expr(Side::Left, e)}
+            / "snd" ws()+ e:atom() {Proj::new_expr(Side::Right, e)}
+            / "unfold" ws()+ e:atom() {Unfold::new_expr(e)}
+            / atom()
+        rule atom() -> TypedLamExpr
+            = "(" ws()* ")" {Unit::new_expr()}
+            / "(" ws()* a:expression() ws()* "," ws()* b:expression() ws()* ")" {Pair::new_expr(a, b)}
+            / "(" ws()* e:expression() ws()* ")" {e}
+            / v:var() {v.into_expr()}
+        rule var() -> UntyVar
+            = !keyword() v:ident() {UntyVar::new(v)}
+        rule keyword()
+            = ("fst" / "snd" / "inl" / "inr" / "as" / "case" / "of" / "absurd" / "fold" / "unfold") !['a'..='z' | 'A'..='Z' | '0'..='9']
+        rule ident() -> &'input str
+            = $([ 'a'..='z' | 'A'..='Z']['a'..='z' | 'A'..='Z' | '0'..='9' ]*)
+        // `→` binds loosest, then `+`, then `×`, all to the right. Read as propositions, `∧`,
+        // `∨`, `⊤`, `⊥` and `¬A` stand for `×`, `+`, `Unit`, `Empty` and `A → Empty`.
+        pub rule ty() -> Type
+            = mu() ws()* v:type_name() ws()* "." ws()* t:ty() {Type::rec(v, t)}
+            / from:sum_ty() ws()* arrow() ws()* to:ty() {Type::arrow(from, to)}
+            / sum_ty()
+        // The longest prefix of the input that is a type, for `TypeSyntax`.
+        pub rule type_prefix() -> (Type, usize)
+            = t:ty() end:position!() [_]* {(t, end)}
+        rule sum_ty() -> Type
+            = l:product_ty() ws()* plus() ws()* r:sum_ty() {Type::sum(l, r)}
+            / product_ty()
+        rule product_ty() -> Type
+            = l:type_atom() ws()* times() ws()* r:product_ty() {Type::product(l, r)}
+            / type_atom()
+        rule type_atom() -> Type
+            = n:type_name() {
+                match n {
+                    "Unit" => Type::Unit,
+                    "Empty" => Type::Empty,
+                    _ => Type::base(n),
+                }
+            }
+            / "⊤" {Type::Unit}
+            / "⊥" {Type::Empty}
+            / "¬" ws()* t:type_atom() {Type::arrow(t, Type::Empty)}
+            / "(" ws()* t:ty() ws()* ")" {t}
+        // type variables may also be Greek letters, except for `λ` and `μ`
+        rule type_name() -> &'input str
+            = $(['a'..='z' | 'A'..='Z' | 'α'..='κ' | 'ν'..='ω']['a'..='z' | 'A'..='Z' | '0'..='9']*)
+        rule mu()
+            = "μ" / "mu" ws()+
+        rule arrow()
+            = "→" / "->"
+        rule times()
+            = "×" / "*" / "∧"
+        rule plus()
+            = "+" / "∨"
+        rule to()
+            = "⇒" / "=>"
+        rule lambda()
+            = "λ"/ "\\" / "lam" ws()+ / "lambda" ws()+ / "fun" ws()+
+        rule ws() = quiet!{[' ' | '\n' | '\t']+}
+
+    }
+}
+
+/// Lets another grammar parse a type of this one with `##typed_lambda_calculus_type()`.
+pub(crate) trait TypeSyntax {
+    fn typed_lambda_calculus_type(&self, pos: usize) -> peg::RuleResult<Type>;
+}
+
+impl TypeSyntax for str {
+    fn typed_lambda_calculus_type(&self, pos: usize) -> peg::RuleResult<Type> {
+        match typed_lambda_calculus_parser::type_prefix(&self[pos..]) {
+            Ok((ty, len)) => peg::RuleResult::Matched(pos + len, ty),
+            Err(_) => peg::RuleResult::Failed,
+        }
+    }
+}
+
+pub struct TypedLambdaCalculusParser;
+impl TypedLambdaCalculusParser {
+    pub fn parse(input: &str) -> Result<TypedLamExpr, peg::error::ParseError<peg::str::LineCol>> {
+        typed_lambda_calculus_parser::expression(input)
+    }
+    pub fn parse_type(input: &str) -> Result<Type, peg::error::ParseError<peg::str::LineCol>> {
+        typed_lambda_calculus_parser::ty(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TypedLambdaCalculusParser;
+    use crate::expressions::typed_lambda_calculus::{
+        inj::Inj, pair::Pair, proj::Proj, ty::Type, unit::Unit, Side, TypedLamExpr,
+    };
+    use crate::expressions::untyped_lambda_calculus::unty_var::UntyVar;
+
+    #[test]
+    fn types() {
+        let a = || Type::base("A");
+        let b = || Type::base("B");
+        assert_eq!(
+            TypedLambdaCalculusParser::parse_type("A × B + Unit → Empty").unwrap(),
+            Type::arrow(Type::sum(Type::product(a(), b()), Type::Unit), Type::Empty)
+        );
+        assert_eq!(
+            TypedLambdaCalculusParser::parse_type("A * (B + A)").unwrap(),
+            Type::product(a(), Type::sum(b(), a()))
+        );
+        assert_eq!(
+            TypedLambdaCalculusParser::parse_type("¬A ∨ B ∧ ⊤ → ⊥").unwrap(),
+            Type::arrow(
+                Type::sum(
+                    Type::arrow(a(), Type::Empty),
+                    Type::product(b(), Type::Unit)
+                ),
+                Type::Empty
+            )
+        );
+        for input in [
+            "(A → B) × A",
+            "A + B + A",
+            "(A + B) × (A → B) → A",
+            "(A × B) × A",
+        ] {
+            let ty = TypedLambdaCalculusParser::parse_type(input).unwrap();
+            assert_eq!(ty.to_string(), input);
+        }
+    }
+
+    #[test]
+    fn terms() {
+        let expr = TypedLambdaCalculusParser::parse("inl (fst (x, ())) as A + Unit").unwrap();
+        let expected: TypedLamExpr = Inj::new_expr(
+            Side::Left,
+            Proj::new_expr(
+                Side::Left,
+                Pair::new_expr(UntyVar::new_expr("x"), Unit::new_expr()),
+            ),
+            Type::sum(Type::base("A"), Type::Unit),
+        );
+        assert_eq!(expr, expected);
+        assert!(TypedLambdaCalculusParser::parse("inl x").is_err());
+        assert!(TypedLambdaCalculusParser::parse("λcase: A. case").is_err());
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for input in [
+            "λ p: A × B. (snd p, fst p)",
+            "case x of inl a ⇒ (λ y: A. y) | inr b ⇒ λ y: A. b",
+            "f (inr () as A + Unit) ()",
+            "λ e: Empty. absurd e as A → A",
+            "fst p x",
+            "unfold (fold (λ x: μα. α → α. x) as μα. α → α) y",
+        ] {
+            let expr = TypedLambdaCalculusParser::parse(input).unwrap();
+            assert_eq!(expr.to_string(), input);
+        }
+    }
+}
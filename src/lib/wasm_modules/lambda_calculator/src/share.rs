@@ -0,0 +1,694 @@
+use crate::expressions::{
+    untyped_lambda_calculus::{app::App, unty_abs::UntyAbs, unty_var::UntyVar, UntyLamExpr},
+    ReductionStrategy,
+};
+use std::fmt::Display;
+
+// Layout of a share string before base64url:
+//   version byte, flags byte,
+//   name table: varint count, then per name varint length + utf8 bytes,
+//   term in prefix order: 0 = var (name index), 1 = abs (name index, body), 2 = app (lhs, rhs),
+//   optional strategy byte (flag STRATEGY_FLAG),
+//   optional varint step index (flag STEP_FLAG).
+const FORMAT_VERSION: u8 = 1;
+const STEP_FLAG: u8 = 0b0000_0001;
+const STRATEGY_FLAG: u8 = 0b0000_0010;
+
+const VAR_TAG: u8 = 0;
+const ABS_TAG: u8 = 1;
+const APP_TAG: u8 = 2;
+
+pub const MAX_SHARE_LEN: usize = 64 * 1024;
+pub const MAX_TERM_NODES: usize = 10_000;
+pub const MAX_TERM_DEPTH: usize = 1_000;
+pub const MAX_NAME_LEN: usize = 256;
+pub const MAX_STEP: u64 = 100_000;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ShareDecodeError {
+    TooLong(usize),
+    InvalidBase64(usize),
+    UnexpectedEnd,
+    UnsupportedVersion(u8),
+    UnknownFlags(u8),
+    UnknownTag(u8),
+    UnknownStrategy(u8),
+    VarintOverflow,
+    NameTooLong(usize),
+    InvalidName,
+    NameIndexOutOfRange(usize),
+    TooManyNodes,
+    TooDeep,
+    StepTooLarge(u64),
+    StepWithoutStrategy,
+    TrailingBytes(usize),
+}
+
+impl Display for ShareDecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShareDecodeError::TooLong(len) => write!(
+                f,
+                "share string is {len} characters long, the limit is {MAX_SHARE_LEN}"
+            ),
+            ShareDecodeError::InvalidBase64(pos) => {
+                write!(f, "invalid base64url character at position {pos}")
+            }
+            ShareDecodeError::UnexpectedEnd => write!(f, "share string ended unexpectedly"),
+            ShareDecodeError::UnsupportedVersion(v) => {
+                write!(f, "unsupported share format version {v}")
+            }
+            ShareDecodeError::UnknownFlags(flags) => write!(f, "unknown flags {flags:#010b}"),
+            ShareDecodeError::UnknownTag(tag) => write!(f, "unknown term tag {tag}"),
+            ShareDecodeError::UnknownStrategy(n) => write!(f, "unknown reduction strategy {n}"),
+            ShareDecodeError::VarintOverflow => write!(f, "number does not fit into 64 bits"),
+            ShareDecodeError::NameTooLong(len) => write!(
+                f,
+                "variable name of length {len} exceeds the limit of {MAX_NAME_LEN}"
+            ),
+            ShareDecodeError::InvalidName => write!(f, "variable name is not a valid identifier"),
+            ShareDecodeError::NameIndexOutOfRange(idx) => {
+                write!(f, "variable name index {idx} is out of range")
+            }
+            ShareDecodeError::TooManyNodes => {
+                write!(f, "term has more than {MAX_TERM_NODES} nodes")
+            }
+            ShareDecodeError::TooDeep => write!(f, "term is nested deeper than {MAX_TERM_DEPTH}"),
+            ShareDecodeError::StepTooLarge(step) => {
+                write!(f, "step index {step} exceeds the limit of {MAX_STEP}")
+            }
+            ShareDecodeError::StepWithoutStrategy => {
+                write!(
+                    f,
+                    "step index is given without the strategy to replay it with"
+                )
+            }
+            ShareDecodeError::TrailingBytes(n) => write!(f, "{n} unexpected trailing bytes"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ShareEncodeError {
+    TooManyNodes,
+    TooDeep,
+    NameTooLong(usize),
+    TooLong(usize),
+}
+
+impl Display for ShareEncodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShareEncodeError::TooManyNodes => {
+                write!(f, "term has more than {MAX_TERM_NODES} nodes")
+            }
+            ShareEncodeError::TooDeep => write!(f, "term is nested deeper than {MAX_TERM_DEPTH}"),
+            ShareEncodeError::NameTooLong(len) => write!(
+                f,
+                "variable name of length {len} exceeds the limit of {MAX_NAME_LEN}"
+            ),
+            ShareEncodeError::TooLong(len) => write!(
+                f,
+                "share string would be {len} characters long, the limit is {MAX_SHARE_LEN}"
+            ),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SharedSession {
+    pub expr: UntyLamExpr,
+    pub strategy: Option<ReductionStrategy>,
+    pub step: Option<u64>,
+}
+
+pub fn encode_expr(expr: &UntyLamExpr) -> Result<String, ShareEncodeError> {
+    encode_session(expr, None)
+}
+
+pub fn decode_expr(input: &str) -> Result<UntyLamExpr, ShareDecodeError> {
+    decode_session(input).map(|session| session.expr)
+}
+
+/// `replay` is the strategy the receiver switches to, with the number of steps to replay under it.
+/// Terms that `decode_session` would reject are refused up front.
+pub fn encode_session(
+    expr: &UntyLamExpr,
+    replay: Option<(ReductionStrategy, Option<u64>)>,
+) -> Result<String, ShareEncodeError> {
+    check_limits(expr, 0, &mut 0)?;
+    let mut names: Vec<&str> = vec![];
+    collect_names(expr, &mut names);
+
+    let mut flags = 0;
+    if let Some((_, step)) = replay {
+        flags |= STRATEGY_FLAG;
+        if step.is_some() {
+            flags |= STEP_FLAG;
+        }
+    }
+    let mut bytes = vec![FORMAT_VERSION, flags];
+    write_varint(&mut bytes, names.len() as u64);
+    for name in &names {
+        write_varint(&mut bytes, name.len() as u64);
+        bytes.extend_from_slice(name.as_bytes());
+    }
+    write_expr(&mut bytes, expr, &names);
+    if let Some((strategy, step)) = replay {
+        bytes.push(strategy as u8);
+        if let Some(step) = step {
+            write_varint(&mut bytes, step);
+        }
+    }
+    let encoded = base64url_encode(&bytes);
+    if encoded.len() > MAX_SHARE_LEN {
+        return Err(ShareEncodeError::TooLong(encoded.len()));
+    }
+    Ok(encoded)
+}
+
+pub fn decode_session(input: &str) -> Result<SharedSession, ShareDecodeError> {
+    if input.len() > MAX_SHARE_LEN {
+        return Err(ShareDecodeError::TooLong(input.len()));
+    }
+    let bytes = base64url_decode(input)?;
+    let mut reader = Reader {
+        bytes: &bytes,
+        pos: 0,
+        nodes: 0,
+    };
+
+    let version = reader.byte()?;
+    if version != FORMAT_VERSION {
+        return Err(ShareDecodeError::UnsupportedVersion(version));
+    }
+    let flags = reader.byte()?;
+    if flags & !(STEP_FLAG | STRATEGY_FLAG) != 0 {
+        return Err(ShareDecodeError::UnknownFlags(flags));
+    }
+
+    let name_count = reader.varint()? as usize;
+    // every name takes at least one byte for its length, so this bounds the allocation
+    if name_count > reader.remaining() {
+        return Err(ShareDecodeError::UnexpectedEnd);
+    }
+    let mut names = Vec::with_capacity(name_count);
+    for _ in 0..name_count {
+        names.push(reader.name()?);
+    }
+
+    let expr = reader.expr(&names, 0)?;
+    let strategy = if flags & STRATEGY_FLAG != 0 {
+        let n = reader.byte()?;
+        Some(ReductionStrategy::from_u8(n).ok_or(ShareDecodeError::UnknownStrategy(n))?)
+    } else {
+        None
+    };
+    let step = if flags & STEP_FLAG != 0 {
+        match reader.varint()? {
+            step if step > MAX_STEP => return Err(ShareDecodeError::StepTooLarge(step)),
+            step => Some(step),
+        }
+    } else {
+        None
+    };
+    if reader.remaining() > 0 {
+        return Err(ShareDecodeError::TrailingBytes(reader.remaining()));
+    }
+    if step.is_some() && strategy.is_none() {
+        return Err(ShareDecodeError::StepWithoutStrategy);
+    }
+    Ok(SharedSession {
+        expr,
+        strategy,
+        step,
+    })
+}
+
+// The same limits `Reader::expr` and `Reader::name` enforce, so that every share string decodes.
+fn check_limits(
+    expr: &UntyLamExpr,
+    depth: usize,
+    nodes: &mut usize,
+) -> Result<(), ShareEncodeError> {
+    if depth >= MAX_TERM_DEPTH {
+        return Err(ShareEncodeError::TooDeep);
+    }
+    *nodes += 1;
+    if *nodes > MAX_TERM_NODES {
+        return Err(ShareEncodeError::TooManyNodes);
+    }
+    let check_name = |var: &UntyVar| match var.name().len() {
+        len if len > MAX_NAME_LEN => Err(ShareEncodeError::NameTooLong(len)),
+        _ => Ok(()),
+    };
+    match expr {
+        UntyLamExpr::Var(v) => check_name(v),
+        UntyLamExpr::Abs(abs) => {
+            check_name(&abs.var)?;
+            check_limits(&abs.expr, depth + 1, nodes)
+        }
+        UntyLamExpr::App(app) => {
+            check_limits(&app.lhs, depth + 1, nodes)?;
+            check_limits(&app.rhs, depth + 1, nodes)
+        }
+    }
+}
+
+fn collect_names<'a>(expr: &'a UntyLamExpr, names: &mut Vec<&'a str>) {
+    let mut push = |name: &'a str| {
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    };
+    match expr {
+        UntyLamExpr::Var(v) => push(v.name()),
+        UntyLamExpr::Abs(abs) => {
+            push(abs.var.name());
+            collect_names(&abs.expr, names);
+        }
+        UntyLamExpr::App(app) => {
+            collect_names(&app.lhs, names);
+            collect_names(&app.rhs, names);
+        }
+    }
+}
+
+fn name_index(names: &[&str], var: &UntyVar) -> u64 {
+    names
+        .iter()
+        .position(|n| *n == var.name())
+        .expect("all names are collected before writing") as u64
+}
+
+fn write_expr(bytes: &mut Vec<u8>, expr: &UntyLamExpr, names: &[&str]) {
+    match expr {
+        UntyLamExpr::Var(v) => {
+            bytes.push(VAR_TAG);
+            write_varint(bytes, name_index(names, v));
+        }
+        UntyLamExpr::Abs(abs) => {
+            bytes.push(ABS_TAG);
+            write_varint(bytes, name_index(names, &abs.var));
+            write_expr(bytes, &abs.expr, names);
+        }
+        UntyLamExpr::App(app) => {
+            bytes.push(APP_TAG);
+            write_expr(bytes, &app.lhs, names);
+            write_expr(bytes, &app.rhs, names);
+        }
+    }
+}
+
+fn write_varint(bytes: &mut Vec<u8>, mut n: u64) {
+    loop {
+        let low = (n & 0x7f) as u8;
+        n >>= 7;
+        if n == 0 {
+            bytes.push(low);
+            return;
+        }
+        bytes.push(low | 0x80);
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    nodes: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn remaining(&self) -> usize {
+        self.bytes.len() - self.pos
+    }
+
+    fn byte(&mut self) -> Result<u8, ShareDecodeError> {
+        let b = *self
+            .bytes
+            .get(self.pos)
+            .ok_or(ShareDecodeError::UnexpectedEnd)?;
+        self.pos += 1;
+        Ok(b)
+    }
+
+    fn varint(&mut self) -> Result<u64, ShareDecodeError> {
+        let mut n: u64 = 0;
+        for shift in (0..64).step_by(7) {
+            let b = self.byte()?;
+            let low = (b & 0x7f) as u64;
+            if shift == 63 && low > 1 {
+                return Err(ShareDecodeError::VarintOverflow);
+            }
+            n |= low << shift;
+            if b & 0x80 == 0 {
+                return Ok(n);
+            }
+        }
+        Err(ShareDecodeError::VarintOverflow)
+    }
+
+    fn name(&mut self) -> Result<UntyVar, ShareDecodeError> {
+        let len = self.varint()? as usize;
+        if len > MAX_NAME_LEN {
+            return Err(ShareDecodeError::NameTooLong(len));
+        }
+        if len > self.remaining() {
+            return Err(ShareDecodeError::UnexpectedEnd);
+        }
+        let raw = &self.bytes[self.pos..self.pos + len];
+        self.pos += len;
+        let name = std::str::from_utf8(raw).map_err(|_| ShareDecodeError::InvalidName)?;
+        if !is_identifier(name) {
+            return Err(ShareDecodeError::InvalidName);
+        }
+        Ok(UntyVar::new(name))
+    }
+
+    fn name_ref(&mut self, names: &[UntyVar]) -> Result<UntyVar, ShareDecodeError> {
+        let idx = self.varint()? as usize;
+        names
+            .get(idx)
+            .cloned()
+            .ok_or(ShareDecodeError::NameIndexOutOfRange(idx))
+    }
+
+    fn expr(&mut self, names: &[UntyVar], depth: usize) -> Result<UntyLamExpr, ShareDecodeError> {
+        if depth >= MAX_TERM_DEPTH {
+            return Err(ShareDecodeError::TooDeep);
+        }
+        self.nodes += 1;
+        if self.nodes > MAX_TERM_NODES {
+            return Err(ShareDecodeError::TooManyNodes);
+        }
+        match self.byte()? {
+            VAR_TAG => Ok(self.name_ref(names)?.into_expr()),
+            ABS_TAG => {
+                let var = self.name_ref(names)?;
+                let body = self.expr(names, depth + 1)?;
+                Ok(UntyAbs::new_expr(var, body))
+            }
+            APP_TAG => {
+                let lhs = self.expr(names, depth + 1)?;
+                let rhs = self.expr(names, depth + 1)?;
+                Ok(App::new_expr(lhs, rhs))
+            }
+            tag => Err(ShareDecodeError::UnknownTag(tag)),
+        }
+    }
+}
+
+// Mirrors the `var` rule of the untyped parser so decoded terms print back into parsable input.
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric())
+}
+
+const BASE64URL_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+fn base64url_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..chunk.len() + 1 {
+            out.push(BASE64URL_ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+        }
+    }
+    out
+}
+
+fn base64url_decode(input: &str) -> Result<Vec<u8>, ShareDecodeError> {
+    let input = input.trim_end_matches('=');
+    if input.len() % 4 == 1 {
+        return Err(ShareDecodeError::UnexpectedEnd);
+    }
+    let mut out = Vec::with_capacity(input.len() / 4 * 3 + 2);
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    for (pos, c) in input.bytes().enumerate() {
+        let value = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'-' => 62,
+            b'_' => 63,
+            _ => return Err(ShareDecodeError::InvalidBase64(pos)),
+        };
+        acc = (acc << 6) | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((acc >> bits) as u8);
+            acc &= (1 << bits) - 1;
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::untyped_lambda_calculus::UntypedLambdaCalculusParser;
+
+    #[test]
+    fn round_trip() {
+        for input in [
+            "x",
+            "λx. x",
+            "(λx. x x) (λx. x x)",
+            "λf. (λx. f (x x)) (λx. f (x x))",
+            "(λx. x) (λy z. z z y) (λ f. f) p",
+            "λabc1 b. abc1 b Free",
+        ] {
+            let expr = UntypedLambdaCalculusParser::parse(input).unwrap();
+            let encoded = encode_expr(&expr).unwrap();
+            assert!(encoded
+                .bytes()
+                .all(|c| c.is_ascii_alphanumeric() || c == b'-' || c == b'_'));
+            assert_eq!(decode_expr(&encoded).unwrap(), expr);
+
+            let encoded =
+                encode_session(&expr, Some((ReductionStrategy::CallByValue, Some(300)))).unwrap();
+            assert_eq!(
+                decode_session(&encoded).unwrap(),
+                SharedSession {
+                    expr,
+                    strategy: Some(ReductionStrategy::CallByValue),
+                    step: Some(300)
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn base64url() {
+        for len in 0..10 {
+            let bytes: Vec<u8> = (0..len).map(|i| (i * 97 + 13) as u8).collect();
+            assert_eq!(base64url_decode(&base64url_encode(&bytes)).unwrap(), bytes);
+        }
+        assert_eq!(base64url_encode(b"\xfb\xff"), "-_8");
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        let expr = UntypedLambdaCalculusParser::parse("λx y. x y").unwrap();
+        let valid = encode_session(&expr, Some((ReductionStrategy::NormalOrder, Some(2)))).unwrap();
+
+        assert_eq!(decode_expr(""), Err(ShareDecodeError::UnexpectedEnd));
+        assert_eq!(decode_expr("a"), Err(ShareDecodeError::UnexpectedEnd));
+        assert_eq!(decode_expr("AQ$A"), Err(ShareDecodeError::InvalidBase64(2)));
+        assert_eq!(
+            decode_expr(&base64url_encode(&[9, 0])),
+            Err(ShareDecodeError::UnsupportedVersion(9))
+        );
+        assert_eq!(
+            decode_expr(&base64url_encode(&[FORMAT_VERSION, 0x80])),
+            Err(ShareDecodeError::UnknownFlags(0x80))
+        );
+        assert_eq!(
+            decode_expr(&base64url_encode(&[FORMAT_VERSION, 0, 1, 1, b'x', 7])),
+            Err(ShareDecodeError::UnknownTag(7))
+        );
+        assert_eq!(
+            decode_expr(&base64url_encode(&[
+                FORMAT_VERSION,
+                0,
+                1,
+                1,
+                b'x',
+                VAR_TAG,
+                1
+            ])),
+            Err(ShareDecodeError::NameIndexOutOfRange(1))
+        );
+        assert_eq!(
+            decode_expr(&base64url_encode(&[
+                FORMAT_VERSION,
+                0,
+                1,
+                1,
+                b'1',
+                VAR_TAG,
+                0
+            ])),
+            Err(ShareDecodeError::InvalidName)
+        );
+        assert_eq!(
+            decode_expr(&base64url_encode(&[
+                FORMAT_VERSION,
+                0,
+                0xff,
+                0xff,
+                0xff,
+                0xff
+            ])),
+            Err(ShareDecodeError::UnexpectedEnd)
+        );
+        assert_eq!(
+            decode_expr(&base64url_encode(&[
+                FORMAT_VERSION,
+                0,
+                0xff,
+                0xff,
+                0xff,
+                0xff,
+                0xff,
+                0xff,
+                0xff,
+                0xff,
+                0xff,
+                0x7f
+            ])),
+            Err(ShareDecodeError::VarintOverflow)
+        );
+        assert_eq!(
+            decode_expr(&base64url_encode(&[
+                FORMAT_VERSION,
+                0,
+                1,
+                1,
+                b'x',
+                VAR_TAG,
+                0,
+                0
+            ])),
+            Err(ShareDecodeError::TrailingBytes(1))
+        );
+        assert_eq!(
+            decode_expr(&base64url_encode(&[
+                FORMAT_VERSION,
+                STRATEGY_FLAG,
+                1,
+                1,
+                b'x',
+                VAR_TAG,
+                0,
+                9
+            ])),
+            Err(ShareDecodeError::UnknownStrategy(9))
+        );
+        assert!(decode_session(&valid[..valid.len() - 2]).is_err());
+        assert_eq!(
+            decode_session(
+                &encode_session(
+                    &expr,
+                    Some((ReductionStrategy::NormalOrder, Some(MAX_STEP + 1)))
+                )
+                .unwrap()
+            ),
+            Err(ShareDecodeError::StepTooLarge(MAX_STEP + 1))
+        );
+        assert_eq!(
+            decode_session(&base64url_encode(&[
+                FORMAT_VERSION,
+                STEP_FLAG,
+                1,
+                1,
+                b'x',
+                VAR_TAG,
+                0,
+                1
+            ])),
+            Err(ShareDecodeError::StepWithoutStrategy)
+        );
+        assert_eq!(
+            decode_expr(&"A".repeat(MAX_SHARE_LEN + 1)),
+            Err(ShareDecodeError::TooLong(MAX_SHARE_LEN + 1))
+        );
+    }
+
+    #[test]
+    fn rejects_oversized_terms() {
+        let mut deep = vec![FORMAT_VERSION, 0, 1, 1, b'x'];
+        for _ in 0..MAX_TERM_DEPTH {
+            deep.extend_from_slice(&[ABS_TAG, 0]);
+        }
+        deep.extend_from_slice(&[VAR_TAG, 0]);
+        assert_eq!(
+            decode_expr(&base64url_encode(&deep)),
+            Err(ShareDecodeError::TooDeep)
+        );
+
+        fn balanced(bytes: &mut Vec<u8>, depth: usize) {
+            if depth == 0 {
+                bytes.extend_from_slice(&[VAR_TAG, 0]);
+            } else {
+                bytes.push(APP_TAG);
+                balanced(bytes, depth - 1);
+                balanced(bytes, depth - 1);
+            }
+        }
+        let mut wide = vec![FORMAT_VERSION, 0, 1, 1, b'x'];
+        balanced(&mut wide, 13);
+        assert_eq!(
+            decode_expr(&base64url_encode(&wide)),
+            Err(ShareDecodeError::TooManyNodes)
+        );
+        let mut wide = vec![FORMAT_VERSION, 0, 1, 1, b'x'];
+        balanced(&mut wide, 12);
+        assert!(decode_expr(&base64url_encode(&wide)).is_ok());
+    }
+
+    #[test]
+    fn refuses_to_encode_oversized_terms() {
+        let x = UntyVar::new("x");
+        let deep = (0..MAX_TERM_DEPTH).fold(x.clone().into_expr(), |body, _| {
+            UntyAbs::new_expr(x.clone(), body)
+        });
+        assert_eq!(encode_expr(&deep), Err(ShareEncodeError::TooDeep));
+
+        fn balanced(x: &UntyVar, depth: usize) -> UntyLamExpr {
+            match depth {
+                0 => x.clone().into_expr(),
+                _ => App::new_expr(balanced(x, depth - 1), balanced(x, depth - 1)),
+            }
+        }
+        assert_eq!(
+            encode_expr(&balanced(&x, 13)),
+            Err(ShareEncodeError::TooManyNodes)
+        );
+        assert!(encode_expr(&balanced(&x, 12)).is_ok());
+
+        let long = UntyVar::new(&"x".repeat(MAX_NAME_LEN + 1)).into_expr();
+        assert_eq!(
+            encode_expr(&long),
+            Err(ShareEncodeError::NameTooLong(MAX_NAME_LEN + 1))
+        );
+
+        // every binder names a fresh variable, so the name table outgrows the string limit
+        let many_names = (1..MAX_TERM_DEPTH).fold(x.clone().into_expr(), |body, i| {
+            UntyAbs::new_expr(UntyVar::new(&format!("x{i:0>200}")), body)
+        });
+        assert!(matches!(
+            encode_expr(&many_names),
+            Err(ShareEncodeError::TooLong(_))
+        ));
+    }
+}
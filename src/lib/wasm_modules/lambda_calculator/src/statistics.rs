@@ -0,0 +1,129 @@
+use crate::annotation::StepAnnotation;
+use crate::expressions::untyped_lambda_calculus::UntyLamExpr;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub enum CostModel {
+    /// Every β-step costs 1, the usual textbook measure.
+    #[default]
+    Unit = 0,
+    /// A β-step costs the size of the term it produces, what a stepper that rebuilds the
+    /// whole term pays.
+    TermSize = 1,
+    /// A β-step costs 1 plus the size of every copy of the argument it substitutes.
+    SubstitutionSize = 2,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct ReductionStats {
+    pub beta_steps: u32,
+    /// Variable occurrences replaced by a copy of the argument.
+    pub substitutions: u32,
+    pub alpha_renames: u32,
+    pub peak_size: u32,
+    pub peak_depth: u32,
+    /// Every step builds a new term, so this is the sum of the sizes of all produced terms.
+    pub allocated_nodes: u32,
+    substitution_cost: u32,
+}
+
+impl ReductionStats {
+    pub fn new(expr: &UntyLamExpr) -> Self {
+        let mut stats = Self::default();
+        stats.observe(expr);
+        stats
+    }
+
+    fn observe(&mut self, expr: &UntyLamExpr) {
+        self.peak_size = self.peak_size.max(expr.size());
+        self.peak_depth = self.peak_depth.max(expr.depth());
+    }
+
+    pub fn record(&mut self, result: &UntyLamExpr, annotation: &StepAnnotation) {
+        let copies = annotation.argument_copies.len() as u32;
+        let size = result.size();
+        self.beta_steps = self.beta_steps.saturating_add(1);
+        self.substitutions = self.substitutions.saturating_add(copies);
+        self.alpha_renames = self
+            .alpha_renames
+            .saturating_add(annotation.renamings.len() as u32);
+        self.allocated_nodes = self.allocated_nodes.saturating_add(size);
+        self.substitution_cost = self
+            .substitution_cost
+            .saturating_add(copies.saturating_mul(annotation.argument.size()))
+            .saturating_add(1);
+        self.observe(result);
+    }
+
+    pub fn merge(&mut self, other: &Self) {
+        self.beta_steps = self.beta_steps.saturating_add(other.beta_steps);
+        self.substitutions = self.substitutions.saturating_add(other.substitutions);
+        self.alpha_renames = self.alpha_renames.saturating_add(other.alpha_renames);
+        self.peak_size = self.peak_size.max(other.peak_size);
+        self.peak_depth = self.peak_depth.max(other.peak_depth);
+        self.allocated_nodes = self.allocated_nodes.saturating_add(other.allocated_nodes);
+        self.substitution_cost = self
+            .substitution_cost
+            .saturating_add(other.substitution_cost);
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl ReductionStats {
+    pub fn cost(&self, model: CostModel) -> u32 {
+        match model {
+            CostModel::Unit => self.beta_steps,
+            CostModel::TermSize => self.allocated_nodes,
+            CostModel::SubstitutionSize => self.substitution_cost,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CostModel, ReductionStats};
+    use crate::annotation::annotated_step;
+    use crate::expressions::{untyped_lambda_calculus::UntyLamExpr, ReductionStrategy};
+    use crate::parsers::untyped_lambda_calculus::UntypedLambdaCalculusParser;
+
+    fn normalize(input: &str) -> ReductionStats {
+        let mut expr: UntyLamExpr = UntypedLambdaCalculusParser::parse(input).unwrap();
+        let mut stats = ReductionStats::new(&expr);
+        while let Ok((next, annotation)) = annotated_step(&expr, ReductionStrategy::NormalOrder) {
+            stats.record(&next, &annotation);
+            expr = next;
+        }
+        stats
+    }
+
+    #[test]
+    fn counts_steps() {
+        let stats = normalize("(λx y. x y) y");
+        assert_eq!(stats.beta_steps, 1);
+        assert_eq!(stats.substitutions, 1);
+        assert_eq!(stats.alpha_renames, 1);
+        assert_eq!(stats.peak_size, 7);
+        assert_eq!(stats.peak_depth, 5);
+        assert_eq!(stats.allocated_nodes, 4);
+        assert_eq!(stats.cost(CostModel::SubstitutionSize), 2);
+
+        let stats = normalize("(λx y. x x) (λz. z) y");
+        assert_eq!(stats.beta_steps, 3);
+        assert_eq!(stats.substitutions, 3);
+        assert_eq!(stats.alpha_renames, 0);
+        assert_eq!(stats.cost(CostModel::Unit), 3);
+    }
+
+    #[test]
+    fn unit_cost_hides_duplication() {
+        // both terms take two β-steps, but the second one copies its argument four times
+        let small = normalize("(λx. x) ((λy. y) a)");
+        let large = normalize("(λx. x) ((λy. y y y y) a)");
+        assert_eq!(small.cost(CostModel::Unit), large.cost(CostModel::Unit));
+        assert!(small.cost(CostModel::TermSize) < large.cost(CostModel::TermSize));
+        assert!(small.cost(CostModel::SubstitutionSize) < large.cost(CostModel::SubstitutionSize));
+    }
+}
@@ -0,0 +1,643 @@
+use super::typed_lambda_calculus::TypedLamContext;
+use crate::expressions::bidirectional_lambda_calculus::{hole::Hole, BidirExpr};
+use crate::expressions::simply_typed_lambda_calculus::typed_abs::TypedAbs;
+use crate::expressions::typed_lambda_calculus::{
+    absurd::Absurd, case::Case, fold::Fold, inj::Inj, pair::Pair, proj::Proj, ty::Type,
+    unfold::Unfold, unit::Unit, Side, TypedLamExpr,
+};
+use crate::expressions::untyped_lambda_calculus::{app::App, unty_abs::UntyAbs, unty_var::UntyVar};
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum BidirTypeError {
+    UnboundVar(UntyVar),
+    /// Synthesis reached a term that can only be checked.
+    CannotSynthesize(Box<BidirExpr>),
+    /// An abstraction was checked against a type that isn't a function type.
+    NotAFunctionType {
+        expr: Box<BidirExpr>,
+        expected: Type,
+    },
+    /// An injection without `as T` was checked against a type that isn't a sum type.
+    NotASumType {
+        expr: Box<BidirExpr>,
+        expected: Type,
+    },
+    /// A `fold` without `as T` was checked against a type that isn't recursive.
+    NotARecursiveType {
+        expr: Box<BidirExpr>,
+        expected: Type,
+    },
+    /// An annotated abstraction was checked against a function type with another domain.
+    DomainMismatch {
+        expr: Box<BidirExpr>,
+        expected: Type,
+        annotated: Type,
+    },
+    /// Checking fell back to synthesis, which found another type.
+    Mismatch {
+        expr: Box<BidirExpr>,
+        expected: Type,
+        found: Type,
+    },
+    NotAFunction {
+        func: Box<BidirExpr>,
+        ty: Type,
+    },
+    NotAProduct {
+        expr: Box<BidirExpr>,
+        ty: Type,
+    },
+    NotASum {
+        expr: Box<BidirExpr>,
+        ty: Type,
+    },
+    NotEmpty {
+        expr: Box<BidirExpr>,
+        ty: Type,
+    },
+    NotRecursive {
+        expr: Box<BidirExpr>,
+        ty: Type,
+    },
+    UnfilledHole {
+        hole: Hole,
+        expected: Type,
+    },
+}
+
+impl Display for BidirTypeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BidirTypeError::UnboundVar(v) => {
+                write!(f, "synthesis failed: variable `{v}` is not bound")
+            }
+            BidirTypeError::CannotSynthesize(expr) => write!(
+                f,
+                "synthesis failed: the type of `{expr}` can only be checked, annotate it as `({expr} : T)`"
+            ),
+            BidirTypeError::NotAFunctionType { expr, expected } => write!(
+                f,
+                "checking `{expr}` against `{expected}` failed: an abstraction needs a function type"
+            ),
+            BidirTypeError::NotASumType { expr, expected } => write!(
+                f,
+                "checking `{expr}` against `{expected}` failed: an injection needs a sum type"
+            ),
+            BidirTypeError::NotARecursiveType { expr, expected } => write!(
+                f,
+                "checking `{expr}` against `{expected}` failed: `fold` needs a type of the form `μα. T`"
+            ),
+            BidirTypeError::DomainMismatch {
+                expr,
+                expected,
+                annotated,
+            } => write!(
+                f,
+                "checking `{expr}` against `{expected}` failed: its variable is annotated with `{annotated}`"
+            ),
+            BidirTypeError::Mismatch {
+                expr,
+                expected,
+                found,
+            } => write!(
+                f,
+                "checking `{expr}` against `{expected}` failed: its synthesized type is `{found}`"
+            ),
+            BidirTypeError::NotAFunction { func, ty } => write!(
+                f,
+                "synthesis failed: `{func}` is applied to an argument, but it has type `{ty}`, which is not a function type"
+            ),
+            BidirTypeError::NotAProduct { expr, ty } => write!(
+                f,
+                "synthesis failed: `{expr}` is projected, but it has type `{ty}`, which is not a product type"
+            ),
+            BidirTypeError::NotASum { expr, ty } => write!(
+                f,
+                "synthesis failed: `{expr}` is used as a sum, but it has type `{ty}`, which is not a sum type"
+            ),
+            BidirTypeError::NotEmpty { expr, ty } => write!(
+                f,
+                "synthesis failed: `{expr}` is eliminated with `absurd`, but it has type `{ty}` instead of `Empty`"
+            ),
+            BidirTypeError::NotRecursive { expr, ty } => write!(
+                f,
+                "synthesis failed: `{expr}` is used as a recursive type, but it has type `{ty}`, which is not of the form `μα. T`"
+            ),
+            BidirTypeError::UnfilledHole { hole, expected } => write!(
+                f,
+                "checking `{hole}` against `{expected}` failed: the hole has not been filled in"
+            ),
+        }
+    }
+}
+
+/// Builds the result of checking a term from the results for the premises of each rule, so
+/// that elaboration and proof mode share the rules of `BidirectionalLambdaCalculusChecker`.
+pub trait Elaborator {
+    type Output;
+    type Error: From<BidirTypeError>;
+
+    /// Runs before any rule is tried on `expr`, to reject terms the elaborator can't handle.
+    fn enter(&mut self, _expr: &BidirExpr) -> Result<(), Self::Error> {
+        Ok(())
+    }
+    /// The result for `expr : ty` in `ctx` from the results for its premises, in the order of
+    /// the subterms. Ascriptions have no rule of their own and holes are passed to `hole`.
+    fn rule(
+        &mut self,
+        ctx: &TypedLamContext,
+        expr: &BidirExpr,
+        ty: &Type,
+        premises: Vec<Self::Output>,
+    ) -> Result<Self::Output, Self::Error>;
+    fn hole(
+        &mut self,
+        ctx: &TypedLamContext,
+        hole: Hole,
+        ty: &Type,
+    ) -> Result<Self::Output, Self::Error>;
+}
+
+/// Elaborates well typed terms into a `TypedLamExpr` and rejects holes.
+pub struct Elaboration;
+
+impl Elaborator for Elaboration {
+    type Output = TypedLamExpr;
+    type Error = BidirTypeError;
+
+    fn rule(
+        &mut self,
+        _ctx: &TypedLamContext,
+        expr: &BidirExpr,
+        ty: &Type,
+        premises: Vec<TypedLamExpr>,
+    ) -> Result<TypedLamExpr, BidirTypeError> {
+        let mut premises = premises.into_iter();
+        let mut premise = || premises.next().expect("the rule has this premise");
+        Ok(match (expr, ty) {
+            (BidirExpr::Var(v), _) => v.clone().into_expr(),
+            (BidirExpr::Abs(abs), Type::Arrow(from, _)) => {
+                TypedAbs::new_expr(abs.var.clone(), (**from).clone(), premise())
+            }
+            (BidirExpr::TypedAbs(abs), _) => {
+                TypedAbs::new_expr(abs.var.clone(), abs.ty.clone(), premise())
+            }
+            (BidirExpr::App(_), _) => App::new_expr(premise(), premise()),
+            (BidirExpr::Unit(_), _) => Unit::new_expr(),
+            (BidirExpr::Pair(_), _) => Pair::new_expr(premise(), premise()),
+            (BidirExpr::Proj(proj), _) => Proj::new_expr(proj.side, premise()),
+            (BidirExpr::Inj(inj), _) => Inj::new_expr(inj.side, premise(), ty.clone()),
+            (BidirExpr::Case(case), _) => Case::new_expr(
+                premise(),
+                UntyAbs::new(case.left.var.clone(), premise()),
+                UntyAbs::new(case.right.var.clone(), premise()),
+            ),
+            (BidirExpr::Absurd(_), _) => Absurd::new_expr(premise(), ty.clone()),
+            (BidirExpr::Fold(_), _) => Fold::new_expr(premise(), ty.clone()),
+            (BidirExpr::Unfold(_), _) => Unfold::new_expr(premise()),
+            (BidirExpr::Abs(_) | BidirExpr::Ann(_) | BidirExpr::Hole(_), _) => {
+                unreachable!("the checker has no rule for `{expr} : {ty}`")
+            }
+        })
+    }
+
+    fn hole(
+        &mut self,
+        _ctx: &TypedLamContext,
+        hole: Hole,
+        ty: &Type,
+    ) -> Result<TypedLamExpr, BidirTypeError> {
+        Err(BidirTypeError::UnfilledHole {
+            hole,
+            expected: ty.clone(),
+        })
+    }
+}
+
+/// Synthesizes types for variables, eliminations and annotated terms, and checks
+/// introductions against a known type, so only the abstractions of β-redexes need
+/// annotations. Well typed terms are elaborated into a `TypedLamExpr`.
+pub struct BidirectionalLambdaCalculusChecker;
+impl BidirectionalLambdaCalculusChecker {
+    pub fn synthesize(expr: &BidirExpr) -> Result<(TypedLamExpr, Type), BidirTypeError> {
+        Self::synthesize_in(&mut vec![], expr)
+    }
+
+    pub fn check(expr: &BidirExpr, ty: &Type) -> Result<TypedLamExpr, BidirTypeError> {
+        Self::check_in(&mut vec![], expr, ty)
+    }
+
+    /// Later entries of `ctx` shadow earlier ones.
+    pub fn synthesize_in(
+        ctx: &mut TypedLamContext,
+        expr: &BidirExpr,
+    ) -> Result<(TypedLamExpr, Type), BidirTypeError> {
+        Self::synthesize_with(&mut Elaboration, ctx, expr)
+    }
+
+    pub fn check_in(
+        ctx: &mut TypedLamContext,
+        expr: &BidirExpr,
+        ty: &Type,
+    ) -> Result<TypedLamExpr, BidirTypeError> {
+        Self::check_with(&mut Elaboration, ctx, expr, ty)
+    }
+
+    pub fn synthesize_with<E: Elaborator>(
+        elaborator: &mut E,
+        ctx: &mut TypedLamContext,
+        expr: &BidirExpr,
+    ) -> Result<(E::Output, Type), E::Error> {
+        elaborator.enter(expr)?;
+        let (premises, ty) = match expr {
+            BidirExpr::Var(v) => {
+                let ty = ctx
+                    .iter()
+                    .rev()
+                    .find(|(var, _)| var == v)
+                    .map(|(_, ty)| ty.clone())
+                    .ok_or_else(|| BidirTypeError::UnboundVar(v.clone()))?;
+                (vec![], ty)
+            }
+            BidirExpr::Abs(_)
+            | BidirExpr::Hole(_)
+            | BidirExpr::Inj(Inj { ty: None, .. })
+            | BidirExpr::Absurd(Absurd { ty: None, .. })
+            | BidirExpr::Fold(Fold { ty: None, .. }) => {
+                return Err(BidirTypeError::CannotSynthesize(Box::new(expr.clone())).into())
+            }
+            BidirExpr::TypedAbs(abs) => {
+                let (body, ty) = Self::in_scope(ctx, &abs.var, abs.ty.clone(), |ctx| {
+                    Self::synthesize_with(elaborator, ctx, &abs.expr)
+                })?;
+                (vec![body], Type::arrow(abs.ty.clone(), ty))
+            }
+            BidirExpr::App(app) => {
+                let (func, ty) = Self::synthesize_with(elaborator, ctx, &app.lhs)?;
+                let Type::Arrow(from, to) = ty else {
+                    return Err(BidirTypeError::NotAFunction {
+                        func: app.lhs.clone(),
+                        ty,
+                    }
+                    .into());
+                };
+                let arg = Self::check_with(elaborator, ctx, &app.rhs, &from)?;
+                (vec![func, arg], *to)
+            }
+            BidirExpr::Ann(ann) => {
+                let e = Self::check_with(elaborator, ctx, &ann.expr, &ann.ty)?;
+                return Ok((e, ann.ty.clone()));
+            }
+            BidirExpr::Unit(_) => (vec![], Type::Unit),
+            BidirExpr::Pair(pair) => {
+                let (fst, fst_ty) = Self::synthesize_with(elaborator, ctx, &pair.fst)?;
+                let (snd, snd_ty) = Self::synthesize_with(elaborator, ctx, &pair.snd)?;
+                (vec![fst, snd], Type::product(fst_ty, snd_ty))
+            }
+            BidirExpr::Proj(proj) => {
+                let (e, ty) = Self::synthesize_with(elaborator, ctx, &proj.expr)?;
+                let ty = match ty {
+                    Type::Product(fst, _) if proj.side == Side::Left => *fst,
+                    Type::Product(_, snd) => *snd,
+                    ty => {
+                        return Err(BidirTypeError::NotAProduct {
+                            expr: proj.expr.clone(),
+                            ty,
+                        }
+                        .into())
+                    }
+                };
+                (vec![e], ty)
+            }
+            BidirExpr::Inj(Inj {
+                side,
+                expr: e,
+                ty: Some(ty),
+            }) => {
+                let Type::Sum(left, right) = ty else {
+                    return Err(BidirTypeError::NotASum {
+                        expr: Box::new(expr.clone()),
+                        ty: ty.clone(),
+                    }
+                    .into());
+                };
+                let summand = match side {
+                    Side::Left => left,
+                    Side::Right => right,
+                };
+                let e = Self::check_with(elaborator, ctx, e, summand)?;
+                (vec![e], ty.clone())
+            }
+            BidirExpr::Case(case) => {
+                let (e, left_ty, right_ty) = Self::synthesize_sum(elaborator, ctx, &case.expr)?;
+                let (left, ty) = Self::in_scope(ctx, &case.left.var, left_ty, |ctx| {
+                    Self::synthesize_with(elaborator, ctx, &case.left.expr)
+                })?;
+                let right = Self::in_scope(ctx, &case.right.var, right_ty, |ctx| {
+                    Self::check_with(elaborator, ctx, &case.right.expr, &ty)
+                })?;
+                (vec![e, left, right], ty)
+            }
+            BidirExpr::Absurd(Absurd {
+                expr: e,
+                ty: Some(ty),
+            }) => (
+                vec![Self::synthesize_empty(elaborator, ctx, e)?],
+                ty.clone(),
+            ),
+            BidirExpr::Fold(Fold {
+                expr: e,
+                ty: Some(ty),
+            }) => {
+                let Some(unfolded) = ty.unfold() else {
+                    return Err(BidirTypeError::NotRecursive {
+                        expr: Box::new(expr.clone()),
+                        ty: ty.clone(),
+                    }
+                    .into());
+                };
+                let e = Self::check_with(elaborator, ctx, e, &unfolded)?;
+                (vec![e], ty.clone())
+            }
+            BidirExpr::Unfold(unfold) => {
+                let (e, ty) = Self::synthesize_with(elaborator, ctx, &unfold.expr)?;
+                match ty.unfold() {
+                    Some(unfolded) => (vec![e], unfolded),
+                    None => {
+                        return Err(BidirTypeError::NotRecursive {
+                            expr: unfold.expr.clone(),
+                            ty,
+                        }
+                        .into())
+                    }
+                }
+            }
+        };
+        Ok((elaborator.rule(ctx, expr, &ty, premises)?, ty))
+    }
+
+    pub fn check_with<E: Elaborator>(
+        elaborator: &mut E,
+        ctx: &mut TypedLamContext,
+        expr: &BidirExpr,
+        ty: &Type,
+    ) -> Result<E::Output, E::Error> {
+        elaborator.enter(expr)?;
+        let premises = match (expr, ty) {
+            (BidirExpr::Abs(abs), Type::Arrow(from, to)) => {
+                vec![Self::in_scope(ctx, &abs.var, (**from).clone(), |ctx| {
+                    Self::check_with(elaborator, ctx, &abs.expr, to)
+                })?]
+            }
+            (BidirExpr::TypedAbs(abs), Type::Arrow(from, to)) => {
+                if !abs.ty.alpha_eq(from) {
+                    return Err(BidirTypeError::DomainMismatch {
+                        expr: Box::new(expr.clone()),
+                        expected: ty.clone(),
+                        annotated: abs.ty.clone(),
+                    }
+                    .into());
+                }
+                vec![Self::in_scope(ctx, &abs.var, abs.ty.clone(), |ctx| {
+                    Self::check_with(elaborator, ctx, &abs.expr, to)
+                })?]
+            }
+            (BidirExpr::Abs(_) | BidirExpr::TypedAbs(_), _) => {
+                return Err(BidirTypeError::NotAFunctionType {
+                    expr: Box::new(expr.clone()),
+                    expected: ty.clone(),
+                }
+                .into())
+            }
+            (BidirExpr::Hole(hole), _) => return elaborator.hole(ctx, *hole, ty),
+            (BidirExpr::Pair(pair), Type::Product(fst_ty, snd_ty)) => vec![
+                Self::check_with(elaborator, ctx, &pair.fst, fst_ty)?,
+                Self::check_with(elaborator, ctx, &pair.snd, snd_ty)?,
+            ],
+            (BidirExpr::Case(case), _) => {
+                let (e, left_ty, right_ty) = Self::synthesize_sum(elaborator, ctx, &case.expr)?;
+                let left = Self::in_scope(ctx, &case.left.var, left_ty, |ctx| {
+                    Self::check_with(elaborator, ctx, &case.left.expr, ty)
+                })?;
+                let right = Self::in_scope(ctx, &case.right.var, right_ty, |ctx| {
+                    Self::check_with(elaborator, ctx, &case.right.expr, ty)
+                })?;
+                vec![e, left, right]
+            }
+            (BidirExpr::Inj(inj @ Inj { ty: None, .. }), Type::Sum(left, right)) => {
+                let summand = match inj.side {
+                    Side::Left => left,
+                    Side::Right => right,
+                };
+                vec![Self::check_with(elaborator, ctx, &inj.expr, summand)?]
+            }
+            (BidirExpr::Inj(Inj { ty: None, .. }), _) => {
+                return Err(BidirTypeError::NotASumType {
+                    expr: Box::new(expr.clone()),
+                    expected: ty.clone(),
+                }
+                .into())
+            }
+            (BidirExpr::Absurd(Absurd { expr: e, ty: None }), _) => {
+                vec![Self::synthesize_empty(elaborator, ctx, e)?]
+            }
+            (BidirExpr::Fold(Fold { expr: e, ty: None }), _) => {
+                let Some(unfolded) = ty.unfold() else {
+                    return Err(BidirTypeError::NotARecursiveType {
+                        expr: Box::new(expr.clone()),
+                        expected: ty.clone(),
+                    }
+                    .into());
+                };
+                vec![Self::check_with(elaborator, ctx, e, &unfolded)?]
+            }
+            _ => {
+                let (e, found) = Self::synthesize_with(elaborator, ctx, expr)?;
+                if !found.alpha_eq(ty) {
+                    return Err(BidirTypeError::Mismatch {
+                        expr: Box::new(expr.clone()),
+                        expected: ty.clone(),
+                        found,
+                    }
+                    .into());
+                }
+                return Ok(e);
+            }
+        };
+        elaborator.rule(ctx, expr, ty, premises)
+    }
+
+    fn synthesize_sum<E: Elaborator>(
+        elaborator: &mut E,
+        ctx: &mut TypedLamContext,
+        expr: &BidirExpr,
+    ) -> Result<(E::Output, Type, Type), E::Error> {
+        match Self::synthesize_with(elaborator, ctx, expr)? {
+            (e, Type::Sum(left, right)) => Ok((e, *left, *right)),
+            (_, ty) => Err(BidirTypeError::NotASum {
+                expr: Box::new(expr.clone()),
+                ty,
+            }
+            .into()),
+        }
+    }
+
+    fn synthesize_empty<E: Elaborator>(
+        elaborator: &mut E,
+        ctx: &mut TypedLamContext,
+        expr: &BidirExpr,
+    ) -> Result<E::Output, E::Error> {
+        match Self::synthesize_with(elaborator, ctx, expr)? {
+            (e, Type::Empty) => Ok(e),
+            (_, ty) => Err(BidirTypeError::NotEmpty {
+                expr: Box::new(expr.clone()),
+                ty,
+            }
+            .into()),
+        }
+    }
+
+    fn in_scope<T, ERR>(
+        ctx: &mut TypedLamContext,
+        var: &UntyVar,
+        ty: Type,
+        f: impl FnOnce(&mut TypedLamContext) -> Result<T, ERR>,
+    ) -> Result<T, ERR> {
+        ctx.push((var.clone(), ty));
+        let result = f(ctx);
+        ctx.pop();
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BidirTypeError, BidirectionalLambdaCalculusChecker};
+    use crate::parsers::bidirectional_lambda_calculus::BidirectionalLambdaCalculusParser;
+    use crate::parsers::typed_lambda_calculus::TypedLambdaCalculusParser;
+    use crate::type_checkers::typed_lambda_calculus::TypedLambdaCalculusChecker;
+
+    fn synthesize(input: &str) -> Result<(String, String), String> {
+        let expr = BidirectionalLambdaCalculusParser::parse(input).unwrap();
+        BidirectionalLambdaCalculusChecker::synthesize(&expr)
+            .map(|(e, ty)| (e.to_string(), ty.to_string()))
+            .map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn annotations_only_at_redexes() {
+        assert_eq!(
+            synthesize("(λf x. f x : (A → B) → A → B)"),
+            Ok((
+                "λ f: A → B. λ x: A. f x".to_string(),
+                "(A → B) → A → B".to_string()
+            ))
+        );
+        assert_eq!(
+            synthesize("λg: (A → A) → B. g (λx. x)"),
+            Ok((
+                "λ g: (A → A) → B. g (λ x: A. x)".to_string(),
+                "((A → A) → B) → B".to_string()
+            ))
+        );
+        assert_eq!(
+            synthesize("(λp. (snd p, fst p) : A × B → B × A)").map(|(_, ty)| ty),
+            Ok("A × B → B × A".to_string())
+        );
+        assert_eq!(
+            synthesize(
+                "(λs. case s of inl a ⇒ inr a as B + A | inr b ⇒ inl b as B + A : A + B → B + A)"
+            )
+            .map(|(_, ty)| ty),
+            Ok("A + B → B + A".to_string())
+        );
+    }
+
+    #[test]
+    fn checked_introductions_leave_out_their_type() {
+        assert_eq!(
+            synthesize("(λs. case s of inl a ⇒ inr a | inr b ⇒ inl b : A + B → B + A)"),
+            Ok((
+                "λ s: A + B. case s of inl a ⇒ (inr a as B + A) | inr b ⇒ inl b as B + A"
+                    .to_string(),
+                "A + B → B + A".to_string()
+            ))
+        );
+        assert_eq!(
+            synthesize("(λx. absurd x : Empty → A)").map(|(e, _)| e),
+            Ok("λ x: Empty. absurd x as A".to_string())
+        );
+        assert_eq!(
+            synthesize("(fold (λy. y) : μα. α → α)").map(|(e, _)| e),
+            Ok("fold (λ y: μα. α → α. y) as μα. α → α".to_string())
+        );
+        assert_eq!(
+            synthesize("inl ()"),
+            Err("synthesis failed: the type of `inl ()` can only be checked, annotate it as `(inl () : T)`".to_string())
+        );
+        assert_eq!(
+            synthesize("(inl () : A)"),
+            Err("checking `inl ()` against `A` failed: an injection needs a sum type".to_string())
+        );
+        assert_eq!(
+            synthesize("(fold () : A)"),
+            Err(
+                "checking `fold ()` against `A` failed: `fold` needs a type of the form `μα. T`"
+                    .to_string()
+            )
+        );
+        assert!(matches!(
+            synthesize("(λx: A. absurd x : A → B)"),
+            Err(err) if err.contains("instead of `Empty`")
+        ));
+    }
+
+    #[test]
+    fn elaborations_are_well_typed() {
+        for input in [
+            "(λf x. f (f x) : (A → A) → A → A) (λy. y)",
+            "(λs. case s of inl a ⇒ (λk. k a) | inr b ⇒ (λk. k b) : A + A → (A → B) → B)",
+            "λx: A. fold (λy. x) as μα. α → A",
+        ] {
+            let expr = BidirectionalLambdaCalculusParser::parse(input).unwrap();
+            let (elaborated, ty) = BidirectionalLambdaCalculusChecker::synthesize(&expr).unwrap();
+            let reparsed = TypedLambdaCalculusParser::parse(&elaborated.to_string()).unwrap();
+            assert_eq!(reparsed, elaborated);
+            assert!(TypedLambdaCalculusChecker::type_of(&elaborated)
+                .unwrap()
+                .alpha_eq(&ty));
+        }
+    }
+
+    #[test]
+    fn errors_name_the_mode() {
+        assert_eq!(
+            synthesize("(λx. x) a"),
+            Err("synthesis failed: the type of `λ x. x` can only be checked, annotate it as `(λ x. x : T)`".to_string())
+        );
+        assert_eq!(
+            synthesize("(λx. x : A)"),
+            Err(
+                "checking `λ x. x` against `A` failed: an abstraction needs a function type"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            synthesize("λa: A. (a : B)"),
+            Err("checking `a` against `B` failed: its synthesized type is `A`".to_string())
+        );
+        assert_eq!(
+            synthesize("(λx: B. x : A → A)"),
+            Err(
+                "checking `λ x: B. x` against `A → A` failed: its variable is annotated with `B`"
+                    .to_string()
+            )
+        );
+        let expr = BidirectionalLambdaCalculusParser::parse("λf: A. f y").unwrap();
+        assert!(matches!(
+            BidirectionalLambdaCalculusChecker::synthesize(&expr),
+            Err(BidirTypeError::NotAFunction { .. })
+        ));
+    }
+}
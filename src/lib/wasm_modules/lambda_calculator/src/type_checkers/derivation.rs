@@ -0,0 +1,221 @@
+use std::fmt::{Display, Write};
+
+/// A node of a typing derivation, concluding `Γ ⊢ expr : ty` by `rule` from `premises`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Derivation {
+    pub rule: &'static str,
+    /// Later entries shadow earlier ones.
+    pub context: Vec<(String, String)>,
+    pub expr: String,
+    /// `None` if the rule failed, either itself or in one of its premises. The premises of a
+    /// failed node are the ones derived up to the failure.
+    pub ty: Option<String>,
+    pub premises: Vec<Derivation>,
+}
+
+impl Derivation {
+    pub fn new<VAR: Display, TY: Display>(
+        rule: &'static str,
+        context: &[(VAR, TY)],
+        expr: &impl Display,
+        ty: Option<&TY>,
+        premises: Vec<Derivation>,
+    ) -> Self {
+        Self {
+            rule,
+            context: context
+                .iter()
+                .map(|(var, ty)| (var.to_string(), ty.to_string()))
+                .collect(),
+            expr: expr.to_string(),
+            ty: ty.map(|ty| ty.to_string()),
+            premises,
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.ty.is_some()
+    }
+
+    /// `{"rule", "context": [{"var", "type"}], "expr", "type", "premises"}`, where `type` is
+    /// `null` for failed rules.
+    pub fn to_json(&self) -> String {
+        let mut s = String::new();
+        self.write_json(&mut s);
+        s
+    }
+
+    fn write_json(&self, s: &mut String) {
+        write!(s, "{{\"rule\":{},\"context\":[", json_string(self.rule)).unwrap();
+        for (i, (var, ty)) in self.context.iter().enumerate() {
+            if i > 0 {
+                s.push(',');
+            }
+            write!(
+                s,
+                "{{\"var\":{},\"type\":{}}}",
+                json_string(var),
+                json_string(ty)
+            )
+            .unwrap();
+        }
+        write!(s, "],\"expr\":{},\"type\":", json_string(&self.expr)).unwrap();
+        match &self.ty {
+            Some(ty) => s.push_str(&json_string(ty)),
+            None => s.push_str("null"),
+        }
+        s.push_str(",\"premises\":[");
+        for (i, premise) in self.premises.iter().enumerate() {
+            if i > 0 {
+                s.push(',');
+            }
+            premise.write_json(s);
+        }
+        s.push_str("]}");
+    }
+
+    /// A `prooftree` environment of the `bussproofs` package. Failed judgements end in `: ?`.
+    pub fn to_latex(&self) -> String {
+        let mut s = String::from("\\begin{prooftree}\n");
+        self.write_latex(&mut s);
+        s.push_str("\\end{prooftree}\n");
+        s
+    }
+
+    fn write_latex(&self, s: &mut String) {
+        if self.premises.is_empty() {
+            s.push_str("\\AxiomC{}\n");
+        }
+        for premise in &self.premises {
+            premise.write_latex(s);
+        }
+        let inference = match self.premises.len() {
+            0 | 1 => "Unary",
+            2 => "Binary",
+            3 => "Trinary",
+            4 => "Quaternary",
+            _ => "Quinary",
+        };
+        let context: String = self
+            .context
+            .iter()
+            .map(|(var, ty)| format!("{} : {}, ", latex_math(var), latex_math(ty)))
+            .collect();
+        let context = match context.strip_suffix(", ") {
+            Some(context) => format!("{context} "),
+            None => context,
+        };
+        let ty = self.ty.as_deref().map_or("?".to_string(), latex_math);
+        writeln!(
+            s,
+            "\\RightLabel{{\\scriptsize $\\mathrm{{{}}}$}}",
+            latex_math(self.rule)
+        )
+        .unwrap();
+        writeln!(
+            s,
+            "\\{inference}InfC{{${context}\\vdash {} : {ty}$}}",
+            latex_math(&self.expr)
+        )
+        .unwrap();
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c if c.is_control() => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// Rewrites the pretty printed syntax into math mode, keeping spaces visible.
+fn latex_math(s: &str) -> String {
+    let mut latex = String::new();
+    for c in s.chars() {
+        match c {
+            ' ' => latex.push_str("\\ "),
+            'λ' => latex.push_str("\\lambda "),
+            'μ' => latex.push_str("\\mu "),
+            'Λ' => latex.push_str("\\Lambda "),
+            '∀' => latex.push_str("\\forall "),
+            '→' => latex.push_str("\\to "),
+            '×' => latex.push_str("\\times "),
+            '∧' => latex.push_str("\\land "),
+            '∨' => latex.push_str("\\lor "),
+            '¬' => latex.push_str("\\neg "),
+            '⊤' => latex.push_str("\\top "),
+            '⊥' => latex.push_str("\\bot "),
+            '⇒' => latex.push_str("\\Rightarrow "),
+            '₁' => latex.push_str("_1"),
+            '₂' => latex.push_str("_2"),
+            'α' => latex.push_str("\\alpha "),
+            'β' => latex.push_str("\\beta "),
+            'γ' => latex.push_str("\\gamma "),
+            'δ' => latex.push_str("\\delta "),
+            '{' | '}' | '_' | '#' | '&' | '%' | '$' => {
+                latex.push('\\');
+                latex.push(c);
+            }
+            c => latex.push(c),
+        }
+    }
+    latex
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Derivation;
+
+    fn leaf(expr: &str, ty: Option<&str>) -> Derivation {
+        Derivation::new("Var", &[("x", "α")], &expr, ty.as_ref(), vec![])
+    }
+
+    #[test]
+    fn json() {
+        let d = leaf("x", Some("α"));
+        assert_eq!(
+            d.to_json(),
+            r#"{"rule":"Var","context":[{"var":"x","type":"α"}],"expr":"x","type":"α","premises":[]}"#
+        );
+        let d = Derivation {
+            rule: "→E",
+            context: vec![],
+            expr: "\"x\" y".to_string(),
+            ty: None,
+            premises: vec![leaf("x", Some("α")), leaf("y", None)],
+        };
+        assert!(!d.is_complete());
+        assert!(d.to_json().starts_with(
+            r#"{"rule":"→E","context":[],"expr":"\"x\" y","type":null,"premises":[{"rule":"Var""#
+        ));
+    }
+
+    #[test]
+    fn latex() {
+        let d = Derivation {
+            rule: "→I",
+            context: vec![],
+            expr: "λ x: α. x".to_string(),
+            ty: Some("α → α".to_string()),
+            premises: vec![leaf("x", Some("α"))],
+        };
+        assert_eq!(
+            d.to_latex(),
+            "\\begin{prooftree}\n\
+             \\AxiomC{}\n\
+             \\RightLabel{\\scriptsize $\\mathrm{Var}$}\n\
+             \\UnaryInfC{$x : \\alpha  \\vdash x : \\alpha $}\n\
+             \\RightLabel{\\scriptsize $\\mathrm{\\to I}$}\n\
+             \\UnaryInfC{$\\vdash \\lambda \\ x:\\ \\alpha .\\ x : \\alpha \\ \\to \\ \\alpha $}\n\
+             \\end{prooftree}\n"
+        );
+    }
+}
@@ -0,0 +1,390 @@
+use crate::expressions::let_lambda_calculus::LetLamExpr;
+use crate::expressions::simply_typed_lambda_calculus::simple_type::SimpleType;
+use crate::expressions::untyped_lambda_calculus::{unty_var::UntyVar, UntyLamExpr};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+
+// Untyped terms only ever produce variables and arrows; base types are there for calculi with
+// constants such as PCF, which reuse this inference.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub(crate) enum MonoType {
+    Var(u32),
+    Base(String),
+    Arrow(Box<MonoType>, Box<MonoType>),
+}
+
+impl MonoType {
+    pub(crate) fn base(name: &str) -> Self {
+        Self::Base(name.to_string())
+    }
+
+    pub(crate) fn arrow(from: Self, to: Self) -> Self {
+        Self::Arrow(Box::new(from), Box::new(to))
+    }
+
+    fn vars(&self, vars: &mut BTreeSet<u32>) {
+        match self {
+            MonoType::Var(v) => {
+                vars.insert(*v);
+            }
+            MonoType::Base(_) => {}
+            MonoType::Arrow(from, to) => {
+                from.vars(vars);
+                to.vars(vars);
+            }
+        }
+    }
+
+    fn occurs(&self, v: u32) -> bool {
+        match self {
+            MonoType::Var(w) => *w == v,
+            MonoType::Base(_) => false,
+            MonoType::Arrow(from, to) => from.occurs(v) || to.occurs(v),
+        }
+    }
+}
+
+// `∀ bound. ty`, the type of a `let`-bound variable.
+#[derive(Debug, Clone)]
+pub(crate) struct Scheme {
+    bound: Vec<u32>,
+    ty: MonoType,
+}
+
+impl Scheme {
+    pub(crate) fn mono(ty: MonoType) -> Self {
+        Self { bound: vec![], ty }
+    }
+}
+
+pub(crate) enum UnifyError {
+    /// The variable occurs in the type it would have to equal.
+    Occurs(u32, MonoType),
+    Mismatch,
+}
+
+/// The principal type of an expression, together with the types its free variables must have.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InferredType {
+    pub assumptions: Vec<(UntyVar, SimpleType)>,
+    pub ty: SimpleType,
+}
+
+impl Display for InferredType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !self.assumptions.is_empty() {
+            let assumptions: Vec<String> = self
+                .assumptions
+                .iter()
+                .map(|(v, ty)| format!("{v}: {ty}"))
+                .collect();
+            write!(f, "{} ⊢ ", assumptions.join(", "))?;
+        }
+        write!(f, "{}", self.ty)
+    }
+}
+
+// Without base types the only way unification can fail is the occurs check, so every error is
+// an application whose function type would have to contain itself.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InferenceError {
+    InfiniteType(Box<InfiniteType>),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InfiniteType {
+    pub func: LetLamExpr,
+    pub arg: LetLamExpr,
+    pub func_ty: SimpleType,
+    pub arg_ty: SimpleType,
+    /// The function type `func` would need to accept `arg`.
+    pub expected: SimpleType,
+    pub var: SimpleType,
+    pub ty: SimpleType,
+}
+
+impl Display for InferenceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InferenceError::InfiniteType(err) => {
+                let InfiniteType {
+                    func,
+                    arg,
+                    func_ty,
+                    arg_ty,
+                    expected,
+                    var,
+                    ty,
+                } = err.as_ref();
+                write!(
+                    f,
+                    "cannot apply `{func}` to `{arg}`: `{func}` has type `{func_ty}`, but to accept `{arg}` of type `{arg_ty}` it would need type `{expected}`; \
+                     unifying the two requires `{var} = {ty}`, and since `{var}` occurs in `{ty}` that type would be infinite"
+                )
+            }
+        }
+    }
+}
+
+// Gives type variables the names `a`, `b`, …, `z`, `a1`, … in the order they are first shown.
+#[derive(Default)]
+pub(crate) struct Namer {
+    names: BTreeMap<u32, String>,
+}
+
+impl Namer {
+    pub(crate) fn name(&mut self, ty: &MonoType) -> SimpleType {
+        match ty {
+            MonoType::Var(v) => {
+                let next = self.names.len();
+                let name = self.names.entry(*v).or_insert_with(|| {
+                    let letter = char::from(b'a' + (next % 26) as u8);
+                    match next / 26 {
+                        0 => letter.to_string(),
+                        n => format!("{letter}{n}"),
+                    }
+                });
+                SimpleType::base(name)
+            }
+            MonoType::Base(name) => SimpleType::base(name),
+            MonoType::Arrow(from, to) => SimpleType::arrow(self.name(from), self.name(to)),
+        }
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct Inference {
+    next_var: u32,
+    subst: BTreeMap<u32, MonoType>,
+    env: Vec<(UntyVar, Scheme)>,
+    /// Monomorphic assumptions for variables that are not bound anywhere.
+    free: Vec<(UntyVar, MonoType)>,
+}
+
+impl Inference {
+    pub(crate) fn fresh(&mut self) -> MonoType {
+        self.next_var += 1;
+        MonoType::Var(self.next_var - 1)
+    }
+
+    pub(crate) fn resolve(&self, ty: &MonoType) -> MonoType {
+        match ty {
+            MonoType::Var(v) => match self.subst.get(v) {
+                Some(ty) => self.resolve(ty),
+                None => ty.clone(),
+            },
+            MonoType::Base(_) => ty.clone(),
+            MonoType::Arrow(from, to) => MonoType::arrow(self.resolve(from), self.resolve(to)),
+        }
+    }
+
+    pub(crate) fn unify(&mut self, a: &MonoType, b: &MonoType) -> Result<(), UnifyError> {
+        match (self.resolve(a), self.resolve(b)) {
+            (MonoType::Var(v), MonoType::Var(w)) if v == w => Ok(()),
+            (MonoType::Var(v), ty) | (ty, MonoType::Var(v)) => {
+                if ty.occurs(v) {
+                    return Err(UnifyError::Occurs(v, ty));
+                }
+                self.subst.insert(v, ty);
+                Ok(())
+            }
+            (MonoType::Base(a), MonoType::Base(b)) if a == b => Ok(()),
+            (MonoType::Arrow(from_a, to_a), MonoType::Arrow(from_b, to_b)) => {
+                self.unify(&from_a, &from_b)?;
+                self.unify(&to_a, &to_b)
+            }
+            _ => Err(UnifyError::Mismatch),
+        }
+    }
+
+    fn instantiate(&mut self, scheme: &Scheme) -> MonoType {
+        let mut subst = Inference::default();
+        for v in &scheme.bound {
+            let fresh = self.fresh();
+            subst.subst.insert(*v, fresh);
+        }
+        subst.resolve(&scheme.ty)
+    }
+
+    fn generalize(&self, ty: &MonoType) -> Scheme {
+        let ty = self.resolve(ty);
+        let mut in_env = BTreeSet::new();
+        for (_, scheme) in &self.env {
+            let mut vars = BTreeSet::new();
+            self.resolve(&scheme.ty).vars(&mut vars);
+            in_env.extend(vars.into_iter().filter(|v| !scheme.bound.contains(v)));
+        }
+        for (_, ty) in &self.free {
+            self.resolve(ty).vars(&mut in_env);
+        }
+        let mut vars = BTreeSet::new();
+        ty.vars(&mut vars);
+        Scheme {
+            bound: vars.difference(&in_env).copied().collect(),
+            ty,
+        }
+    }
+
+    /// Names the type variables of `ty` and of the assumptions made for free variables.
+    pub(crate) fn result(&self, ty: &MonoType) -> InferredType {
+        let mut namer = Namer::default();
+        let assumptions = self
+            .free
+            .iter()
+            .map(|(v, ty)| (v.clone(), namer.name(&self.resolve(ty))))
+            .collect();
+        InferredType {
+            assumptions,
+            ty: namer.name(&self.resolve(ty)),
+        }
+    }
+
+    pub(crate) fn lookup(&mut self, v: &UntyVar) -> MonoType {
+        if let Some((_, scheme)) = self.env.iter().rev().find(|(var, _)| var == v) {
+            let scheme = scheme.clone();
+            return self.instantiate(&scheme);
+        }
+        if let Some((_, ty)) = self.free.iter().find(|(var, _)| var == v) {
+            return ty.clone();
+        }
+        let ty = self.fresh();
+        self.free.push((v.clone(), ty.clone()));
+        ty
+    }
+
+    /// The types of the bound variables in scope, with the quantifiers of `let`-bound schemes
+    /// left out.
+    pub(crate) fn bindings(&self) -> Vec<(UntyVar, MonoType)> {
+        self.env
+            .iter()
+            .map(|(v, scheme)| (v.clone(), scheme.ty.clone()))
+            .collect()
+    }
+
+    pub(crate) fn assumptions(&self) -> &[(UntyVar, MonoType)] {
+        &self.free
+    }
+
+    pub(crate) fn with_binding<T>(
+        &mut self,
+        v: &UntyVar,
+        scheme: Scheme,
+        f: impl FnOnce(&mut Self) -> T,
+    ) -> T {
+        self.env.push((v.clone(), scheme));
+        let result = f(self);
+        self.env.pop();
+        result
+    }
+
+    fn infer(&mut self, expr: &LetLamExpr) -> Result<MonoType, InferenceError> {
+        match expr {
+            LetLamExpr::Var(v) => Ok(self.lookup(v)),
+            LetLamExpr::Abs(abs) => {
+                let param = self.fresh();
+                let body = self.with_binding(&abs.var, Scheme::mono(param.clone()), |this| {
+                    this.infer(&abs.expr)
+                })?;
+                Ok(MonoType::arrow(param, body))
+            }
+            LetLamExpr::App(app) => {
+                let func_ty = self.infer(&app.lhs)?;
+                let arg_ty = self.infer(&app.rhs)?;
+                let result = self.fresh();
+                let expected = MonoType::arrow(arg_ty.clone(), result.clone());
+                match self.unify(&func_ty, &expected) {
+                    Ok(()) => Ok(result),
+                    Err(UnifyError::Mismatch) => {
+                        unreachable!("terms without constants never mention base types")
+                    }
+                    Err(UnifyError::Occurs(var, ty)) => {
+                        let mut namer = Namer::default();
+                        Err(InferenceError::InfiniteType(Box::new(InfiniteType {
+                            func: (*app.lhs).clone(),
+                            arg: (*app.rhs).clone(),
+                            func_ty: namer.name(&self.resolve(&func_ty)),
+                            arg_ty: namer.name(&self.resolve(&arg_ty)),
+                            expected: namer.name(&self.resolve(&expected)),
+                            var: namer.name(&MonoType::Var(var)),
+                            ty: namer.name(&ty),
+                        })))
+                    }
+                }
+            }
+            LetLamExpr::Let(l) => {
+                let value = self.infer(&l.value)?;
+                let scheme = self.generalize(&value);
+                self.with_binding(&l.var, scheme, |this| this.infer(&l.body))
+            }
+        }
+    }
+}
+
+pub struct HindleyMilnerChecker;
+impl HindleyMilnerChecker {
+    /// Infers the principal type of `expr`, generalizing the types of `let`-bound variables.
+    pub fn infer(expr: &LetLamExpr) -> Result<InferredType, InferenceError> {
+        let mut inference = Inference::default();
+        let ty = inference.infer(expr)?;
+        Ok(inference.result(&ty))
+    }
+
+    pub fn infer_untyped(expr: &UntyLamExpr) -> Result<InferredType, InferenceError> {
+        Self::infer(&expr.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HindleyMilnerChecker;
+    use crate::parsers::let_lambda_calculus::LetLambdaCalculusParser;
+    use crate::parsers::untyped_lambda_calculus::UntypedLambdaCalculusParser;
+
+    fn infer(input: &str) -> Result<String, String> {
+        let expr = LetLambdaCalculusParser::parse(input).unwrap();
+        HindleyMilnerChecker::infer(&expr)
+            .map(|ty| ty.to_string())
+            .map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn principal_types() {
+        assert_eq!(infer("λx. x"), Ok("a → a".to_string()));
+        assert_eq!(infer("λx y. x"), Ok("a → b → a".to_string()));
+        assert_eq!(
+            infer("λf g x. f (g x)"),
+            Ok("(a → b) → (c → a) → c → b".to_string())
+        );
+        assert_eq!(
+            infer("λx y z. x z (y z)"),
+            Ok("(a → b → c) → (a → b) → a → c".to_string())
+        );
+        assert_eq!(infer("f x"), Ok("f: a → b, x: a ⊢ b".to_string()));
+    }
+
+    #[test]
+    fn let_polymorphism() {
+        assert_eq!(infer("let id = λx. x in id id"), Ok("a → a".to_string()));
+        assert_eq!(
+            infer("let two = λf x. f (f x) in two two"),
+            Ok("(a → a) → a → a".to_string())
+        );
+        // the desugared form is not polymorphic
+        let desugared = UntypedLambdaCalculusParser::parse("(λid. id id) (λx. x)").unwrap();
+        assert!(HindleyMilnerChecker::infer_untyped(&desugared).is_err());
+        // lambda-bound variables stay monomorphic even when bound again by `let`
+        assert!(infer("λf. let g = f in g g").is_err());
+    }
+
+    #[test]
+    fn occurs_check() {
+        assert_eq!(
+            infer("λx. x x"),
+            Err("cannot apply `x` to `x`: `x` has type `a`, but to accept `x` of type `a` it would need type `a → b`; \
+                 unifying the two requires `a = a → b`, and since `a` occurs in `a → b` that type would be infinite"
+                .to_string())
+        );
+        assert!(infer("(λx. x x) (λx. x x)").is_err());
+        assert!(infer("λf. (λx. f (x x)) (λx. f (x x))").is_err());
+    }
+}
@@ -0,0 +1,269 @@
+use crate::expressions::simply_typed_lambda_calculus::simple_type::SimpleType;
+use crate::expressions::untyped_lambda_calculus::{
+    app::App, unty_abs::UntyAbs, unty_var::UntyVar, UntyLamExpr,
+};
+use std::collections::BTreeSet;
+use std::iter;
+
+type Context = Vec<(UntyVar, SimpleType)>;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Inhabitants {
+    /// No closed term has the type, so read as a formula it is not provable.
+    Uninhabited,
+    Inhabited {
+        /// Some η-long inhabitant, which may be larger than the bound.
+        witness: UntyLamExpr,
+        /// All β-normal inhabitants up to the bound, smallest first.
+        within_bound: Vec<UntyLamExpr>,
+    },
+}
+
+/// Searches closed β-normal terms of a simple type, which are the proofs of the type read as a
+/// formula of intuitionistic implicational logic.
+pub struct InhabitantSearch;
+impl InhabitantSearch {
+    pub fn search(ty: &SimpleType, max_size: u32) -> Inhabitants {
+        match Self::find(ty) {
+            None => Inhabitants::Uninhabited,
+            Some(witness) => Inhabitants::Inhabited {
+                witness,
+                within_bound: Self::enumerate(ty, max_size),
+            },
+        }
+    }
+
+    /// Decides whether `ty` is inhabited and returns an η-long inhabitant if it is.
+    ///
+    /// The search introduces all arguments of the goal and then tries every hypothesis ending
+    /// in the goal's base type. A branch fails when it reaches the same base type with the same
+    /// set of hypothesis types again, since a shortest proof never does that. There are
+    /// finitely many such sequents, so the search always terminates.
+    pub fn find(ty: &SimpleType) -> Option<UntyLamExpr> {
+        Self::prove(&mut vec![], ty, &mut vec![])
+    }
+
+    fn prove(
+        ctx: &mut Context,
+        goal: &SimpleType,
+        seen: &mut Vec<(BTreeSet<SimpleType>, String)>,
+    ) -> Option<UntyLamExpr> {
+        let (args, base) = Self::spine(goal);
+        let depth = ctx.len();
+        for (i, arg) in args.iter().enumerate() {
+            ctx.push((Self::var(depth + i), (*arg).clone()));
+        }
+        let sequent = (
+            ctx.iter().map(|(_, ty)| ty.clone()).collect(),
+            base.to_string(),
+        );
+        let body = if seen.contains(&sequent) {
+            None
+        } else {
+            seen.push(sequent);
+            let body = Self::prove_base(ctx, base, seen);
+            seen.pop();
+            body
+        };
+        let binders = ctx.split_off(depth);
+        let body = body?;
+        Some(
+            binders
+                .into_iter()
+                .rev()
+                .fold(body, |acc, (var, _)| UntyAbs::new_expr(var, acc)),
+        )
+    }
+
+    fn prove_base(
+        ctx: &mut Context,
+        base: &str,
+        seen: &mut Vec<(BTreeSet<SimpleType>, String)>,
+    ) -> Option<UntyLamExpr> {
+        let mut tried = BTreeSet::new();
+        for i in (0..ctx.len()).rev() {
+            let (var, ty) = ctx[i].clone();
+            let (params, result) = Self::spine(&ty);
+            // hypotheses of the same type prove the same things
+            if result != base || !tried.insert(ty.clone()) {
+                continue;
+            }
+            let args: Option<Vec<_>> = params
+                .into_iter()
+                .map(|param| Self::prove(ctx, param, seen))
+                .collect();
+            if let Some(args) = args {
+                return iter::once(var.into_expr())
+                    .chain(args)
+                    .reduce(App::new_expr);
+            }
+        }
+        None
+    }
+
+    /// All closed β-normal inhabitants of `ty` of at most `max_size` nodes, smallest first.
+    /// Their number can grow exponentially with the bound.
+    pub fn enumerate(ty: &SimpleType, max_size: u32) -> Vec<UntyLamExpr> {
+        let mut terms = Self::normal(&mut vec![], ty, max_size);
+        terms.sort_by_cached_key(|e| (e.size(), e.to_string()));
+        terms
+    }
+
+    fn normal(ctx: &mut Context, goal: &SimpleType, budget: u32) -> Vec<UntyLamExpr> {
+        let mut terms = vec![];
+        if let (SimpleType::Arrow(from, to), 2..) = (goal, budget) {
+            let var = Self::var(ctx.len());
+            ctx.push((var.clone(), (**from).clone()));
+            terms.extend(
+                Self::normal(ctx, to, budget - 1)
+                    .into_iter()
+                    .map(|body| UntyAbs::new_expr(var.clone(), body)),
+            );
+            ctx.pop();
+        }
+        for i in 0..ctx.len() {
+            let (var, ty) = ctx[i].clone();
+            let (params, _) = Self::spine(&ty);
+            // `var` applied to its first `n` parameters, which takes `1 + 2n` nodes at least
+            let mut rest = &ty;
+            for n in 0..=params.len() as u32 {
+                if 1 + 2 * n > budget {
+                    break;
+                }
+                if rest == goal {
+                    let params = &params[..n as usize];
+                    for args in Self::arguments(ctx, params, budget - 1 - n) {
+                        terms.push(
+                            iter::once(var.clone().into_expr())
+                                .chain(args)
+                                .reduce(App::new_expr)
+                                .unwrap(),
+                        );
+                    }
+                }
+                if let SimpleType::Arrow(_, to) = rest {
+                    rest = to;
+                }
+            }
+        }
+        terms
+    }
+
+    // Every combination of normal terms of `types` with at most `budget` nodes in total.
+    fn arguments(ctx: &mut Context, types: &[&SimpleType], budget: u32) -> Vec<Vec<UntyLamExpr>> {
+        let Some((first, rest)) = types.split_first() else {
+            return vec![vec![]];
+        };
+        let Some(first_budget) = budget.checked_sub(rest.len() as u32) else {
+            return vec![];
+        };
+        let mut combinations = vec![];
+        for arg in Self::normal(ctx, first, first_budget) {
+            for mut args in Self::arguments(ctx, rest, budget - arg.size()) {
+                args.insert(0, arg.clone());
+                combinations.push(args);
+            }
+        }
+        combinations
+    }
+
+    /// Splits `A₁ → … → Aₙ → a` into `[A₁, …, Aₙ]` and `a`.
+    fn spine(ty: &SimpleType) -> (Vec<&SimpleType>, &str) {
+        let mut params = vec![];
+        let mut ty = ty;
+        loop {
+            match ty {
+                SimpleType::Arrow(from, to) => {
+                    params.push(&**from);
+                    ty = to;
+                }
+                SimpleType::Base(name) => return (params, name),
+            }
+        }
+    }
+
+    // Binders are named after their depth, so that no binder shadows another.
+    fn var(depth: usize) -> UntyVar {
+        const NAMES: [&str; 6] = ["x", "y", "z", "u", "v", "w"];
+        match depth / NAMES.len() {
+            0 => UntyVar::new(NAMES[depth]),
+            n => UntyVar::new(&format!("{}{n}", NAMES[depth % NAMES.len()])),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InhabitantSearch, Inhabitants};
+    use crate::parsers::simply_typed_lambda_calculus::SimplyTypedLambdaCalculusParser;
+
+    fn enumerate(ty: &str, max_size: u32) -> Vec<String> {
+        let ty = SimplyTypedLambdaCalculusParser::parse_type(ty).unwrap();
+        InhabitantSearch::enumerate(&ty, max_size)
+            .iter()
+            .map(|e| e.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn composition() {
+        let ty = SimplyTypedLambdaCalculusParser::parse_type("(a → b) → (b → c) → a → c").unwrap();
+        assert_eq!(
+            InhabitantSearch::search(&ty, 20),
+            Inhabitants::Inhabited {
+                witness: InhabitantSearch::find(&ty).unwrap(),
+                within_bound: InhabitantSearch::enumerate(&ty, 20),
+            }
+        );
+        assert_eq!(
+            enumerate("(a → b) → (b → c) → a → c", 20),
+            vec!["λ x. λ y. λ z. y (x z)"]
+        );
+    }
+
+    #[test]
+    fn church_numerals_up_to_the_bound() {
+        assert_eq!(
+            enumerate("(a → a) → a → a", 7),
+            vec![
+                "λ x. x",
+                "λ x. λ y. y",
+                "λ x. λ y. x y",
+                "λ x. λ y. x (x y)"
+            ]
+        );
+        assert_eq!(enumerate("(a → a) → a → a", 1), Vec::<String>::new());
+    }
+
+    #[test]
+    fn witnesses_are_eta_long() {
+        for (input, expected) in [
+            ("a → b → a", "λ x. λ y. x"),
+            ("(a → b → c) → (a → b) → a → c", "λ x. λ y. λ z. x z (y z)"),
+            (
+                "((((a → b) → a) → a) → b) → b",
+                "λ x. x (λ y. y (λ z. x (λ u. z)))",
+            ),
+            ("(a → a → b) → a → b", "λ x. λ y. x y y"),
+        ] {
+            let ty = SimplyTypedLambdaCalculusParser::parse_type(input).unwrap();
+            let witness = InhabitantSearch::find(&ty).unwrap();
+            assert_eq!(witness.to_string(), expected);
+            assert!(InhabitantSearch::enumerate(&ty, witness.size()).contains(&witness));
+        }
+    }
+
+    #[test]
+    fn unprovable_formulas() {
+        for input in [
+            "a → b",
+            "((a → b) → a) → a",
+            "(a → b) → b → a",
+            "((a → a) → b) → a",
+        ] {
+            let ty = SimplyTypedLambdaCalculusParser::parse_type(input).unwrap();
+            assert_eq!(InhabitantSearch::search(&ty, 10), Inhabitants::Uninhabited);
+            assert_eq!(enumerate(input, 10), Vec::<String>::new());
+        }
+    }
+}
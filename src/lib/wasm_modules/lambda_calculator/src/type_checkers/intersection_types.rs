@@ -0,0 +1,573 @@
+use crate::expressions::untyped_lambda_calculus::{app::App, unty_var::UntyVar, UntyLamExpr};
+use crate::expressions::{Expression, FreeVars, ReductionStrategy};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+pub enum IntersectionType {
+    Atom(String),
+    Arrow(Box<IntersectionType>, Box<IntersectionType>),
+    And(Box<IntersectionType>, Box<IntersectionType>),
+}
+
+impl Display for IntersectionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let operand = |ty: &Self| match ty {
+            IntersectionType::Atom(_) => ty.to_string(),
+            _ => format!("({ty})"),
+        };
+        match self {
+            IntersectionType::Atom(name) => write!(f, "{name}"),
+            IntersectionType::Arrow(from, to) => match **from {
+                IntersectionType::Arrow(..) => write!(f, "({from}) → {to}"),
+                _ => write!(f, "{from} → {to}"),
+            },
+            IntersectionType::And(..) => {
+                let components: Vec<_> = self.components().into_iter().map(operand).collect();
+                write!(f, "{}", components.join(" ∧ "))
+            }
+        }
+    }
+}
+
+impl IntersectionType {
+    pub fn atom(name: &str) -> Self {
+        Self::Atom(name.to_string())
+    }
+    pub fn arrow(from: Self, to: Self) -> Self {
+        Self::Arrow(Box::new(from), Box::new(to))
+    }
+    pub fn and(left: Self, right: Self) -> Self {
+        Self::And(Box::new(left), Box::new(right))
+    }
+    /// `A₁ ∧ … ∧ Aₙ`, or `None` for no components.
+    pub fn intersection(components: impl IntoIterator<Item = Self>) -> Option<Self> {
+        components.into_iter().reduce(Self::and)
+    }
+
+    /// The atoms and arrows this type is an intersection of.
+    pub fn components(&self) -> Vec<&Self> {
+        match self {
+            IntersectionType::And(left, right) => {
+                let mut components = left.components();
+                components.extend(right.components());
+                components
+            }
+            ty => vec![ty],
+        }
+    }
+
+    /// Equality up to associativity, commutativity and idempotence of `∧`.
+    pub fn equiv(&self, other: &Self) -> bool {
+        self.is_subtype(other) && other.is_subtype(self)
+    }
+
+    /// `self ≤ other` by `∧`-elimination: every component of `other` is one of `self`.
+    pub fn is_subtype(&self, other: &Self) -> bool {
+        let components = self.components();
+        other
+            .components()
+            .into_iter()
+            .all(|c| components.iter().any(|d| d.component_equiv(c)))
+    }
+
+    fn component_equiv(&self, other: &Self) -> bool {
+        match (self, other) {
+            (IntersectionType::Atom(a), IntersectionType::Atom(b)) => a == b,
+            (IntersectionType::Arrow(a, b), IntersectionType::Arrow(c, d)) => {
+                a.equiv(c) && b.equiv(d)
+            }
+            _ => false,
+        }
+    }
+
+    fn substitute(&self, subst: &BTreeMap<String, IntersectionType>) -> Self {
+        match self {
+            IntersectionType::Atom(name) => {
+                subst.get(name).cloned().unwrap_or_else(|| self.clone())
+            }
+            IntersectionType::Arrow(from, to) => {
+                Self::arrow(from.substitute(subst), to.substitute(subst))
+            }
+            IntersectionType::And(left, right) => {
+                Self::and(left.substitute(subst), right.substitute(subst))
+            }
+        }
+    }
+}
+
+/// Later entries shadow earlier ones.
+pub type IntersectionContext = Vec<(UntyVar, IntersectionType)>;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct IntersectionTyping {
+    pub context: IntersectionContext,
+    pub ty: IntersectionType,
+}
+
+impl IntersectionTyping {
+    /// `Γ ⊢ expr : T`.
+    pub fn judgement(&self, expr: &UntyLamExpr) -> String {
+        let context: Vec<_> = self
+            .context
+            .iter()
+            .map(|(var, ty)| format!("{var}: {ty}"))
+            .collect();
+        match context.is_empty() {
+            true => format!("⊢ {expr} : {}", self.ty),
+            false => format!("{} ⊢ {expr} : {}", context.join(", "), self.ty),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum IntersectionTypeError {
+    /// Typings are only found automatically for normal forms.
+    NotNormal(UntyLamExpr),
+    UnboundVar(UntyVar),
+    NoTyping {
+        expr: UntyLamExpr,
+        expected: IntersectionType,
+    },
+    /// A discarded argument has no type, so it isn't strongly normalizing or doesn't fit
+    /// the context.
+    Untypable(UntyLamExpr),
+    /// Checking took more than this many β-expansions, most likely because the term isn't
+    /// strongly normalizing.
+    GaveUp(u32),
+}
+
+impl Display for IntersectionTypeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IntersectionTypeError::NotNormal(expr) => write!(
+                f,
+                "`{expr}` is not in normal form, so its typing has to be given"
+            ),
+            IntersectionTypeError::UnboundVar(v) => {
+                write!(f, "variable `{v}` has no type in the context")
+            }
+            IntersectionTypeError::NoTyping { expr, expected } => {
+                write!(f, "`{expr}` can't be given type `{expected}`")
+            }
+            IntersectionTypeError::Untypable(expr) => write!(
+                f,
+                "the discarded argument `{expr}` has no type in this context"
+            ),
+            IntersectionTypeError::GaveUp(steps) => write!(
+                f,
+                "gave up after {steps} β-steps, the term is probably not strongly normalizing"
+            ),
+        }
+    }
+}
+
+/// Type assignment with intersection types and without `ω`, where exactly the strongly
+/// normalizing terms are typable.
+pub struct IntersectionTypeChecker;
+impl IntersectionTypeChecker {
+    pub const MAX_STEPS: u32 = 1000;
+
+    /// The principal typing of a β-normal form, from which all its other typings follow.
+    pub fn principal_typing(
+        expr: &UntyLamExpr,
+    ) -> Result<IntersectionTyping, IntersectionTypeError> {
+        if !expr.is_normal_form(ReductionStrategy::NormalOrder) {
+            return Err(IntersectionTypeError::NotNormal(expr.clone()));
+        }
+        let mut next_atom = 0;
+        let (requirements, ty) = Self::infer_normal(expr, &mut next_atom);
+        let context = requirements
+            .into_iter()
+            .map(|(var, tys)| {
+                let ty = IntersectionType::intersection(tys).expect("only used variables");
+                (var, ty)
+            })
+            .collect();
+        Ok(IntersectionTyping { context, ty })
+    }
+
+    /// Checks `ctx ⊢ expr : ty`, following head β-redexes by subject expansion.
+    pub fn check(
+        ctx: &IntersectionContext,
+        expr: &UntyLamExpr,
+        ty: &IntersectionType,
+    ) -> Result<(), IntersectionTypeError> {
+        let mut steps = 0;
+        Self::check_in(&mut ctx.clone(), expr, ty, &mut steps)
+    }
+
+    fn fresh_atom(next_atom: &mut usize) -> IntersectionType {
+        let letter = char::from(b'a' + (*next_atom % 26) as u8);
+        let name = match *next_atom / 26 {
+            0 => letter.to_string(),
+            n => format!("{letter}{n}"),
+        };
+        *next_atom += 1;
+        IntersectionType::Atom(name)
+    }
+
+    // A normal form is `λx₁ … xₙ. y N₁ … Nₖ`: every use of a variable adds one component to
+    // its type.
+    fn infer_normal(
+        expr: &UntyLamExpr,
+        next_atom: &mut usize,
+    ) -> (BTreeMap<UntyVar, Vec<IntersectionType>>, IntersectionType) {
+        match expr {
+            UntyLamExpr::Abs(abs) => {
+                let (mut requirements, body) = Self::infer_normal(&abs.expr, next_atom);
+                let from = match requirements.remove(&abs.var) {
+                    Some(tys) => IntersectionType::intersection(tys).expect("only used variables"),
+                    None => Self::fresh_atom(next_atom),
+                };
+                (requirements, IntersectionType::arrow(from, body))
+            }
+            _ => {
+                let (head, args) = spine(expr);
+                let UntyLamExpr::Var(head) = head else {
+                    unreachable!("normal forms have a variable in head position")
+                };
+                let mut requirements: BTreeMap<UntyVar, Vec<IntersectionType>> = BTreeMap::new();
+                let mut arg_types = vec![];
+                for arg in args {
+                    let (arg_requirements, ty) = Self::infer_normal(arg, next_atom);
+                    for (var, tys) in arg_requirements {
+                        requirements.entry(var).or_default().extend(tys);
+                    }
+                    arg_types.push(ty);
+                }
+                let ty = Self::fresh_atom(next_atom);
+                let head_ty = arg_types
+                    .into_iter()
+                    .rev()
+                    .fold(ty.clone(), |to, from| IntersectionType::arrow(from, to));
+                requirements.entry(head.clone()).or_default().push(head_ty);
+                (requirements, ty)
+            }
+        }
+    }
+
+    fn check_in(
+        ctx: &mut IntersectionContext,
+        expr: &UntyLamExpr,
+        ty: &IntersectionType,
+        steps: &mut u32,
+    ) -> Result<(), IntersectionTypeError> {
+        if let IntersectionType::And(..) = ty {
+            return ty
+                .components()
+                .into_iter()
+                .try_for_each(|ty| Self::check_in(ctx, expr, ty, steps));
+        }
+        let mut expr = expr.clone();
+        while let Some(contractum) = Self::contract_head(ctx, &expr, steps)? {
+            expr = contractum;
+        }
+        let no_typing = || IntersectionTypeError::NoTyping {
+            expr: expr.clone(),
+            expected: ty.clone(),
+        };
+        let (head, args) = spine(&expr);
+        match head {
+            UntyLamExpr::Abs(abs) => {
+                let IntersectionType::Arrow(from, to) = ty else {
+                    return Err(no_typing());
+                };
+                ctx.push((abs.var.clone(), (**from).clone()));
+                let result = Self::check_in(ctx, &abs.expr, to, steps);
+                ctx.pop();
+                result
+            }
+            UntyLamExpr::Var(v) => {
+                let var_ty = ctx
+                    .iter()
+                    .rev()
+                    .find(|(var, _)| var == v)
+                    .map(|(_, ty)| ty.clone())
+                    .ok_or_else(|| IntersectionTypeError::UnboundVar(v.clone()))?;
+                let mut error = no_typing();
+                'components: for component in var_ty.components() {
+                    let mut arg_types = vec![];
+                    let mut result = component;
+                    for _ in &args {
+                        let IntersectionType::Arrow(from, to) = result else {
+                            continue 'components;
+                        };
+                        arg_types.push(&**from);
+                        result = to;
+                    }
+                    if !result.is_subtype(ty) {
+                        continue;
+                    }
+                    let checked = args
+                        .iter()
+                        .zip(arg_types)
+                        .try_for_each(|(arg, ty)| Self::check_in(ctx, arg, ty, steps));
+                    match checked {
+                        Ok(()) => return Ok(()),
+                        Err(err @ IntersectionTypeError::GaveUp(_)) => return Err(err),
+                        Err(err) => error = err,
+                    }
+                }
+                Err(error)
+            }
+            UntyLamExpr::App(_) => unreachable!("the head of a spine is no application"),
+        }
+    }
+
+    // A head redex has the types of its contractum, as long as a discarded argument is
+    // typable on its own.
+    fn contract_head(
+        ctx: &IntersectionContext,
+        expr: &UntyLamExpr,
+        steps: &mut u32,
+    ) -> Result<Option<UntyLamExpr>, IntersectionTypeError> {
+        let (UntyLamExpr::Abs(abs), args) = spine(expr) else {
+            return Ok(None);
+        };
+        let Some((arg, rest)) = args.split_first() else {
+            return Ok(None);
+        };
+        Self::count_step(steps)?;
+        if !abs.expr.free_vars().contains(&abs.var) {
+            Self::check_typable(ctx, arg, steps)?;
+        }
+        let contractum = rest
+            .iter()
+            .fold(abs.apply(arg), |e, arg| App::new_expr(e, (*arg).clone()));
+        Ok(Some(contractum))
+    }
+
+    fn count_step(steps: &mut u32) -> Result<(), IntersectionTypeError> {
+        *steps += 1;
+        if *steps > Self::MAX_STEPS {
+            Err(IntersectionTypeError::GaveUp(Self::MAX_STEPS))
+        } else {
+            Ok(())
+        }
+    }
+
+    // `expr` is typable in `ctx` iff its normal form is, which holds iff the principal
+    // typing of the normal form is satisfied by `ctx` for some choice of its atoms. Typings
+    // that need an expansion of the principal typing are missed.
+    fn check_typable(
+        ctx: &IntersectionContext,
+        expr: &UntyLamExpr,
+        steps: &mut u32,
+    ) -> Result<(), IntersectionTypeError> {
+        let mut normal = expr.clone();
+        while let Ok(next) = normal.calc_step(ReductionStrategy::NormalOrder) {
+            Self::count_step(steps)?;
+            normal = next;
+        }
+        let principal = Self::principal_typing(&normal)?;
+        let mut obligations = vec![];
+        for (var, requirement) in &principal.context {
+            let available = ctx
+                .iter()
+                .rev()
+                .find(|(v, _)| v == var)
+                .map(|(_, ty)| ty)
+                .ok_or_else(|| IntersectionTypeError::UnboundVar(var.clone()))?;
+            obligations.push(Obligation::Sub(requirement, available));
+        }
+        if satisfiable(&obligations, &BTreeMap::new()) {
+            Ok(())
+        } else {
+            Err(IntersectionTypeError::Untypable(expr.clone()))
+        }
+    }
+}
+
+fn spine(expr: &UntyLamExpr) -> (&UntyLamExpr, Vec<&UntyLamExpr>) {
+    let mut head = expr;
+    let mut args = vec![];
+    while let UntyLamExpr::App(app) = head {
+        args.push(&*app.rhs);
+        head = &app.lhs;
+    }
+    args.reverse();
+    (head, args)
+}
+
+// Constraints on a substitution `S` for the atoms of a principal typing.
+#[derive(Clone, Copy)]
+enum Obligation<'a> {
+    /// `target ≤ S(pattern)`
+    Sub(&'a IntersectionType, &'a IntersectionType),
+    /// `S(pattern) = target` for a single component `target`.
+    Eq(&'a IntersectionType, &'a IntersectionType),
+    /// `S(pattern) = target` once all atoms are known.
+    Final(&'a IntersectionType, &'a IntersectionType),
+}
+
+// Solves the obligations from the last one to the first one, trying every component that
+// could satisfy a `Sub`.
+fn satisfiable(obligations: &[Obligation], subst: &BTreeMap<String, IntersectionType>) -> bool {
+    let Some((&obligation, rest)) = obligations.split_last() else {
+        return true;
+    };
+    let with = |added: &[Obligation], subst: &BTreeMap<String, IntersectionType>| {
+        satisfiable(&[rest, added].concat(), subst)
+    };
+    match obligation {
+        Obligation::Sub(pattern, target) => match pattern.components()[..] {
+            [component] => {
+                let whole = matches!(component, IntersectionType::Atom(_)).then_some(target);
+                target
+                    .components()
+                    .into_iter()
+                    .chain(whole)
+                    .any(|t| with(&[Obligation::Eq(component, t)], subst))
+            }
+            ref components => {
+                let subs: Vec<_> = components
+                    .iter()
+                    .map(|c| Obligation::Sub(c, target))
+                    .collect();
+                with(&subs, subst)
+            }
+        },
+        Obligation::Eq(pattern, target) => match (pattern, target) {
+            (IntersectionType::Atom(name), _) => match subst.get(name) {
+                Some(bound) => bound.equiv(target) && with(&[], subst),
+                None => {
+                    let mut subst = subst.clone();
+                    subst.insert(name.clone(), target.clone());
+                    with(&[], &subst)
+                }
+            },
+            (
+                IntersectionType::Arrow(from, to),
+                IntersectionType::Arrow(target_from, target_to),
+            ) => with(
+                &[
+                    Obligation::Final(from, target_from),
+                    Obligation::Sub(from, target_from),
+                    Obligation::Eq(to, target_to),
+                ],
+                subst,
+            ),
+            (IntersectionType::Arrow(..), _) => false,
+            (IntersectionType::And(..), _) => with(
+                &[
+                    Obligation::Final(pattern, target),
+                    Obligation::Sub(pattern, target),
+                ],
+                subst,
+            ),
+        },
+        Obligation::Final(pattern, target) => {
+            pattern.substitute(subst).equiv(target) && with(&[], subst)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IntersectionType, IntersectionTypeChecker, IntersectionTypeError};
+    use crate::expressions::untyped_lambda_calculus::unty_var::UntyVar;
+    use crate::parsers::intersection_types::IntersectionTypeParser;
+    use crate::parsers::untyped_lambda_calculus::UntypedLambdaCalculusParser;
+
+    fn principal(input: &str) -> Result<String, String> {
+        let expr = UntypedLambdaCalculusParser::parse(input).unwrap();
+        IntersectionTypeChecker::principal_typing(&expr)
+            .map(|typing| typing.judgement(&expr))
+            .map_err(|err| err.to_string())
+    }
+
+    fn check(ctx: &str, input: &str, ty: &str) -> Result<(), IntersectionTypeError> {
+        let ctx = IntersectionTypeParser::parse_context(ctx).unwrap();
+        let expr = UntypedLambdaCalculusParser::parse(input).unwrap();
+        let ty = IntersectionTypeParser::parse_type(ty).unwrap();
+        IntersectionTypeChecker::check(&ctx, &expr, &ty)
+    }
+
+    #[test]
+    fn types() {
+        let a = || IntersectionType::atom("a");
+        let ty = IntersectionType::and(
+            IntersectionType::arrow(a(), a()),
+            IntersectionType::and(a(), IntersectionType::atom("b")),
+        );
+        assert_eq!(ty.to_string(), "(a → a) ∧ a ∧ b");
+        assert!(ty.is_subtype(&IntersectionType::and(IntersectionType::atom("b"), a())));
+        assert!(!a().is_subtype(&ty));
+        assert!(ty.equiv(&IntersectionType::and(ty.clone(), a())));
+    }
+
+    #[test]
+    fn principal_typings() {
+        assert_eq!(principal("λx. x"), Ok("⊢ λ x. x : a → a".to_string()));
+        assert_eq!(
+            principal("λx. x x"),
+            Ok("⊢ λ x. x x : a ∧ (a → b) → b".to_string())
+        );
+        assert_eq!(
+            principal("λx y. y"),
+            Ok("⊢ λ x. λ y. y : b → a → a".to_string())
+        );
+        assert_eq!(
+            principal("f x x"),
+            Ok("f: a → b → c, x: a ∧ b ⊢ f x x : c".to_string())
+        );
+        assert_eq!(
+            principal("(λx. x) y"),
+            Err("`(λ x. x) y` is not in normal form, so its typing has to be given".to_string())
+        );
+    }
+
+    #[test]
+    fn principal_typings_check() {
+        for input in [
+            "λx. x x",
+            "λf x. f (f x)",
+            "λx y z. x z (y z)",
+            "f (λx. x x) y",
+        ] {
+            let expr = UntypedLambdaCalculusParser::parse(input).unwrap();
+            let typing = IntersectionTypeChecker::principal_typing(&expr).unwrap();
+            assert_eq!(
+                IntersectionTypeChecker::check(&typing.context, &expr, &typing.ty),
+                Ok(())
+            );
+        }
+    }
+
+    #[test]
+    fn user_typings() {
+        assert_eq!(check("", "(λx. x x) (λy. y)", "a → a"), Ok(()));
+        assert_eq!(check("", "λx. x x", "(a → b) ∧ a → b"), Ok(()));
+        assert_eq!(check("", "λx. x x", "a ∧ (a → b) → b"), Ok(()));
+        assert_eq!(check("", "λx. x", "(a → a) ∧ (b → b)"), Ok(()));
+        assert_eq!(check("z: b", "(λx y. y) (λw. w) z", "b"), Ok(()));
+        assert_eq!(check("f: a → a, z: a", "(λx. z) (f z)", "a"), Ok(()));
+        assert_eq!(
+            check("z: a", "(λx. z) (z z)", "a"),
+            Err(IntersectionTypeError::Untypable(
+                UntypedLambdaCalculusParser::parse("z z").unwrap()
+            ))
+        );
+        assert_eq!(
+            check("", "λx. x x", "a → a"),
+            Err(IntersectionTypeError::NoTyping {
+                expr: UntypedLambdaCalculusParser::parse("x x").unwrap(),
+                expected: IntersectionType::atom("a"),
+            })
+        );
+        assert_eq!(
+            check("", "λx. y", "a → a"),
+            Err(IntersectionTypeError::UnboundVar(UntyVar::new("y")))
+        );
+        assert_eq!(
+            check("", "(λx. x x) (λx. x x)", "a"),
+            Err(IntersectionTypeError::GaveUp(
+                IntersectionTypeChecker::MAX_STEPS
+            ))
+        );
+        // `λx. x x` needs both components of its argument's type
+        assert_eq!(check("y: (a → a) ∧ a", "(λx. x x) y", "a"), Ok(()));
+        assert!(check("y: a → a", "(λx. x x) y", "a").is_err());
+    }
+}
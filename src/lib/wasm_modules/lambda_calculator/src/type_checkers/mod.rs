@@ -0,0 +1,12 @@
+pub mod bidirectional_lambda_calculus;
+pub mod derivation;
+pub mod hindley_milner;
+pub mod inhabitation;
+pub mod intersection_types;
+pub mod pcf;
+pub mod proof;
+pub mod pure_type_system;
+pub mod record_lambda_calculus;
+pub mod simply_typed_lambda_calculus;
+pub mod system_f;
+pub mod typed_lambda_calculus;
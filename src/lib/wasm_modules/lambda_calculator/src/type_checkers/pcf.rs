@@ -0,0 +1,269 @@
+use super::derivation::Derivation;
+use super::hindley_milner::{Inference, InferredType, MonoType, Namer, Scheme, UnifyError};
+use crate::expressions::pcf::PcfExpr;
+use crate::expressions::simply_typed_lambda_calculus::simple_type::SimpleType;
+use crate::expressions::untyped_lambda_calculus::unty_var::UntyVar;
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum PcfTypeError {
+    Mismatch {
+        expr: Box<PcfExpr>,
+        expected: SimpleType,
+        found: SimpleType,
+    },
+    InfiniteType {
+        expr: Box<PcfExpr>,
+        var: SimpleType,
+        ty: SimpleType,
+    },
+}
+
+impl Display for PcfTypeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PcfTypeError::Mismatch {
+                expr,
+                expected,
+                found,
+            } => write!(
+                f,
+                "`{expr}` has type `{found}`, but it is used where `{expected}` is expected"
+            ),
+            PcfTypeError::InfiniteType { expr, var, ty } => write!(
+                f,
+                "typing `{expr}` requires `{var} = {ty}`, and since `{var}` occurs in `{ty}` that type would be infinite"
+            ),
+        }
+    }
+}
+
+fn nat() -> MonoType {
+    MonoType::base("Nat")
+}
+
+impl Inference {
+    // `expr` was inferred to have type `found` but its context needs `expected`.
+    fn expect(
+        &mut self,
+        expr: &PcfExpr,
+        found: &MonoType,
+        expected: &MonoType,
+    ) -> Result<(), PcfTypeError> {
+        let mut namer = Namer::default();
+        match self.unify(found, expected) {
+            Ok(()) => Ok(()),
+            Err(UnifyError::Mismatch) => Err(PcfTypeError::Mismatch {
+                expr: Box::new(expr.clone()),
+                expected: namer.name(&self.resolve(expected)),
+                found: namer.name(&self.resolve(found)),
+            }),
+            Err(UnifyError::Occurs(var, ty)) => Err(PcfTypeError::InfiniteType {
+                expr: Box::new(expr.clone()),
+                var: namer.name(&MonoType::Var(var)),
+                ty: namer.name(&ty),
+            }),
+        }
+    }
+
+    fn derive_pcf(
+        &mut self,
+        expr: &PcfExpr,
+    ) -> (PendingDerivation, Result<MonoType, PcfTypeError>) {
+        let context = self.bindings();
+        let mut premises = vec![];
+        let ty = self.apply_rule(expr, &mut premises);
+        let rule = match expr {
+            PcfExpr::Var(_) => "Var",
+            PcfExpr::Abs(_) => "→I",
+            PcfExpr::App(_) => "→E",
+            PcfExpr::Nat(_) => "Nat",
+            PcfExpr::Succ(_) => "Succ",
+            PcfExpr::Pred(_) => "Pred",
+            PcfExpr::Ifz(_) => "Ifz",
+            PcfExpr::Fix(_) => "Fix",
+        };
+        let derivation = PendingDerivation {
+            rule,
+            context,
+            expr: expr.to_string(),
+            ty: ty.as_ref().ok().cloned(),
+            premises,
+        };
+        (derivation, ty)
+    }
+
+    fn apply_rule(
+        &mut self,
+        expr: &PcfExpr,
+        premises: &mut Vec<PendingDerivation>,
+    ) -> Result<MonoType, PcfTypeError> {
+        let mut premise = |this: &mut Self, expr: &PcfExpr| {
+            let (derivation, ty) = this.derive_pcf(expr);
+            premises.push(derivation);
+            ty
+        };
+        match expr {
+            PcfExpr::Var(v) => Ok(self.lookup(v)),
+            PcfExpr::Abs(abs) => {
+                let param = self.fresh();
+                let body = self.with_binding(&abs.var, Scheme::mono(param.clone()), |this| {
+                    premise(this, &abs.expr)
+                })?;
+                Ok(MonoType::arrow(param, body))
+            }
+            PcfExpr::App(app) => {
+                let func_ty = premise(self, &app.lhs)?;
+                let arg_ty = premise(self, &app.rhs)?;
+                let result = self.fresh();
+                self.expect(&app.lhs, &func_ty, &MonoType::arrow(arg_ty, result.clone()))?;
+                Ok(result)
+            }
+            PcfExpr::Nat(_) => Ok(nat()),
+            PcfExpr::Succ(succ) => {
+                let ty = premise(self, &succ.expr)?;
+                self.expect(&succ.expr, &ty, &nat())?;
+                Ok(nat())
+            }
+            PcfExpr::Pred(pred) => {
+                let ty = premise(self, &pred.expr)?;
+                self.expect(&pred.expr, &ty, &nat())?;
+                Ok(nat())
+            }
+            PcfExpr::Ifz(ifz) => {
+                let cond = premise(self, &ifz.cond)?;
+                self.expect(&ifz.cond, &cond, &nat())?;
+                let zero = premise(self, &ifz.zero)?;
+                let succ = premise(self, &ifz.succ)?;
+                self.expect(&ifz.succ, &succ, &zero)?;
+                Ok(zero)
+            }
+            PcfExpr::Fix(fix) => {
+                let ty = premise(self, &fix.expr)?;
+                let point = self.fresh();
+                self.expect(
+                    &fix.expr,
+                    &ty,
+                    &MonoType::arrow(point.clone(), point.clone()),
+                )?;
+                Ok(point)
+            }
+        }
+    }
+
+    // Types in the tree are only known once inference has finished. The assumptions for free
+    // variables are the outermost part of every context, as in `InferredType`.
+    fn name_derivation(&self, pending: PendingDerivation, namer: &mut Namer) -> Derivation {
+        let context: Vec<_> = self
+            .assumptions()
+            .iter()
+            .chain(&pending.context)
+            .map(|(v, ty)| (v, namer.name(&self.resolve(ty))))
+            .collect();
+        let ty = pending.ty.map(|ty| namer.name(&self.resolve(&ty)));
+        let premises = pending
+            .premises
+            .into_iter()
+            .map(|premise| self.name_derivation(premise, namer))
+            .collect();
+        Derivation::new(pending.rule, &context, &pending.expr, ty.as_ref(), premises)
+    }
+}
+
+// A `Derivation` whose types still contain the variables of the inference.
+struct PendingDerivation {
+    rule: &'static str,
+    context: Vec<(UntyVar, MonoType)>,
+    expr: String,
+    ty: Option<MonoType>,
+    premises: Vec<PendingDerivation>,
+}
+
+/// Monomorphic type inference with `Nat` as the only base type. PCF terms run fine without it;
+/// a well-typed term can only get stuck on a free variable.
+pub struct PcfChecker;
+impl PcfChecker {
+    pub fn infer(expr: &PcfExpr) -> Result<InferredType, PcfTypeError> {
+        Self::derive(expr).1
+    }
+
+    /// The derivation of the type of `expr`, which stops at the first failing rule.
+    pub fn derive(expr: &PcfExpr) -> (Derivation, Result<InferredType, PcfTypeError>) {
+        let mut inference = Inference::default();
+        let (pending, ty) = inference.derive_pcf(expr);
+        let ty = ty.map(|ty| inference.result(&ty));
+        let mut namer = Namer::default();
+        (inference.name_derivation(pending, &mut namer), ty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PcfChecker;
+    use crate::parsers::pcf::PcfParser;
+
+    fn infer(input: &str) -> Result<String, String> {
+        let expr = PcfParser::parse(input).unwrap();
+        PcfChecker::infer(&expr)
+            .map(|ty| ty.to_string())
+            .map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn well_typed() {
+        assert_eq!(infer("succ 1"), Ok("Nat".to_string()));
+        assert_eq!(
+            infer("fix (λadd. λm n. ifz m then n else succ (add (pred m) n))"),
+            Ok("Nat → Nat → Nat".to_string())
+        );
+        assert_eq!(
+            infer("λf x. ifz x then f x else x"),
+            Ok("(Nat → Nat) → Nat → Nat".to_string())
+        );
+        assert_eq!(infer("fix (λx. x)"), Ok("a".to_string()));
+        assert_eq!(infer("succ n"), Ok("n: Nat ⊢ Nat".to_string()));
+    }
+
+    #[test]
+    fn derivations() {
+        let expr = PcfParser::parse("λf. f (succ n)").unwrap();
+        let (derivation, ty) = PcfChecker::derive(&expr);
+        assert_eq!(ty.unwrap().to_string(), "n: Nat ⊢ (Nat → a) → a");
+        assert_eq!(derivation.ty.as_deref(), Some("(Nat → a) → a"));
+        let app = &derivation.premises[0];
+        assert_eq!(app.rule, "→E");
+        let context: Vec<_> = app
+            .context
+            .iter()
+            .map(|(v, ty)| format!("{v}: {ty}"))
+            .collect();
+        assert_eq!(context, vec!["n: Nat", "f: Nat → a"]);
+        assert_eq!(app.premises[1].rule, "Succ");
+        assert_eq!(app.premises[1].premises[0].rule, "Var");
+
+        let expr = PcfParser::parse("ifz 0 then 1 else λx. x").unwrap();
+        let (derivation, ty) = PcfChecker::derive(&expr);
+        assert!(ty.is_err());
+        assert_eq!(derivation.ty, None);
+        let rules: Vec<_> = derivation.premises.iter().map(|p| p.rule).collect();
+        assert_eq!(rules, vec!["Nat", "Nat", "→I"]);
+        assert!(derivation.premises.iter().all(|p| p.is_complete()));
+    }
+
+    #[test]
+    fn ill_typed() {
+        assert_eq!(
+            infer("succ (λx. x)"),
+            Err("`λ x. x` has type `a → a`, but it is used where `Nat` is expected".to_string())
+        );
+        assert_eq!(
+            infer("ifz 0 then 1 else λx. x"),
+            Err("`λ x. x` has type `a → a`, but it is used where `Nat` is expected".to_string())
+        );
+        assert_eq!(
+            infer("2 3"),
+            Err("`2` has type `Nat`, but it is used where `Nat → a` is expected".to_string())
+        );
+        assert!(infer("λx. x x").is_err());
+    }
+}
@@ -0,0 +1,353 @@
+use super::bidirectional_lambda_calculus::{
+    BidirTypeError, BidirectionalLambdaCalculusChecker, Elaborator,
+};
+use super::derivation::Derivation;
+use super::typed_lambda_calculus::TypedLamContext;
+use crate::expressions::bidirectional_lambda_calculus::{hole::Hole, BidirExpr};
+use crate::expressions::typed_lambda_calculus::{ty::Type, Side, TypedLamExpr};
+use std::fmt::Display;
+
+/// A hole of a proof and the proposition it has to prove from the hypotheses in scope.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Goal {
+    pub hole: Hole,
+    /// Later entries shadow earlier ones.
+    pub context: TypedLamContext,
+    pub ty: Type,
+}
+
+impl Display for Goal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, (var, ty)) in self.context.iter().enumerate() {
+            let separator = if i + 1 < self.context.len() { "," } else { "" };
+            write!(f, "{var} : {}{separator} ", ty.as_proposition())?;
+        }
+        write!(f, "⊢ {} : {}", self.hole, self.ty.as_proposition())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ProofError {
+    Type(BidirTypeError),
+    /// `fold` and `unfold` have no counterpart in propositional logic.
+    NotLogical(Box<BidirExpr>),
+    NoSuchHole(usize),
+}
+
+impl Display for ProofError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProofError::Type(err) => write!(f, "{err}"),
+            ProofError::NotLogical(expr) => write!(
+                f,
+                "`{expr}` is not a proof in intuitionistic propositional logic"
+            ),
+            ProofError::NoSuchHole(id) => write!(f, "there is no hole `?{id}` to fill in"),
+        }
+    }
+}
+
+impl From<BidirTypeError> for ProofError {
+    fn from(err: BidirTypeError) -> Self {
+        ProofError::Type(err)
+    }
+}
+
+/// Checks partial proof terms with `BidirectionalLambdaCalculusChecker`, but collects holes as
+/// goals instead of rejecting them, and builds the natural deduction proof with the hypotheses
+/// named by the variables of the term.
+pub struct ProofChecker {
+    goals: Vec<Goal>,
+}
+
+impl ProofChecker {
+    pub fn check(expr: &BidirExpr, ty: &Type) -> Result<(Derivation, Vec<Goal>), ProofError> {
+        let mut checker = Self { goals: vec![] };
+        let derivation =
+            BidirectionalLambdaCalculusChecker::check_with(&mut checker, &mut vec![], expr, ty)?;
+        Ok((derivation, checker.goals))
+    }
+
+    fn node(
+        rule: &'static str,
+        ctx: &TypedLamContext,
+        expr: &BidirExpr,
+        ty: &Type,
+        premises: Vec<Derivation>,
+    ) -> Derivation {
+        let hypotheses: Vec<_> = ctx
+            .iter()
+            .map(|(var, ty)| (var, ty.as_proposition()))
+            .collect();
+        Derivation::new(
+            rule,
+            &hypotheses,
+            expr,
+            Some(&ty.as_proposition()),
+            premises,
+        )
+    }
+}
+
+impl Elaborator for ProofChecker {
+    type Output = Derivation;
+    type Error = ProofError;
+
+    fn enter(&mut self, expr: &BidirExpr) -> Result<(), ProofError> {
+        match expr {
+            BidirExpr::Fold(_) | BidirExpr::Unfold(_) => {
+                Err(ProofError::NotLogical(Box::new(expr.clone())))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn rule(
+        &mut self,
+        ctx: &TypedLamContext,
+        expr: &BidirExpr,
+        ty: &Type,
+        premises: Vec<Derivation>,
+    ) -> Result<Derivation, ProofError> {
+        let rule = match expr {
+            BidirExpr::Var(_) => "Hyp",
+            BidirExpr::Abs(_) | BidirExpr::TypedAbs(_) => "→I",
+            BidirExpr::App(_) => "→E",
+            BidirExpr::Unit(_) => "⊤I",
+            BidirExpr::Pair(_) => "∧I",
+            BidirExpr::Proj(proj) => match proj.side {
+                Side::Left => "∧E₁",
+                Side::Right => "∧E₂",
+            },
+            BidirExpr::Inj(inj) => match inj.side {
+                Side::Left => "∨I₁",
+                Side::Right => "∨I₂",
+            },
+            BidirExpr::Case(_) => "∨E",
+            BidirExpr::Absurd(_) => "⊥E",
+            BidirExpr::Fold(_) | BidirExpr::Unfold(_) | BidirExpr::Ann(_) | BidirExpr::Hole(_) => {
+                unreachable!("proofs have no rule for `{expr}`")
+            }
+        };
+        Ok(Self::node(rule, ctx, expr, ty, premises))
+    }
+
+    fn hole(
+        &mut self,
+        ctx: &TypedLamContext,
+        hole: Hole,
+        ty: &Type,
+    ) -> Result<Derivation, ProofError> {
+        self.goals.push(Goal {
+            hole,
+            context: ctx.clone(),
+            ty: ty.clone(),
+        });
+        Ok(Self::node("?", ctx, &hole.into_expr(), ty, vec![]))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ProofStep {
+    term: BidirExpr,
+    derivation: Derivation,
+    goals: Vec<Goal>,
+    next_hole: usize,
+}
+
+/// A proof of a proposition that starts as a single hole and is refined one hole at a time.
+/// Every refinement is checked, so the proof term never becomes ill-typed.
+#[derive(Debug, Clone)]
+pub struct Proof {
+    proposition: Type,
+    steps: Vec<ProofStep>,
+}
+
+impl Proof {
+    pub fn new(proposition: Type) -> Self {
+        let term = Hole::new_expr(0);
+        let (derivation, goals) =
+            ProofChecker::check(&term, &proposition).expect("a hole proves anything");
+        Self {
+            proposition,
+            steps: vec![ProofStep {
+                term,
+                derivation,
+                goals,
+                next_hole: 1,
+            }],
+        }
+    }
+
+    fn current(&self) -> &ProofStep {
+        self.steps.last().expect("a proof has at least one step")
+    }
+
+    pub fn proposition(&self) -> &Type {
+        &self.proposition
+    }
+
+    pub fn term(&self) -> &BidirExpr {
+        &self.current().term
+    }
+
+    /// The open goals, from left to right.
+    pub fn goals(&self) -> &[Goal] {
+        &self.current().goals
+    }
+
+    pub fn derivation(&self) -> &Derivation {
+        &self.current().derivation
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.goals().is_empty()
+    }
+
+    /// Fills `?hole` with `fragment`, whose holes get fresh numbers. A rejected refinement
+    /// leaves the proof unchanged.
+    pub fn refine(&mut self, hole: usize, fragment: &BidirExpr) -> Result<(), ProofError> {
+        let current = self.current();
+        if !current.goals.iter().any(|goal| goal.hole.id() == hole) {
+            return Err(ProofError::NoSuchHole(hole));
+        }
+        let mut next_hole = current.next_hole;
+        let fragment = fragment.map_holes(&mut |_| {
+            next_hole += 1;
+            Hole::new_expr(next_hole - 1)
+        });
+        let term = current.term.map_holes(&mut |h| {
+            if h.id() == hole {
+                fragment.clone()
+            } else {
+                h.into_expr()
+            }
+        });
+        let (derivation, goals) = ProofChecker::check(&term, &self.proposition)?;
+        self.steps.push(ProofStep {
+            term,
+            derivation,
+            goals,
+            next_hole,
+        });
+        Ok(())
+    }
+
+    /// Takes back the last refinement, returning whether there was one.
+    pub fn undo(&mut self) -> bool {
+        if self.steps.len() > 1 {
+            self.steps.pop();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// The finished proof elaborated into the typed lambda calculus, `None` while goals are open.
+    pub fn proof_term(&self) -> Option<TypedLamExpr> {
+        if !self.is_complete() {
+            return None;
+        }
+        BidirectionalLambdaCalculusChecker::check(self.term(), &self.proposition).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Proof, ProofError};
+    use crate::parsers::bidirectional_lambda_calculus::BidirectionalLambdaCalculusParser;
+    use crate::parsers::typed_lambda_calculus::TypedLambdaCalculusParser;
+
+    fn start(proposition: &str) -> Proof {
+        Proof::new(TypedLambdaCalculusParser::parse_type(proposition).unwrap())
+    }
+
+    fn refine(proof: &mut Proof, hole: usize, input: &str) -> Result<(), ProofError> {
+        proof.refine(
+            hole,
+            &BidirectionalLambdaCalculusParser::parse(input).unwrap(),
+        )
+    }
+
+    fn goals(proof: &Proof) -> Vec<String> {
+        proof.goals().iter().map(|goal| goal.to_string()).collect()
+    }
+
+    #[test]
+    fn and_commutes() {
+        let mut proof = start("A ∧ B → B ∧ A");
+        assert_eq!(goals(&proof), vec!["⊢ ?0 : A ∧ B → B ∧ A"]);
+        refine(&mut proof, 0, "λp. (?, ?)").unwrap();
+        assert_eq!(proof.term().to_string(), "λ p. (?1, ?2)");
+        assert_eq!(
+            goals(&proof),
+            vec!["p : A ∧ B ⊢ ?1 : B", "p : A ∧ B ⊢ ?2 : A"]
+        );
+        refine(&mut proof, 2, "fst p").unwrap();
+        assert_eq!(goals(&proof), vec!["p : A ∧ B ⊢ ?1 : B"]);
+        assert!(!proof.is_complete());
+        assert_eq!(proof.proof_term(), None);
+        refine(&mut proof, 1, "snd p").unwrap();
+        assert!(proof.is_complete());
+        assert_eq!(
+            proof.proof_term().unwrap().to_string(),
+            "λ p: A × B. (snd p, fst p)"
+        );
+        let derivation = proof.derivation();
+        assert_eq!(derivation.rule, "→I");
+        assert_eq!(derivation.premises[0].rule, "∧I");
+        assert_eq!(derivation.premises[0].premises[0].rule, "∧E₂");
+        assert_eq!(derivation.premises[0].premises[0].premises[0].rule, "Hyp");
+    }
+
+    #[test]
+    fn rejected_refinements_change_nothing() {
+        let mut proof = start("A → ¬¬A");
+        refine(&mut proof, 0, "λa k. ?").unwrap();
+        assert_eq!(goals(&proof), vec!["a : A, k : ¬A ⊢ ?1 : ⊥"]);
+        assert_eq!(
+            refine(&mut proof, 1, "a").map_err(|err| err.to_string()),
+            Err("checking `a` against `Empty` failed: its synthesized type is `A`".to_string())
+        );
+        assert_eq!(refine(&mut proof, 0, "a"), Err(ProofError::NoSuchHole(0)));
+        assert!(matches!(
+            refine(&mut proof, 1, "unfold a"),
+            Err(ProofError::NotLogical(_))
+        ));
+        assert_eq!(proof.term().to_string(), "λ a. λ k. ?1");
+        refine(&mut proof, 1, "k ?").unwrap();
+        refine(&mut proof, 2, "a").unwrap();
+        assert!(proof.is_complete());
+        assert!(proof.undo());
+        assert_eq!(goals(&proof), vec!["a : A, k : ¬A ⊢ ?2 : A"]);
+    }
+
+    #[test]
+    fn or_elimination() {
+        let mut proof = start("A ∨ B → B ∨ A");
+        refine(&mut proof, 0, "λs. case s of inl a ⇒ ? | inr b ⇒ ?").unwrap();
+        refine(&mut proof, 1, "inr a").unwrap();
+        refine(&mut proof, 2, "inl b as B ∨ A").unwrap();
+        let derivation = proof.derivation();
+        assert_eq!(derivation.premises[0].rule, "∨E");
+        let rules: Vec<_> = derivation.premises[0]
+            .premises
+            .iter()
+            .map(|premise| premise.rule)
+            .collect();
+        assert_eq!(rules, vec!["Hyp", "∨I₂", "∨I₁"]);
+        assert!(derivation.to_latex().contains("\\lor "));
+    }
+
+    #[test]
+    fn or_introduction_without_type() {
+        let mut proof = start("A → A ∨ B");
+        refine(&mut proof, 0, "λa. inl ?").unwrap();
+        assert_eq!(goals(&proof), vec!["a : A ⊢ ?1 : A"]);
+        refine(&mut proof, 1, "a").unwrap();
+        assert_eq!(
+            proof.proof_term().unwrap().to_string(),
+            "λ a: A. inl a as A + B"
+        );
+    }
+}
@@ -0,0 +1,529 @@
+use crate::expressions::pure_type_system::{pi::Pi, sort::Sort, PtsExpr};
+use crate::expressions::untyped_lambda_calculus::unty_var::UntyVar;
+use crate::expressions::{Expression, FreeVars, ReductionStrategy, Substitution};
+use crate::type_checkers::derivation::Derivation;
+use std::collections::BTreeSet;
+use std::fmt::Display;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+/// Conversion checks give up after this many reduction steps on either side.
+pub const MAX_NORMALIZATION_STEPS: u32 = 10_000;
+
+/// A pure type system: `axioms` type sorts by sorts, `rules` say which products may be formed.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PureTypeSystem {
+    pub sorts: Vec<Sort>,
+    pub axioms: Vec<(Sort, Sort)>,
+    pub rules: Vec<(Sort, Sort, Sort)>,
+}
+
+impl PureTypeSystem {
+    pub fn new(
+        sorts: Vec<Sort>,
+        axioms: Vec<(Sort, Sort)>,
+        rules: Vec<(Sort, Sort, Sort)>,
+    ) -> Self {
+        Self {
+            sorts,
+            axioms,
+            rules,
+        }
+    }
+
+    fn axiom(&self, s: &Sort) -> Option<&Sort> {
+        self.axioms.iter().find(|(s1, _)| s1 == s).map(|(_, s2)| s2)
+    }
+
+    fn rule(&self, s1: &Sort, s2: &Sort) -> Option<&Sort> {
+        self.rules
+            .iter()
+            .find(|(r1, r2, _)| r1 == s1 && r2 == s2)
+            .map(|(_, _, s3)| s3)
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum LambdaCube {
+    /// λ→, terms depending on terms only.
+    SimplyTyped = 0,
+    /// λ2, adds terms depending on types.
+    SecondOrder = 1,
+    /// λP, adds types depending on terms.
+    DependentTypes = 2,
+    /// λω̲, adds types depending on types.
+    TypeOperators = 3,
+    /// λP2
+    DependentSecondOrder = 4,
+    /// λω, also known as System Fω.
+    HigherOrder = 5,
+    /// λPω̲
+    DependentTypeOperators = 6,
+    /// λC, the calculus of constructions.
+    Constructions = 7,
+}
+
+impl LambdaCube {
+    pub fn all() -> [Self; 8] {
+        [
+            Self::SimplyTyped,
+            Self::SecondOrder,
+            Self::DependentTypes,
+            Self::TypeOperators,
+            Self::DependentSecondOrder,
+            Self::HigherOrder,
+            Self::DependentTypeOperators,
+            Self::Constructions,
+        ]
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::SimplyTyped => "λ→",
+            Self::SecondOrder => "λ2",
+            Self::DependentTypes => "λP",
+            Self::TypeOperators => "λω̲",
+            Self::DependentSecondOrder => "λP2",
+            Self::HigherOrder => "λω",
+            Self::DependentTypeOperators => "λPω̲",
+            Self::Constructions => "λC",
+        }
+    }
+
+    // each corner adds a subset of the three dimensions to (*, *)
+    fn dimensions(self) -> (bool, bool, bool) {
+        let (polymorphism, dependent, operators) = match self {
+            Self::SimplyTyped => (false, false, false),
+            Self::SecondOrder => (true, false, false),
+            Self::DependentTypes => (false, true, false),
+            Self::TypeOperators => (false, false, true),
+            Self::DependentSecondOrder => (true, true, false),
+            Self::HigherOrder => (true, false, true),
+            Self::DependentTypeOperators => (false, true, true),
+            Self::Constructions => (true, true, true),
+        };
+        (polymorphism, dependent, operators)
+    }
+
+    pub fn system(self) -> PureTypeSystem {
+        let star = Sort::star;
+        let boxed = Sort::boxed;
+        let (polymorphism, dependent, operators) = self.dimensions();
+        let mut rules = vec![(star(), star(), star())];
+        if polymorphism {
+            rules.push((boxed(), star(), star()));
+        }
+        if dependent {
+            rules.push((star(), boxed(), boxed()));
+        }
+        if operators {
+            rules.push((boxed(), boxed(), boxed()));
+        }
+        PureTypeSystem::new(vec![star(), boxed()], vec![(star(), boxed())], rules)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum PtsTypeError {
+    UnboundVar(UntyVar),
+    NoAxiom(Sort),
+    NotASort {
+        expr: Box<PtsExpr>,
+        ty: Box<PtsExpr>,
+    },
+    NoRule {
+        product: Box<PtsExpr>,
+        domain: Sort,
+        codomain: Sort,
+    },
+    NotAFunction {
+        func: Box<PtsExpr>,
+        ty: Box<PtsExpr>,
+    },
+    ArgumentMismatch {
+        func: Box<PtsExpr>,
+        arg: Box<PtsExpr>,
+        expected: Box<PtsExpr>,
+        found: Box<PtsExpr>,
+    },
+    NormalizationLimit(Box<PtsExpr>),
+}
+
+impl Display for PtsTypeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PtsTypeError::UnboundVar(v) => write!(f, "variable `{v}` is not bound"),
+            PtsTypeError::NoAxiom(s) => write!(f, "the sort `{s}` has no type in this system"),
+            PtsTypeError::NotASort { expr, ty } => write!(
+                f,
+                "`{expr}` is used as a type, but its type `{ty}` is not a sort"
+            ),
+            PtsTypeError::NoRule {
+                product,
+                domain,
+                codomain,
+            } => write!(
+                f,
+                "`{product}` is not allowed: this system has no rule ({domain}, {codomain})"
+            ),
+            PtsTypeError::NotAFunction { func, ty } => write!(
+                f,
+                "`{func}` is applied to an argument, but it has type `{ty}`, which is not a product"
+            ),
+            PtsTypeError::ArgumentMismatch {
+                func,
+                arg,
+                expected,
+                found,
+            } => write!(
+                f,
+                "`{func}` expects an argument of type `{expected}`, but `{arg}` has type `{found}`, which does not convert to it"
+            ),
+            PtsTypeError::NormalizationLimit(expr) => write!(
+                f,
+                "`{expr}` has no normal form within {MAX_NORMALIZATION_STEPS} steps"
+            ),
+        }
+    }
+}
+
+pub type PtsContext = Vec<(UntyVar, PtsExpr)>;
+
+pub struct PureTypeSystemChecker;
+impl PureTypeSystemChecker {
+    pub fn type_of(system: &PureTypeSystem, expr: &PtsExpr) -> Result<PtsExpr, PtsTypeError> {
+        Self::type_of_in(system, &mut vec![], expr)
+    }
+
+    /// Later entries of `ctx` shadow earlier ones; their types may mention earlier variables.
+    pub fn type_of_in(
+        system: &PureTypeSystem,
+        ctx: &mut PtsContext,
+        expr: &PtsExpr,
+    ) -> Result<PtsExpr, PtsTypeError> {
+        Self::derive_in(system, ctx, expr).1
+    }
+
+    /// The derivation of the type of `expr`, which stops at the first failing rule. Conversion
+    /// is part of the application rule rather than a node of its own.
+    pub fn derive(
+        system: &PureTypeSystem,
+        expr: &PtsExpr,
+    ) -> (Derivation, Result<PtsExpr, PtsTypeError>) {
+        Self::derive_in(system, &mut vec![], expr)
+    }
+
+    pub fn derive_in(
+        system: &PureTypeSystem,
+        ctx: &mut PtsContext,
+        expr: &PtsExpr,
+    ) -> (Derivation, Result<PtsExpr, PtsTypeError>) {
+        let mut premises = vec![];
+        let ty = Self::apply_rule(system, ctx, expr, &mut premises);
+        let rule = match expr {
+            PtsExpr::Var(_) => "Var",
+            PtsExpr::Sort(_) => "Axiom",
+            PtsExpr::Pi(_) => "Π",
+            PtsExpr::Abs(_) => "λ",
+            PtsExpr::App(_) => "App",
+        };
+        let derivation = Derivation::new(rule, ctx, expr, ty.as_ref().ok(), premises);
+        (derivation, ty)
+    }
+
+    fn apply_rule(
+        system: &PureTypeSystem,
+        ctx: &mut PtsContext,
+        expr: &PtsExpr,
+        premises: &mut Vec<Derivation>,
+    ) -> Result<PtsExpr, PtsTypeError> {
+        match expr {
+            PtsExpr::Var(v) => ctx
+                .iter()
+                .rev()
+                .find(|(var, _)| var == v)
+                .map(|(_, ty)| ty.clone())
+                .ok_or_else(|| PtsTypeError::UnboundVar(v.clone())),
+            PtsExpr::Sort(s) => system
+                .axiom(s)
+                .map(|s| s.clone().into_expr())
+                .ok_or_else(|| PtsTypeError::NoAxiom(s.clone())),
+            PtsExpr::Pi(pi) => {
+                let domain = Self::sort_of(system, ctx, &pi.ty, premises)?;
+                let (var, body) = Self::enter(ctx, &pi.var, &pi.body);
+                ctx.push((var, (*pi.ty).clone()));
+                let codomain = Self::sort_of(system, ctx, &body, premises);
+                ctx.pop();
+                let codomain = codomain?;
+                system
+                    .rule(&domain, &codomain)
+                    .map(|s| s.clone().into_expr())
+                    .ok_or_else(|| PtsTypeError::NoRule {
+                        product: Box::new(expr.clone()),
+                        domain,
+                        codomain,
+                    })
+            }
+            PtsExpr::Abs(abs) => {
+                Self::sort_of(system, ctx, &abs.ty, premises)?;
+                let (var, body) = Self::enter(ctx, &abs.var, &abs.expr);
+                ctx.push((var.clone(), abs.ty.as_ref().clone()));
+                let body_ty = Self::premise(system, ctx, &body, premises);
+                ctx.pop();
+                let product: PtsExpr = Pi::new_expr(var, abs.ty.as_ref().clone(), body_ty?);
+                Self::sort_of(system, ctx, &product, premises)?;
+                Ok(product)
+            }
+            PtsExpr::App(app) => {
+                let func_ty = Self::premise(system, ctx, &app.lhs, premises)?;
+                let arg_ty = Self::premise(system, ctx, &app.rhs, premises)?;
+                match Self::normalize(&func_ty)? {
+                    PtsExpr::Pi(pi) => {
+                        if Self::convertible(&pi.ty, &arg_ty)? {
+                            Ok(pi.apply(&app.rhs))
+                        } else {
+                            Err(PtsTypeError::ArgumentMismatch {
+                                func: app.lhs.clone(),
+                                arg: app.rhs.clone(),
+                                expected: pi.ty,
+                                found: Box::new(arg_ty),
+                            })
+                        }
+                    }
+                    _ => Err(PtsTypeError::NotAFunction {
+                        func: app.lhs.clone(),
+                        ty: Box::new(func_ty),
+                    }),
+                }
+            }
+        }
+    }
+
+    fn premise(
+        system: &PureTypeSystem,
+        ctx: &mut PtsContext,
+        expr: &PtsExpr,
+        premises: &mut Vec<Derivation>,
+    ) -> Result<PtsExpr, PtsTypeError> {
+        let (derivation, ty) = Self::derive_in(system, ctx, expr);
+        premises.push(derivation);
+        ty
+    }
+
+    /// Reduces `expr` to its normal form, reducing inside binders and their annotations.
+    pub fn normalize(expr: &PtsExpr) -> Result<PtsExpr, PtsTypeError> {
+        let mut expr = expr.clone();
+        for _ in 0..MAX_NORMALIZATION_STEPS {
+            match expr.calc_step(ReductionStrategy::NormalOrder) {
+                Ok(next) => expr = next,
+                Err(_) => return Ok(expr),
+            }
+        }
+        Err(PtsTypeError::NormalizationLimit(Box::new(expr)))
+    }
+
+    /// Whether `a` and `b` have alpha-equivalent normal forms.
+    pub fn convertible(a: &PtsExpr, b: &PtsExpr) -> Result<bool, PtsTypeError> {
+        Ok(Self::normalize(a)?.alpha_eq(&Self::normalize(b)?))
+    }
+
+    fn sort_of(
+        system: &PureTypeSystem,
+        ctx: &mut PtsContext,
+        expr: &PtsExpr,
+        premises: &mut Vec<Derivation>,
+    ) -> Result<Sort, PtsTypeError> {
+        let ty = Self::premise(system, ctx, expr, premises)?;
+        match Self::normalize(&ty)? {
+            PtsExpr::Sort(s) => Ok(s),
+            _ => Err(PtsTypeError::NotASort {
+                expr: Box::new(expr.clone()),
+                ty: Box::new(ty),
+            }),
+        }
+    }
+
+    // A binder that shadows a variable the context still refers to is renamed first, otherwise
+    // the types in the context would suddenly talk about the inner variable.
+    fn enter(ctx: &PtsContext, var: &UntyVar, body: &PtsExpr) -> (UntyVar, PtsExpr) {
+        let mut avoid: BTreeSet<UntyVar> = ctx.iter().map(|(v, _)| v.clone()).collect();
+        if !avoid.contains(var) {
+            return (var.clone(), body.clone());
+        }
+        for (_, ty) in ctx {
+            avoid.extend(ty.free_vars());
+        }
+        avoid.extend(body.free_vars());
+        let fresh = var.fresh(&avoid);
+        let body = body.substitute(var, &fresh.clone().into_expr());
+        (fresh, body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LambdaCube, PureTypeSystemChecker};
+    use crate::expressions::pure_type_system::sort::Sort;
+    use crate::expressions::untyped_lambda_calculus::unty_var::UntyVar;
+    use crate::parsers::pure_type_system::PureTypeSystemParser;
+
+    fn type_in(cube: LambdaCube, ctx: &[(&str, &str)], input: &str) -> Result<String, String> {
+        let mut ctx = ctx
+            .iter()
+            .map(|(v, ty)| (UntyVar::new(v), PureTypeSystemParser::parse(ty).unwrap()))
+            .collect();
+        let expr = PureTypeSystemParser::parse(input).unwrap();
+        PureTypeSystemChecker::type_of_in(&cube.system(), &mut ctx, &expr)
+            .map(|ty| ty.to_string())
+            .map_err(|err| err.to_string())
+    }
+
+    fn type_of(cube: LambdaCube, input: &str) -> Result<String, String> {
+        type_in(cube, &[], input)
+    }
+
+    #[test]
+    fn presets() {
+        for cube in LambdaCube::all() {
+            let system = cube.system();
+            assert_eq!(system.sorts, vec![Sort::star(), Sort::boxed()]);
+            assert!(system
+                .rules
+                .contains(&(Sort::star(), Sort::star(), Sort::star())));
+        }
+        assert_eq!(LambdaCube::SimplyTyped.system().rules.len(), 1);
+        assert_eq!(LambdaCube::Constructions.system().rules.len(), 4);
+    }
+
+    #[test]
+    fn simply_typed() {
+        let ctx = [("A", "*"), ("B", "*")];
+        assert_eq!(
+            type_in(LambdaCube::SimplyTyped, &ctx, "λf:A → B. λx:A. f x"),
+            Ok("(A → B) → A → B".to_string())
+        );
+        assert_eq!(
+            type_of(LambdaCube::SimplyTyped, "λA:*. λx:A. x"),
+            Err("`Π A: *. A → A` is not allowed: this system has no rule (□, *)".to_string())
+        );
+        assert_eq!(
+            type_of(LambdaCube::Constructions, "□"),
+            Err("the sort `□` has no type in this system".to_string())
+        );
+    }
+
+    #[test]
+    fn polymorphism() {
+        assert_eq!(
+            type_of(LambdaCube::SecondOrder, "λA:*. λx:A. x"),
+            Ok("Π A: *. A → A".to_string())
+        );
+        assert_eq!(
+            type_of(
+                LambdaCube::SecondOrder,
+                "(λA:*. λx:A. x) (Π A: *. A → A) (λA:*. λx:A. x)"
+            ),
+            Ok("Π A: *. A → A".to_string())
+        );
+    }
+
+    #[test]
+    fn dependent_types() {
+        let ctx = [("A", "*"), ("P", "A → *")];
+        assert_eq!(
+            type_in(LambdaCube::DependentTypes, &ctx, "λx:A. λp:P x. p"),
+            Ok("Π x: A. P x → P x".to_string())
+        );
+        assert_eq!(
+            type_in(LambdaCube::DependentTypes, &ctx, "Π x: A. P x"),
+            Ok("*".to_string())
+        );
+        assert_eq!(
+            type_in(LambdaCube::SimplyTyped, &ctx, "λQ:A → *. Q"),
+            Err("`A → *` is not allowed: this system has no rule (*, □)".to_string())
+        );
+    }
+
+    #[test]
+    fn conversion() {
+        let ctx = [("A", "*")];
+        assert_eq!(
+            type_in(
+                LambdaCube::HigherOrder,
+                &ctx,
+                "(λx:(λT:*. T → T) A. x) (λy:A. y)"
+            ),
+            Ok("A → A".to_string())
+        );
+        assert_eq!(
+            type_in(LambdaCube::HigherOrder, &ctx, "(λx:(λT:*. T) A. x) (λy:A. y)"),
+            Err("`λ x: (λ T: *. T) A. x` expects an argument of type `A`, but `λ y: A. y` has type `A → A`, which does not convert to it".to_string())
+        );
+        assert!(type_in(
+            LambdaCube::SecondOrder,
+            &ctx,
+            "(λx:(λT:*. T → T) A. x) (λy:A. y)"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn calculus_of_constructions() {
+        // Leibniz equality is reflexive
+        let eq = "λA:*. λx:A. λy:A. Π P: A → *. P x → P y";
+        let refl = "λA:*. λx:A. λP:A → *. λp:P x. p";
+        assert_eq!(
+            type_of(LambdaCube::Constructions, eq),
+            Ok("Π A: *. A → A → *".to_string())
+        );
+        assert_eq!(
+            type_of(LambdaCube::Constructions, refl),
+            Ok("Π A: *. Π x: A. Π P: A → *. P x → P x".to_string())
+        );
+        let ctx = [("A", "*"), ("a", "A")];
+        assert_eq!(
+            type_in(
+                LambdaCube::Constructions,
+                &ctx,
+                &format!("(λp:({eq}) A a a. p) (({refl}) A a)")
+            ),
+            Ok("Π P: A → *. P a → P a".to_string())
+        );
+        assert!(type_of(LambdaCube::DependentTypes, eq).is_err());
+    }
+
+    #[test]
+    fn shadowing() {
+        let ctx = [("A", "*")];
+        assert_eq!(
+            type_in(LambdaCube::Constructions, &ctx, "λx:A. λA:*. λy:A. x"),
+            Ok("A → Π A1: *. A1 → A".to_string())
+        );
+    }
+
+    #[test]
+    fn derivations() {
+        let system = LambdaCube::SecondOrder.system();
+        let expr = PureTypeSystemParser::parse("λA:*. λx:A. x").unwrap();
+        let (derivation, ty) = PureTypeSystemChecker::derive(&system, &expr);
+        assert_eq!(derivation.ty, Some(ty.unwrap().to_string()));
+        assert_eq!(derivation.rule, "λ");
+        // the domain's sort, the body, and the product's sort
+        let rules: Vec<_> = derivation.premises.iter().map(|p| p.rule).collect();
+        assert_eq!(rules, ["Axiom", "λ", "Π"]);
+        assert_eq!(
+            derivation.premises[1].premises[1].context,
+            vec![
+                ("A".to_string(), "*".to_string()),
+                ("x".to_string(), "A".to_string())
+            ]
+        );
+
+        let system = LambdaCube::SimplyTyped.system();
+        let (derivation, ty) = PureTypeSystemChecker::derive(&system, &expr);
+        assert!(ty.is_err());
+        assert_eq!(derivation.premises[2].rule, "Π");
+        assert_eq!(derivation.premises[2].ty, None);
+    }
+}
@@ -0,0 +1,298 @@
+use crate::expressions::record_lambda_calculus::{record_type::RecordType, RecordLamExpr};
+use crate::expressions::untyped_lambda_calculus::unty_var::UntyVar;
+use crate::type_checkers::derivation::Derivation;
+use std::fmt::Display;
+
+/// The innermost subtyping judgement `sub <: sup` that could not be derived.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SubtypeFailure {
+    pub sub: RecordType,
+    pub sup: RecordType,
+    /// Set when `sup` is a record with a field `sub` lacks.
+    pub missing_field: Option<String>,
+}
+
+impl Display for SubtypeFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.missing_field {
+            Some(label) => write!(
+                f,
+                "`{} <: {}` does not hold, since `{}` has no field `{label}`",
+                self.sub, self.sup, self.sub
+            ),
+            None => write!(
+                f,
+                "`{} <: {}` does not hold, no subtyping rule applies",
+                self.sub, self.sup
+            ),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum RecordTypeError {
+    UnboundVar(UntyVar),
+    NotAFunction {
+        func: Box<RecordLamExpr>,
+        ty: RecordType,
+    },
+    ArgumentMismatch {
+        func: Box<RecordLamExpr>,
+        arg: Box<RecordLamExpr>,
+        expected: RecordType,
+        found: RecordType,
+        failure: Box<SubtypeFailure>,
+    },
+    NoSuchField {
+        expr: Box<RecordLamExpr>,
+        ty: RecordType,
+        label: String,
+    },
+}
+
+impl Display for RecordTypeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecordTypeError::UnboundVar(v) => write!(f, "variable `{v}` is not bound"),
+            RecordTypeError::NotAFunction { func, ty } => write!(
+                f,
+                "`{func}` is applied to an argument, but it has type `{ty}`, which is not a function type"
+            ),
+            RecordTypeError::ArgumentMismatch {
+                func,
+                arg,
+                expected,
+                found,
+                failure,
+            } => write!(
+                f,
+                "`{func}` expects an argument of type `{expected}`, but `{arg}` has type `{found}`: {failure}"
+            ),
+            RecordTypeError::NoSuchField { expr, ty, label } => write!(
+                f,
+                "`{expr}` has type `{ty}`, which has no field `{label}`"
+            ),
+        }
+    }
+}
+
+pub type RecordTypeContext = Vec<(UntyVar, RecordType)>;
+
+pub struct RecordLambdaCalculusChecker;
+impl RecordLambdaCalculusChecker {
+    /// Algorithmic subtyping: `Top` is above everything, arrows are contravariant in their
+    /// argument, and a record is below any record with a subset of its fields at supertypes.
+    pub fn is_subtype(sub: &RecordType, sup: &RecordType) -> Result<(), SubtypeFailure> {
+        let fail = |missing_field| SubtypeFailure {
+            sub: sub.clone(),
+            sup: sup.clone(),
+            missing_field,
+        };
+        match (sub, sup) {
+            (_, RecordType::Top) => Ok(()),
+            (RecordType::Base(a), RecordType::Base(b)) if a == b => Ok(()),
+            (RecordType::Arrow(s1, s2), RecordType::Arrow(t1, t2)) => {
+                Self::is_subtype(t1, s1)?;
+                Self::is_subtype(s2, t2)
+            }
+            (RecordType::Record(_), RecordType::Record(fields)) => {
+                for (label, t) in fields {
+                    match sub.field(label) {
+                        Some(s) => Self::is_subtype(s, t)?,
+                        None => return Err(fail(Some(label.clone()))),
+                    }
+                }
+                Ok(())
+            }
+            _ => Err(fail(None)),
+        }
+    }
+
+    /// The minimal type of `expr`.
+    pub fn type_of(expr: &RecordLamExpr) -> Result<RecordType, RecordTypeError> {
+        Self::type_of_in(&mut vec![], expr)
+    }
+
+    /// Later entries of `ctx` shadow earlier ones.
+    pub fn type_of_in(
+        ctx: &mut RecordTypeContext,
+        expr: &RecordLamExpr,
+    ) -> Result<RecordType, RecordTypeError> {
+        Self::derive_in(ctx, expr).1
+    }
+
+    /// The derivation of the minimal type of `expr`, which stops at the first failing rule.
+    /// Subsumption is part of `→E`, where the argument's type only has to be a subtype.
+    pub fn derive(expr: &RecordLamExpr) -> (Derivation, Result<RecordType, RecordTypeError>) {
+        Self::derive_in(&mut vec![], expr)
+    }
+
+    pub fn derive_in(
+        ctx: &mut RecordTypeContext,
+        expr: &RecordLamExpr,
+    ) -> (Derivation, Result<RecordType, RecordTypeError>) {
+        let mut premises = vec![];
+        let ty = Self::apply_rule(ctx, expr, &mut premises);
+        let rule = match expr {
+            RecordLamExpr::Var(_) => "Var",
+            RecordLamExpr::Abs(_) => "→I",
+            RecordLamExpr::App(_) => "→E",
+            RecordLamExpr::Record(_) => "Rcd",
+            RecordLamExpr::Field(_) => "Proj",
+        };
+        let derivation = Derivation::new(rule, ctx, expr, ty.as_ref().ok(), premises);
+        (derivation, ty)
+    }
+
+    fn apply_rule(
+        ctx: &mut RecordTypeContext,
+        expr: &RecordLamExpr,
+        premises: &mut Vec<Derivation>,
+    ) -> Result<RecordType, RecordTypeError> {
+        let mut premise = |ctx: &mut RecordTypeContext, expr: &RecordLamExpr| {
+            let (derivation, ty) = Self::derive_in(ctx, expr);
+            premises.push(derivation);
+            ty
+        };
+        match expr {
+            RecordLamExpr::Var(v) => ctx
+                .iter()
+                .rev()
+                .find(|(var, _)| var == v)
+                .map(|(_, ty)| ty.clone())
+                .ok_or_else(|| RecordTypeError::UnboundVar(v.clone())),
+            RecordLamExpr::Abs(abs) => {
+                ctx.push((abs.var.clone(), abs.ty.clone()));
+                let body = premise(ctx, &abs.expr);
+                ctx.pop();
+                Ok(RecordType::arrow(abs.ty.clone(), body?))
+            }
+            RecordLamExpr::App(app) => {
+                let func = premise(ctx, &app.lhs)?;
+                let arg = premise(ctx, &app.rhs)?;
+                match func {
+                    RecordType::Arrow(from, to) => match Self::is_subtype(&arg, &from) {
+                        Ok(()) => Ok(*to),
+                        Err(failure) => Err(RecordTypeError::ArgumentMismatch {
+                            func: app.lhs.clone(),
+                            arg: app.rhs.clone(),
+                            expected: *from,
+                            found: arg,
+                            failure: Box::new(failure),
+                        }),
+                    },
+                    ty => Err(RecordTypeError::NotAFunction {
+                        func: app.lhs.clone(),
+                        ty,
+                    }),
+                }
+            }
+            RecordLamExpr::Record(record) => Ok(RecordType::Record(
+                record
+                    .fields
+                    .iter()
+                    .map(|(label, e)| Ok((label.clone(), premise(ctx, e)?)))
+                    .collect::<Result<_, _>>()?,
+            )),
+            RecordLamExpr::Field(field) => {
+                let ty = premise(ctx, &field.expr)?;
+                match ty.field(&field.label) {
+                    Some(t) => Ok(t.clone()),
+                    None => Err(RecordTypeError::NoSuchField {
+                        expr: field.expr.clone(),
+                        ty,
+                        label: field.label.clone(),
+                    }),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RecordLambdaCalculusChecker;
+    use crate::parsers::record_lambda_calculus::RecordLambdaCalculusParser;
+
+    fn type_of(input: &str) -> Result<String, String> {
+        let expr = RecordLambdaCalculusParser::parse(input).unwrap();
+        RecordLambdaCalculusChecker::type_of(&expr)
+            .map(|ty| ty.to_string())
+            .map_err(|err| err.to_string())
+    }
+
+    fn is_subtype(sub: &str, sup: &str) -> Result<(), String> {
+        let sub = RecordLambdaCalculusParser::parse_type(sub).unwrap();
+        let sup = RecordLambdaCalculusParser::parse_type(sup).unwrap();
+        RecordLambdaCalculusChecker::is_subtype(&sub, &sup).map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn subtyping() {
+        // width, permutation and depth
+        assert_eq!(is_subtype("{x: A, y: B}", "{x: A}"), Ok(()));
+        assert_eq!(is_subtype("{y: B, x: A}", "{x: A, y: B}"), Ok(()));
+        assert_eq!(is_subtype("{r: {x: A, y: B}}", "{r: {y: B}}"), Ok(()));
+        assert_eq!(is_subtype("A → {x: A}", "A → Top"), Ok(()));
+        // arrows are contravariant in their argument
+        assert_eq!(is_subtype("{x: A} → A", "{x: A, y: B} → A"), Ok(()));
+        assert_eq!(
+            is_subtype("{x: A, y: B} → A", "{x: A} → A"),
+            Err(
+                "`{x: A} <: {x: A, y: B}` does not hold, since `{x: A}` has no field `y`"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            is_subtype("{r: {x: A}}", "{r: {x: B}}"),
+            Err("`A <: B` does not hold, no subtyping rule applies".to_string())
+        );
+        assert!(is_subtype("Top", "{}").is_err());
+    }
+
+    #[test]
+    fn checking() {
+        assert_eq!(
+            type_of("(λr: {x: A}. r.x) {x = a, y = b}"),
+            Err("variable `a` is not bound".to_string())
+        );
+        assert_eq!(
+            type_of("λa: A. λb: B. (λr: {x: A}. r.x) {y = b, x = a}"),
+            Ok("A → B → A".to_string())
+        );
+        assert_eq!(
+            type_of("λf: {x: A} → B. λg: ({x: A, y: B} → B) → A. g f"),
+            Ok("({x: A} → B) → (({x: A, y: B} → B) → A) → A".to_string())
+        );
+        assert_eq!(
+            type_of("λa: A. (λr: {x: A, y: A}. r) {x = a}"),
+            Err("`λ r: {x: A, y: A}. r` expects an argument of type `{x: A, y: A}`, but `{x = a}` has type `{x: A}`: \
+                 `{x: A} <: {x: A, y: A}` does not hold, since `{x: A}` has no field `y`"
+                .to_string())
+        );
+        assert_eq!(
+            type_of("λr: {x: A}. r.y"),
+            Err("`r` has type `{x: A}`, which has no field `y`".to_string())
+        );
+    }
+
+    #[test]
+    fn derivations() {
+        let expr =
+            RecordLambdaCalculusParser::parse("λa: A. (λr: {x: A}. r.x) {x = a, y = a}").unwrap();
+        let (derivation, ty) = RecordLambdaCalculusChecker::derive(&expr);
+        assert_eq!(derivation.ty, Some(ty.unwrap().to_string()));
+        let app = &derivation.premises[0];
+        assert_eq!(app.rule, "→E");
+        assert_eq!(app.premises[0].premises[0].rule, "Proj");
+        assert_eq!(app.premises[1].rule, "Rcd");
+        assert_eq!(app.premises[1].premises.len(), 2);
+
+        let expr = RecordLambdaCalculusParser::parse("λr: {x: A}. r.y").unwrap();
+        let (derivation, ty) = RecordLambdaCalculusChecker::derive(&expr);
+        assert!(ty.is_err());
+        let proj = &derivation.premises[0];
+        assert_eq!(proj.ty, None);
+        assert!(proj.premises[0].is_complete());
+    }
+}
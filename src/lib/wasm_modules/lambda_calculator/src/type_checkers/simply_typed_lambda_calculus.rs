@@ -0,0 +1,214 @@
+use crate::expressions::simply_typed_lambda_calculus::{simple_type::SimpleType, SimplyTypedExpr};
+use crate::expressions::untyped_lambda_calculus::unty_var::UntyVar;
+use crate::type_checkers::derivation::Derivation;
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SimpleTypeError {
+    UnboundVar(UntyVar),
+    NotAFunction {
+        func: Box<SimplyTypedExpr>,
+        ty: SimpleType,
+    },
+    ArgumentMismatch {
+        func: Box<SimplyTypedExpr>,
+        arg: Box<SimplyTypedExpr>,
+        expected: SimpleType,
+        found: SimpleType,
+    },
+}
+
+impl Display for SimpleTypeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SimpleTypeError::UnboundVar(v) => write!(f, "variable `{v}` is not bound"),
+            SimpleTypeError::NotAFunction { func, ty } => write!(
+                f,
+                "`{func}` is applied to an argument, but it has type `{ty}`, which is not a function type"
+            ),
+            SimpleTypeError::ArgumentMismatch {
+                func,
+                arg,
+                expected,
+                found,
+            } => write!(
+                f,
+                "`{func}` expects an argument of type `{expected}`, but `{arg}` has type `{found}`"
+            ),
+        }
+    }
+}
+
+pub type SimpleTypeContext = Vec<(UntyVar, SimpleType)>;
+
+pub struct SimplyTypedLambdaCalculusChecker;
+impl SimplyTypedLambdaCalculusChecker {
+    pub fn type_of(expr: &SimplyTypedExpr) -> Result<SimpleType, SimpleTypeError> {
+        Self::type_of_in(&mut vec![], expr)
+    }
+
+    /// Later entries of `ctx` shadow earlier ones.
+    pub fn type_of_in(
+        ctx: &mut SimpleTypeContext,
+        expr: &SimplyTypedExpr,
+    ) -> Result<SimpleType, SimpleTypeError> {
+        Self::derive_in(ctx, expr).1
+    }
+
+    /// The derivation of the type of `expr`, which stops at the first failing rule.
+    pub fn derive(expr: &SimplyTypedExpr) -> (Derivation, Result<SimpleType, SimpleTypeError>) {
+        Self::derive_in(&mut vec![], expr)
+    }
+
+    pub fn derive_in(
+        ctx: &mut SimpleTypeContext,
+        expr: &SimplyTypedExpr,
+    ) -> (Derivation, Result<SimpleType, SimpleTypeError>) {
+        let mut premises = vec![];
+        let ty = Self::apply_rule(ctx, expr, &mut premises);
+        let rule = match expr {
+            SimplyTypedExpr::Var(_) => "Var",
+            SimplyTypedExpr::Abs(_) => "→I",
+            SimplyTypedExpr::App(_) => "→E",
+        };
+        let derivation = Derivation::new(rule, ctx, expr, ty.as_ref().ok(), premises);
+        (derivation, ty)
+    }
+
+    fn apply_rule(
+        ctx: &mut SimpleTypeContext,
+        expr: &SimplyTypedExpr,
+        premises: &mut Vec<Derivation>,
+    ) -> Result<SimpleType, SimpleTypeError> {
+        let mut premise = |ctx: &mut SimpleTypeContext, expr: &SimplyTypedExpr| {
+            let (derivation, ty) = Self::derive_in(ctx, expr);
+            premises.push(derivation);
+            ty
+        };
+        match expr {
+            SimplyTypedExpr::Var(v) => ctx
+                .iter()
+                .rev()
+                .find(|(var, _)| var == v)
+                .map(|(_, ty)| ty.clone())
+                .ok_or_else(|| SimpleTypeError::UnboundVar(v.clone())),
+            SimplyTypedExpr::Abs(abs) => {
+                ctx.push((abs.var.clone(), abs.ty.clone()));
+                let body = premise(ctx, &abs.expr);
+                ctx.pop();
+                Ok(SimpleType::arrow(abs.ty.clone(), body?))
+            }
+            SimplyTypedExpr::App(app) => {
+                let func = premise(ctx, &app.lhs)?;
+                let arg = premise(ctx, &app.rhs)?;
+                match func {
+                    SimpleType::Arrow(from, to) if *from == arg => Ok(*to),
+                    SimpleType::Arrow(from, _) => Err(SimpleTypeError::ArgumentMismatch {
+                        func: app.lhs.clone(),
+                        arg: app.rhs.clone(),
+                        expected: *from,
+                        found: arg,
+                    }),
+                    ty => Err(SimpleTypeError::NotAFunction {
+                        func: app.lhs.clone(),
+                        ty,
+                    }),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SimpleTypeError, SimplyTypedLambdaCalculusChecker};
+    use crate::expressions::{Expression, ReductionStrategy};
+    use crate::parsers::simply_typed_lambda_calculus::SimplyTypedLambdaCalculusParser;
+
+    fn type_of(input: &str) -> Result<String, String> {
+        let expr = SimplyTypedLambdaCalculusParser::parse(input).unwrap();
+        SimplyTypedLambdaCalculusChecker::type_of(&expr)
+            .map(|ty| ty.to_string())
+            .map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn well_typed() {
+        assert_eq!(type_of("λx:A. x"), Ok("A → A".to_string()));
+        assert_eq!(type_of("λx:A y:B. x"), Ok("A → B → A".to_string()));
+        assert_eq!(
+            type_of("λf:A → B. λg:B → C. λx:A. g (f x)"),
+            Ok("(A → B) → (B → C) → A → C".to_string())
+        );
+        assert_eq!(type_of("(λx:A → A. x) (λy:A. y)"), Ok("A → A".to_string()));
+        assert_eq!(type_of("λx:A. λx:B. x"), Ok("A → B → B".to_string()));
+    }
+
+    #[test]
+    fn ill_typed() {
+        assert_eq!(
+            type_of("λx:A. y"),
+            Err("variable `y` is not bound".to_string())
+        );
+        assert_eq!(
+            type_of("λx:A. x x"),
+            Err(
+                "`x` is applied to an argument, but it has type `A`, which is not a function type"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            type_of("λf:A → B. λy:B. f y"),
+            Err("`f` expects an argument of type `A`, but `y` has type `B`".to_string())
+        );
+        let expr = SimplyTypedLambdaCalculusParser::parse("λx:A. z").unwrap();
+        assert!(matches!(
+            SimplyTypedLambdaCalculusChecker::type_of(&expr),
+            Err(SimpleTypeError::UnboundVar(_))
+        ));
+    }
+
+    #[test]
+    fn derivations() {
+        let expr = SimplyTypedLambdaCalculusParser::parse("λf:A → B. λx:A. f x").unwrap();
+        let (derivation, ty) = SimplyTypedLambdaCalculusChecker::derive(&expr);
+        assert_eq!(derivation.ty, Some(ty.unwrap().to_string()));
+        let app = &derivation.premises[0].premises[0];
+        assert_eq!(app.rule, "→E");
+        assert_eq!(app.context.len(), 2);
+        assert_eq!(app.premises.len(), 2);
+        assert!(app.premises.iter().all(|p| p.rule == "Var"));
+
+        // the partial tree stops at the failing application, after both of its premises
+        let expr = SimplyTypedLambdaCalculusParser::parse("λf:A → B. λy:B. f y").unwrap();
+        let (derivation, ty) = SimplyTypedLambdaCalculusChecker::derive(&expr);
+        assert!(ty.is_err());
+        assert!(!derivation.is_complete());
+        let app = &derivation.premises[0].premises[0];
+        assert_eq!(app.ty, None);
+        assert!(app.premises.iter().all(|p| p.is_complete()));
+
+        let expr = SimplyTypedLambdaCalculusParser::parse("λx:A. y x").unwrap();
+        let (derivation, _) = SimplyTypedLambdaCalculusChecker::derive(&expr);
+        assert_eq!(derivation.premises[0].premises.len(), 1);
+    }
+
+    #[test]
+    fn steps_preserve_types() {
+        let mut expr =
+            SimplyTypedLambdaCalculusParser::parse("(λf:A → A. λx:A. f (f x)) (λy:A. (λz:A. z) y)")
+                .unwrap();
+        let ty = SimplyTypedLambdaCalculusChecker::type_of(&expr).unwrap();
+        let mut steps = 0;
+        while let Ok(next) = expr.calc_step(ReductionStrategy::NormalOrder) {
+            assert_eq!(
+                SimplyTypedLambdaCalculusChecker::type_of(&next),
+                Ok(ty.clone())
+            );
+            expr = next;
+            steps += 1;
+        }
+        assert_eq!(steps, 5);
+        assert_eq!(expr.to_string(), "λ x: A. x");
+    }
+}
@@ -0,0 +1,302 @@
+use crate::expressions::system_f::{
+    poly_type::{PolyType, TyVar},
+    SystemFExpr,
+};
+use crate::expressions::untyped_lambda_calculus::unty_var::UntyVar;
+use crate::expressions::{FreeVars, Substitution};
+use crate::type_checkers::derivation::Derivation;
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SystemFTypeError {
+    UnboundVar(UntyVar),
+    UnboundTypeVar(TyVar),
+    NotAFunction {
+        func: Box<SystemFExpr>,
+        ty: PolyType,
+    },
+    ArgumentMismatch {
+        func: Box<SystemFExpr>,
+        arg: Box<SystemFExpr>,
+        expected: PolyType,
+        found: PolyType,
+    },
+    NotPolymorphic {
+        expr: Box<SystemFExpr>,
+        ty: PolyType,
+    },
+}
+
+impl Display for SystemFTypeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SystemFTypeError::UnboundVar(v) => write!(f, "variable `{v}` is not bound"),
+            SystemFTypeError::UnboundTypeVar(v) => {
+                write!(f, "type variable `{v}` is not bound")
+            }
+            SystemFTypeError::NotAFunction { func, ty } => write!(
+                f,
+                "`{func}` is applied to an argument, but it has type `{ty}`, which is not a function type"
+            ),
+            SystemFTypeError::ArgumentMismatch {
+                func,
+                arg,
+                expected,
+                found,
+            } => write!(
+                f,
+                "`{func}` expects an argument of type `{expected}`, but `{arg}` has type `{found}`"
+            ),
+            SystemFTypeError::NotPolymorphic { expr, ty } => write!(
+                f,
+                "`{expr}` is applied to a type, but it has type `{ty}`, which is not a `∀` type"
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct SystemFContext {
+    vars: Vec<(UntyVar, PolyType)>,
+    ty_vars: Vec<TyVar>,
+}
+
+impl SystemFContext {
+    fn check_type(&self, ty: &PolyType) -> Result<(), SystemFTypeError> {
+        match ty
+            .free_vars()
+            .into_iter()
+            .find(|v| !self.ty_vars.contains(v))
+        {
+            Some(v) => Err(SystemFTypeError::UnboundTypeVar(v)),
+            None => Ok(()),
+        }
+    }
+}
+
+pub struct SystemFChecker;
+impl SystemFChecker {
+    pub fn type_of(expr: &SystemFExpr) -> Result<PolyType, SystemFTypeError> {
+        Self::type_of_in(&mut SystemFContext::default(), expr)
+    }
+
+    /// Type variables used in annotations must be bound by a `Λ` or be part of `ctx`.
+    pub fn type_of_in(
+        ctx: &mut SystemFContext,
+        expr: &SystemFExpr,
+    ) -> Result<PolyType, SystemFTypeError> {
+        Self::derive_in(ctx, expr).1
+    }
+
+    /// The derivation of the type of `expr`, which stops at the first failing rule. Its
+    /// contexts only list term variables.
+    pub fn derive(expr: &SystemFExpr) -> (Derivation, Result<PolyType, SystemFTypeError>) {
+        Self::derive_in(&mut SystemFContext::default(), expr)
+    }
+
+    pub fn derive_in(
+        ctx: &mut SystemFContext,
+        expr: &SystemFExpr,
+    ) -> (Derivation, Result<PolyType, SystemFTypeError>) {
+        let mut premises = vec![];
+        let ty = Self::apply_rule(ctx, expr, &mut premises);
+        let rule = match expr {
+            SystemFExpr::Var(_) => "Var",
+            SystemFExpr::Abs(_) => "→I",
+            SystemFExpr::App(_) => "→E",
+            SystemFExpr::TyAbs(_) => "∀I",
+            SystemFExpr::TyApp(_) => "∀E",
+        };
+        let derivation = Derivation::new(rule, &ctx.vars, expr, ty.as_ref().ok(), premises);
+        (derivation, ty)
+    }
+
+    fn apply_rule(
+        ctx: &mut SystemFContext,
+        expr: &SystemFExpr,
+        premises: &mut Vec<Derivation>,
+    ) -> Result<PolyType, SystemFTypeError> {
+        let mut premise = |ctx: &mut SystemFContext, expr: &SystemFExpr| {
+            let (derivation, ty) = Self::derive_in(ctx, expr);
+            premises.push(derivation);
+            ty
+        };
+        match expr {
+            SystemFExpr::Var(v) => ctx
+                .vars
+                .iter()
+                .rev()
+                .find(|(var, _)| var == v)
+                .map(|(_, ty)| ty.clone())
+                .ok_or_else(|| SystemFTypeError::UnboundVar(v.clone())),
+            SystemFExpr::Abs(abs) => {
+                ctx.check_type(&abs.ty)?;
+                ctx.vars.push((abs.var.clone(), abs.ty.clone()));
+                let body = premise(ctx, &abs.expr);
+                ctx.vars.pop();
+                Ok(PolyType::arrow(abs.ty.clone(), body?))
+            }
+            SystemFExpr::App(app) => {
+                let func = premise(ctx, &app.lhs)?;
+                let arg = premise(ctx, &app.rhs)?;
+                match func {
+                    PolyType::Arrow(from, to) if from.alpha_eq(&arg) => Ok(*to),
+                    PolyType::Arrow(from, _) => Err(SystemFTypeError::ArgumentMismatch {
+                        func: app.lhs.clone(),
+                        arg: app.rhs.clone(),
+                        expected: *from,
+                        found: arg,
+                    }),
+                    ty => Err(SystemFTypeError::NotAFunction {
+                        func: app.lhs.clone(),
+                        ty,
+                    }),
+                }
+            }
+            SystemFExpr::TyAbs(abs) => {
+                // a `Λα` shadowing an `α` that the types in `ctx` still refer to is renamed first,
+                // otherwise the result type would confuse the two
+                let abs = if ctx.ty_vars.contains(&abs.var) {
+                    abs.rename(&ctx.ty_vars.iter().cloned().collect::<BTreeSet<_>>())
+                } else {
+                    abs.clone()
+                };
+                ctx.ty_vars.push(abs.var.clone());
+                let body = premise(ctx, &abs.expr);
+                ctx.ty_vars.pop();
+                Ok(PolyType::forall(abs.var, body?))
+            }
+            SystemFExpr::TyApp(app) => {
+                ctx.check_type(&app.ty)?;
+                match premise(ctx, &app.expr)? {
+                    PolyType::Forall(v, body) => Ok(body.substitute(&v, &app.ty)),
+                    ty => Err(SystemFTypeError::NotPolymorphic {
+                        expr: app.expr.clone(),
+                        ty,
+                    }),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SystemFChecker, SystemFTypeError};
+    use crate::expressions::{Expression, ReductionStrategy};
+    use crate::parsers::system_f::SystemFParser;
+
+    fn type_of(input: &str) -> Result<String, String> {
+        let expr = SystemFParser::parse(input).unwrap();
+        SystemFChecker::type_of(&expr)
+            .map(|ty| ty.to_string())
+            .map_err(|err| err.to_string())
+    }
+
+    const NAT: &str = "∀α. (α → α) → α → α";
+
+    #[test]
+    fn church_encodings() {
+        assert_eq!(type_of("Λα. λx:α. x"), Ok("∀α. α → α".to_string()));
+        assert_eq!(
+            type_of("Λα. λt:α. λf:α. t"),
+            Ok("∀α. α → α → α".to_string())
+        );
+        assert_eq!(type_of("Λα. λf:α → α. λx:α. f (f x)"), Ok(NAT.to_string()));
+        assert_eq!(
+            type_of(&format!("λn:{NAT}. Λα. λf:α → α. λx:α. f (n [α] f x)")),
+            Ok(format!("({NAT}) → {NAT}"))
+        );
+        assert_eq!(
+            type_of(&format!(
+                "λm:{NAT}. λn:{NAT}. m [{NAT}] (λk:{NAT}. Λβ. λf:β → β. λx:β. f (k [β] f x)) n"
+            )),
+            Ok(format!("({NAT}) → ({NAT}) → {NAT}"))
+        );
+        // self application needs a polymorphic argument
+        assert_eq!(
+            type_of("λx:∀α. α → α. x [∀α. α → α] x"),
+            Ok("(∀α. α → α) → ∀α. α → α".to_string())
+        );
+        // bound type variables can be renamed freely
+        assert_eq!(
+            type_of("(λf:∀β. β → β. f) (Λα. λx:α. x)"),
+            Ok("∀β. β → β".to_string())
+        );
+    }
+
+    #[test]
+    fn shadowing() {
+        assert_eq!(type_of("Λα. λx:α. Λα. x"), Ok("∀α. α → ∀α1. α".to_string()));
+    }
+
+    #[test]
+    fn ill_typed() {
+        assert_eq!(
+            type_of("λx:α. x"),
+            Err("type variable `α` is not bound".to_string())
+        );
+        assert_eq!(
+            type_of("Λα. λx:α. x [α]"),
+            Err(
+                "`x` is applied to a type, but it has type `α`, which is not a `∀` type"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            type_of("Λα. λf:α → α. f [α]"),
+            Err(
+                "`f` is applied to a type, but it has type `α → α`, which is not a `∀` type"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            type_of("Λα. Λβ. λf:α → α. λy:β. f y"),
+            Err("`f` expects an argument of type `α`, but `y` has type `β`".to_string())
+        );
+        let expr = SystemFParser::parse("Λα. y").unwrap();
+        assert!(matches!(
+            SystemFChecker::type_of(&expr),
+            Err(SystemFTypeError::UnboundVar(_))
+        ));
+    }
+
+    #[test]
+    fn derivations() {
+        let expr = SystemFParser::parse("Λβ. (Λα. λx:α. x) [β → β]").unwrap();
+        let (derivation, ty) = SystemFChecker::derive(&expr);
+        assert_eq!(derivation.ty, Some(ty.unwrap().to_string()));
+        assert_eq!(derivation.rule, "∀I");
+        assert_eq!(derivation.premises[0].rule, "∀E");
+        let abs = &derivation.premises[0].premises[0].premises[0];
+        assert_eq!(abs.rule, "→I");
+        assert_eq!(
+            abs.premises[0].context,
+            vec![("x".to_string(), "α".to_string())]
+        );
+
+        let expr = SystemFParser::parse("Λα. λx:α. x [α]").unwrap();
+        let (derivation, ty) = SystemFChecker::derive(&expr);
+        assert!(ty.is_err());
+        let ty_app = &derivation.premises[0].premises[0];
+        assert_eq!(ty_app.rule, "∀E");
+        assert_eq!(ty_app.ty, None);
+        assert!(ty_app.premises[0].is_complete());
+    }
+
+    #[test]
+    fn steps_preserve_types() {
+        let two = "Λα. λf:α → α. λx:α. f (f x)";
+        let mut expr = SystemFParser::parse(&format!(
+            "(λn:{NAT}. Λα. λf:α → α. λx:α. f (n [α] f x)) ({two})"
+        ))
+        .unwrap();
+        let ty = SystemFChecker::type_of(&expr).unwrap();
+        while let Ok(next) = expr.calc_step(ReductionStrategy::NormalOrder) {
+            assert!(SystemFChecker::type_of(&next).unwrap().alpha_eq(&ty));
+            expr = next;
+        }
+        assert_eq!(expr.to_string(), "Λ α. λ f: α → α. λ x: α. f (f (f x))");
+    }
+}
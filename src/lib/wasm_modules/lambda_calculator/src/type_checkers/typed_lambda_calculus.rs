@@ -0,0 +1,403 @@
+use crate::expressions::typed_lambda_calculus::{ty::Type, Side, TypedLamExpr};
+use crate::expressions::untyped_lambda_calculus::unty_var::UntyVar;
+use crate::type_checkers::derivation::Derivation;
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum TypedLamTypeError {
+    UnboundVar(UntyVar),
+    NotAFunction {
+        func: Box<TypedLamExpr>,
+        ty: Type,
+    },
+    ArgumentMismatch {
+        func: Box<TypedLamExpr>,
+        arg: Box<TypedLamExpr>,
+        expected: Type,
+        found: Type,
+    },
+    NotAProduct {
+        expr: Box<TypedLamExpr>,
+        ty: Type,
+    },
+    NotASum {
+        expr: Box<TypedLamExpr>,
+        ty: Type,
+    },
+    NotEmpty {
+        expr: Box<TypedLamExpr>,
+        ty: Type,
+    },
+    NotRecursive {
+        expr: Box<TypedLamExpr>,
+        ty: Type,
+    },
+    Mismatch {
+        expr: Box<TypedLamExpr>,
+        expected: Type,
+        found: Type,
+    },
+}
+
+impl Display for TypedLamTypeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TypedLamTypeError::UnboundVar(v) => write!(f, "variable `{v}` is not bound"),
+            TypedLamTypeError::NotAFunction { func, ty } => write!(
+                f,
+                "`{func}` is applied to an argument, but it has type `{ty}`, which is not a function type"
+            ),
+            TypedLamTypeError::ArgumentMismatch {
+                func,
+                arg,
+                expected,
+                found,
+            } => write!(
+                f,
+                "`{func}` expects an argument of type `{expected}`, but `{arg}` has type `{found}`"
+            ),
+            TypedLamTypeError::NotAProduct { expr, ty } => write!(
+                f,
+                "`{expr}` is projected, but it has type `{ty}`, which is not a product type"
+            ),
+            TypedLamTypeError::NotASum { expr, ty } => write!(
+                f,
+                "`{expr}` is used as a sum, but it has type `{ty}`, which is not a sum type"
+            ),
+            TypedLamTypeError::NotEmpty { expr, ty } => write!(
+                f,
+                "`{expr}` is eliminated with `absurd`, but it has type `{ty}` instead of `Empty`"
+            ),
+            TypedLamTypeError::NotRecursive { expr, ty } => write!(
+                f,
+                "`{expr}` is used as a recursive type, but it has type `{ty}`, which is not of the form `μα. T`"
+            ),
+            TypedLamTypeError::Mismatch {
+                expr,
+                expected,
+                found,
+            } => write!(
+                f,
+                "`{expr}` has type `{found}`, but `{expected}` is expected"
+            ),
+        }
+    }
+}
+
+pub type TypedLamContext = Vec<(UntyVar, Type)>;
+
+pub struct TypedLambdaCalculusChecker;
+impl TypedLambdaCalculusChecker {
+    pub fn type_of(expr: &TypedLamExpr) -> Result<Type, TypedLamTypeError> {
+        Self::type_of_in(&mut vec![], expr)
+    }
+
+    /// Later entries of `ctx` shadow earlier ones.
+    pub fn type_of_in(
+        ctx: &mut TypedLamContext,
+        expr: &TypedLamExpr,
+    ) -> Result<Type, TypedLamTypeError> {
+        Self::derive_in(ctx, expr).1
+    }
+
+    /// The derivation of the type of `expr`, which stops at the first failing rule.
+    pub fn derive(expr: &TypedLamExpr) -> (Derivation, Result<Type, TypedLamTypeError>) {
+        Self::derive_in(&mut vec![], expr)
+    }
+
+    pub fn derive_in(
+        ctx: &mut TypedLamContext,
+        expr: &TypedLamExpr,
+    ) -> (Derivation, Result<Type, TypedLamTypeError>) {
+        let mut premises = vec![];
+        let ty = Self::apply_rule(ctx, expr, &mut premises);
+        let derivation = Derivation::new(Self::rule(expr), ctx, expr, ty.as_ref().ok(), premises);
+        (derivation, ty)
+    }
+
+    fn rule(expr: &TypedLamExpr) -> &'static str {
+        match expr {
+            TypedLamExpr::Var(_) => "Var",
+            TypedLamExpr::Abs(_) => "→I",
+            TypedLamExpr::App(_) => "→E",
+            TypedLamExpr::Unit(_) => "1I",
+            TypedLamExpr::Pair(_) => "×I",
+            TypedLamExpr::Proj(proj) if proj.side == Side::Left => "×E₁",
+            TypedLamExpr::Proj(_) => "×E₂",
+            TypedLamExpr::Inj(inj) if inj.side == Side::Left => "+I₁",
+            TypedLamExpr::Inj(_) => "+I₂",
+            TypedLamExpr::Case(_) => "+E",
+            TypedLamExpr::Absurd(_) => "0E",
+            TypedLamExpr::Fold(_) => "μI",
+            TypedLamExpr::Unfold(_) => "μE",
+        }
+    }
+
+    fn apply_rule(
+        ctx: &mut TypedLamContext,
+        expr: &TypedLamExpr,
+        premises: &mut Vec<Derivation>,
+    ) -> Result<Type, TypedLamTypeError> {
+        let mut premise = |ctx: &mut TypedLamContext, expr: &TypedLamExpr| {
+            let (derivation, ty) = Self::derive_in(ctx, expr);
+            premises.push(derivation);
+            ty
+        };
+        match expr {
+            TypedLamExpr::Var(v) => ctx
+                .iter()
+                .rev()
+                .find(|(var, _)| var == v)
+                .map(|(_, ty)| ty.clone())
+                .ok_or_else(|| TypedLamTypeError::UnboundVar(v.clone())),
+            TypedLamExpr::Abs(abs) => {
+                let body =
+                    Self::type_of_bound(ctx, &abs.var, abs.ty.clone(), &abs.expr, &mut premise)?;
+                Ok(Type::arrow(abs.ty.clone(), body))
+            }
+            TypedLamExpr::App(app) => {
+                let func = premise(ctx, &app.lhs)?;
+                let arg = premise(ctx, &app.rhs)?;
+                match func {
+                    Type::Arrow(from, to) if from.alpha_eq(&arg) => Ok(*to),
+                    Type::Arrow(from, _) => Err(TypedLamTypeError::ArgumentMismatch {
+                        func: app.lhs.clone(),
+                        arg: app.rhs.clone(),
+                        expected: *from,
+                        found: arg,
+                    }),
+                    ty => Err(TypedLamTypeError::NotAFunction {
+                        func: app.lhs.clone(),
+                        ty,
+                    }),
+                }
+            }
+            TypedLamExpr::Unit(_) => Ok(Type::Unit),
+            TypedLamExpr::Pair(pair) => Ok(Type::product(
+                premise(ctx, &pair.fst)?,
+                premise(ctx, &pair.snd)?,
+            )),
+            TypedLamExpr::Proj(proj) => match premise(ctx, &proj.expr)? {
+                Type::Product(fst, _) if proj.side == Side::Left => Ok(*fst),
+                Type::Product(_, snd) => Ok(*snd),
+                ty => Err(TypedLamTypeError::NotAProduct {
+                    expr: proj.expr.clone(),
+                    ty,
+                }),
+            },
+            TypedLamExpr::Inj(inj) => {
+                let Type::Sum(left, right) = &inj.ty else {
+                    return Err(TypedLamTypeError::NotASum {
+                        expr: Box::new(expr.clone()),
+                        ty: inj.ty.clone(),
+                    });
+                };
+                let expected = match inj.side {
+                    Side::Left => left,
+                    Side::Right => right,
+                };
+                let found = premise(ctx, &inj.expr)?;
+                if !found.alpha_eq(expected) {
+                    return Err(TypedLamTypeError::Mismatch {
+                        expr: inj.expr.clone(),
+                        expected: (**expected).clone(),
+                        found,
+                    });
+                }
+                Ok(inj.ty.clone())
+            }
+            TypedLamExpr::Case(case) => {
+                let (left, right) = match premise(ctx, &case.expr)? {
+                    Type::Sum(left, right) => (*left, *right),
+                    ty => {
+                        return Err(TypedLamTypeError::NotASum {
+                            expr: case.expr.clone(),
+                            ty,
+                        })
+                    }
+                };
+                let expected =
+                    Self::type_of_bound(ctx, &case.left.var, left, &case.left.expr, &mut premise)?;
+                let found = Self::type_of_bound(
+                    ctx,
+                    &case.right.var,
+                    right,
+                    &case.right.expr,
+                    &mut premise,
+                )?;
+                if !found.alpha_eq(&expected) {
+                    return Err(TypedLamTypeError::Mismatch {
+                        expr: case.right.expr.clone(),
+                        expected,
+                        found,
+                    });
+                }
+                Ok(expected)
+            }
+            TypedLamExpr::Absurd(absurd) => match premise(ctx, &absurd.expr)? {
+                Type::Empty => Ok(absurd.ty.clone()),
+                ty => Err(TypedLamTypeError::NotEmpty {
+                    expr: absurd.expr.clone(),
+                    ty,
+                }),
+            },
+            TypedLamExpr::Fold(fold) => {
+                let Some(expected) = fold.ty.unfold() else {
+                    return Err(TypedLamTypeError::NotRecursive {
+                        expr: Box::new(expr.clone()),
+                        ty: fold.ty.clone(),
+                    });
+                };
+                let found = premise(ctx, &fold.expr)?;
+                if !found.alpha_eq(&expected) {
+                    return Err(TypedLamTypeError::Mismatch {
+                        expr: fold.expr.clone(),
+                        expected,
+                        found,
+                    });
+                }
+                Ok(fold.ty.clone())
+            }
+            TypedLamExpr::Unfold(unfold) => {
+                let ty = premise(ctx, &unfold.expr)?;
+                ty.unfold().ok_or(TypedLamTypeError::NotRecursive {
+                    expr: unfold.expr.clone(),
+                    ty,
+                })
+            }
+        }
+    }
+
+    fn type_of_bound(
+        ctx: &mut TypedLamContext,
+        var: &UntyVar,
+        ty: Type,
+        body: &TypedLamExpr,
+        premise: &mut impl FnMut(&mut TypedLamContext, &TypedLamExpr) -> Result<Type, TypedLamTypeError>,
+    ) -> Result<Type, TypedLamTypeError> {
+        ctx.push((var.clone(), ty));
+        let body = premise(ctx, body);
+        ctx.pop();
+        body
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TypedLambdaCalculusChecker;
+    use crate::expressions::typed_lambda_calculus::TypedLamExpr;
+    use crate::parsers::typed_lambda_calculus::TypedLambdaCalculusParser;
+    use crate::parsers::untyped_lambda_calculus::UntypedLambdaCalculusParser;
+
+    fn type_of(input: &str) -> Result<String, String> {
+        let expr = TypedLambdaCalculusParser::parse(input).unwrap();
+        TypedLambdaCalculusChecker::type_of(&expr)
+            .map(|ty| ty.to_string())
+            .map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn well_typed() {
+        assert_eq!(
+            type_of("λp: A × B. (snd p, fst p)"),
+            Ok("A × B → B × A".to_string())
+        );
+        assert_eq!(
+            type_of("λs: A + B. case s of inl a ⇒ inr a as B + A | inr b ⇒ inl b as B + A"),
+            Ok("A + B → B + A".to_string())
+        );
+        assert_eq!(type_of("(λu: Unit. u) ()"), Ok("Unit".to_string()));
+        assert_eq!(
+            type_of("λe: Empty. absurd e as A → B"),
+            Ok("Empty → A → B".to_string())
+        );
+    }
+
+    #[test]
+    fn ill_typed() {
+        assert_eq!(
+            type_of("λx: A. fst x"),
+            Err("`x` is projected, but it has type `A`, which is not a product type".to_string())
+        );
+        assert_eq!(
+            type_of("inl () as A + Unit"),
+            Err("`()` has type `Unit`, but `A` is expected".to_string())
+        );
+        assert_eq!(
+            type_of("λs: A + B. case s of inl a ⇒ a | inr b ⇒ b"),
+            Err("`b` has type `B`, but `A` is expected".to_string())
+        );
+        assert_eq!(
+            type_of("inr () as Unit"),
+            Err("`inr () as Unit` is used as a sum, but it has type `Unit`, which is not a sum type".to_string())
+        );
+        assert!(type_of("λx: A. absurd x as A").is_err());
+    }
+
+    #[test]
+    fn derivations() {
+        let expr = TypedLambdaCalculusParser::parse(
+            "λs: A + B. case s of inl a ⇒ inr a as B + A | inr b ⇒ inl b as B + A",
+        )
+        .unwrap();
+        let (derivation, ty) = TypedLambdaCalculusChecker::derive(&expr);
+        assert_eq!(derivation.ty, Some(ty.unwrap().to_string()));
+        let case = &derivation.premises[0];
+        assert_eq!(case.rule, "+E");
+        let rules: Vec<_> = case.premises.iter().map(|p| p.rule).collect();
+        assert_eq!(rules, ["Var", "+I₂", "+I₁"]);
+        assert_eq!(
+            case.premises[1].context.last(),
+            Some(&("a".to_string(), "A".to_string()))
+        );
+
+        // the failing `fst` has its premise, but no type
+        let expr = TypedLambdaCalculusParser::parse("λx: A. (x, fst x)").unwrap();
+        let (derivation, ty) = TypedLambdaCalculusChecker::derive(&expr);
+        assert!(ty.is_err());
+        let pair = &derivation.premises[0];
+        assert_eq!(pair.ty, None);
+        assert!(pair.premises[0].is_complete());
+        assert_eq!(pair.premises[1].rule, "×E₁");
+        assert_eq!(pair.premises[1].ty, None);
+        assert_eq!(pair.premises[1].premises[0].ty, Some("A".to_string()));
+    }
+
+    #[test]
+    fn recursive_types() {
+        let nat_list = "μl. Unit + A × l";
+        assert_eq!(
+            type_of(&format!("λx: A. fold (inr (x, fold (inl () as Unit + A × ({nat_list})) as {nat_list}) as Unit + A × ({nat_list})) as {nat_list}")),
+            Ok("A → μl. Unit + A × l".to_string())
+        );
+        assert_eq!(
+            type_of("λs: μβ. β → A. unfold s s"),
+            Ok("(μβ. β → A) → A".to_string())
+        );
+        // the annotation may rename the bound variable
+        assert_eq!(
+            type_of("λs: μβ. β → A. fold (unfold s) as μγ. γ → A"),
+            Ok("(μβ. β → A) → μγ. γ → A".to_string())
+        );
+        assert_eq!(
+            type_of("λx: A. unfold x"),
+            Err("`x` is used as a recursive type, but it has type `A`, which is not of the form `μα. T`".to_string())
+        );
+    }
+
+    #[test]
+    fn embedded_untyped_terms() {
+        for input in [
+            "(λx. x x) (λx. x x)",
+            "λf. (λx. f (x x)) (λx. f (x x))",
+            "λx y. x",
+        ] {
+            let expr = UntypedLambdaCalculusParser::parse(input).unwrap();
+            let ty = TypedLambdaCalculusChecker::type_of(&TypedLamExpr::embed(&expr)).unwrap();
+            assert!(
+                ty.alpha_eq(&TypedLamExpr::untyped_domain()),
+                "{input}: {ty}"
+            );
+        }
+    }
+}
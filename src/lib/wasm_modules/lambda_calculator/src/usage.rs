@@ -0,0 +1,391 @@
+use crate::expressions::bidirectional_lambda_calculus::BidirExpr;
+use crate::expressions::let_lambda_calculus::LetLamExpr;
+use crate::expressions::pcf::PcfExpr;
+use crate::expressions::record_lambda_calculus::RecordLamExpr;
+use crate::expressions::simply_typed_lambda_calculus::SimplyTypedExpr;
+use crate::expressions::system_f::SystemFExpr;
+use crate::expressions::typed_lambda_calculus::TypedLamExpr;
+use crate::expressions::untyped_lambda_calculus::{unty_var::UntyVar, UntyLamExpr};
+use std::fmt::Display;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+/// How often a bound variable may be used in its scope.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub enum Discipline {
+    /// Exactly once.
+    Linear = 0,
+    /// At most once, so arguments may be discarded but never copied.
+    Affine = 1,
+    /// At least once, so arguments may be copied but never discarded.
+    Relevant = 2,
+    #[default]
+    Unrestricted = 3,
+}
+
+impl Discipline {
+    pub fn allows(self, uses: usize) -> bool {
+        match self {
+            Discipline::Linear => uses == 1,
+            Discipline::Affine => uses <= 1,
+            Discipline::Relevant => uses >= 1,
+            Discipline::Unrestricted => true,
+        }
+    }
+}
+
+impl Display for Discipline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Discipline::Linear => write!(f, "linear"),
+            Discipline::Affine => write!(f, "affine"),
+            Discipline::Relevant => write!(f, "relevant"),
+            Discipline::Unrestricted => write!(f, "unrestricted"),
+        }
+    }
+}
+
+/// A term whose variable occurrences can be counted.
+pub trait Usage {
+    fn as_var(&self) -> Option<&UntyVar>;
+    /// The direct subterms, each with the variable it is the scope of, if any.
+    fn subterms(&self) -> Vec<(Option<&UntyVar>, &Self)>;
+    /// Whether only one of the subterms after the first one is ever evaluated, like the
+    /// branches of a `case`. Such a subterm counts with its most frequent use.
+    fn has_alternatives(&self) -> bool {
+        false
+    }
+}
+
+/// Number of free occurrences of `var` in `expr`.
+pub fn occurrences<E: Usage>(expr: &E, var: &UntyVar) -> usize {
+    if expr.as_var() == Some(var) {
+        return 1;
+    }
+    let counts = expr.subterms().into_iter().map(|(bound, e)| {
+        if bound == Some(var) {
+            0
+        } else {
+            occurrences(e, var)
+        }
+    });
+    if expr.has_alternatives() {
+        let mut counts = counts;
+        let first = counts.next().unwrap_or(0);
+        first + counts.max().unwrap_or(0)
+    } else {
+        counts.sum()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BinderUsage {
+    pub var: UntyVar,
+    /// Position of the binder among all binders of the term, from left to right.
+    pub index: usize,
+    pub uses: usize,
+}
+
+impl Display for BinderUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (binder {}) is ", self.var, self.index + 1)?;
+        match self.uses {
+            0 => write!(f, "never used"),
+            1 => write!(f, "used once"),
+            n => write!(f, "used {n} times"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct UsageReport {
+    pub binders: Vec<BinderUsage>,
+}
+
+impl UsageReport {
+    pub fn new<E: Usage>(expr: &E) -> Self {
+        let mut binders = Vec::new();
+        collect_binders(expr, &mut binders);
+        Self { binders }
+    }
+
+    /// The most restrictive discipline every binder obeys.
+    pub fn discipline(&self) -> Discipline {
+        let copies = self.binders.iter().any(|b| b.uses > 1);
+        let discards = self.binders.iter().any(|b| b.uses == 0);
+        match (copies, discards) {
+            (false, false) => Discipline::Linear,
+            (false, true) => Discipline::Affine,
+            (true, false) => Discipline::Relevant,
+            (true, true) => Discipline::Unrestricted,
+        }
+    }
+
+    pub fn violations(&self, discipline: Discipline) -> Vec<BinderUsage> {
+        self.binders
+            .iter()
+            .filter(|b| !discipline.allows(b.uses))
+            .cloned()
+            .collect()
+    }
+}
+
+fn collect_binders<E: Usage>(expr: &E, binders: &mut Vec<BinderUsage>) {
+    for (bound, e) in expr.subterms() {
+        if let Some(var) = bound {
+            binders.push(BinderUsage {
+                var: var.clone(),
+                index: binders.len(),
+                uses: occurrences(e, var),
+            });
+        }
+        collect_binders(e, binders);
+    }
+}
+
+impl Usage for UntyLamExpr {
+    fn as_var(&self) -> Option<&UntyVar> {
+        match self {
+            UntyLamExpr::Var(v) => Some(v),
+            _ => None,
+        }
+    }
+    fn subterms(&self) -> Vec<(Option<&UntyVar>, &Self)> {
+        match self {
+            UntyLamExpr::Var(_) => vec![],
+            UntyLamExpr::Abs(abs) => vec![(Some(&abs.var), &abs.expr)],
+            UntyLamExpr::App(app) => vec![(None, &app.lhs), (None, &app.rhs)],
+        }
+    }
+}
+
+impl Usage for LetLamExpr {
+    fn as_var(&self) -> Option<&UntyVar> {
+        match self {
+            LetLamExpr::Var(v) => Some(v),
+            _ => None,
+        }
+    }
+    fn subterms(&self) -> Vec<(Option<&UntyVar>, &Self)> {
+        match self {
+            LetLamExpr::Var(_) => vec![],
+            LetLamExpr::Abs(abs) => vec![(Some(&abs.var), &abs.expr)],
+            LetLamExpr::App(app) => vec![(None, &app.lhs), (None, &app.rhs)],
+            LetLamExpr::Let(l) => vec![(None, &l.value), (Some(&l.var), &l.body)],
+        }
+    }
+}
+
+impl Usage for SimplyTypedExpr {
+    fn as_var(&self) -> Option<&UntyVar> {
+        match self {
+            SimplyTypedExpr::Var(v) => Some(v),
+            _ => None,
+        }
+    }
+    fn subterms(&self) -> Vec<(Option<&UntyVar>, &Self)> {
+        match self {
+            SimplyTypedExpr::Var(_) => vec![],
+            SimplyTypedExpr::Abs(abs) => vec![(Some(&abs.var), &abs.expr)],
+            SimplyTypedExpr::App(app) => vec![(None, &app.lhs), (None, &app.rhs)],
+        }
+    }
+}
+
+// Only term variables are counted, type variables may be used freely.
+impl Usage for SystemFExpr {
+    fn as_var(&self) -> Option<&UntyVar> {
+        match self {
+            SystemFExpr::Var(v) => Some(v),
+            _ => None,
+        }
+    }
+    fn subterms(&self) -> Vec<(Option<&UntyVar>, &Self)> {
+        match self {
+            SystemFExpr::Var(_) => vec![],
+            SystemFExpr::Abs(abs) => vec![(Some(&abs.var), &abs.expr)],
+            SystemFExpr::App(app) => vec![(None, &app.lhs), (None, &app.rhs)],
+            SystemFExpr::TyAbs(abs) => vec![(None, &abs.expr)],
+            SystemFExpr::TyApp(app) => vec![(None, &app.expr)],
+        }
+    }
+}
+
+impl Usage for PcfExpr {
+    fn as_var(&self) -> Option<&UntyVar> {
+        match self {
+            PcfExpr::Var(v) => Some(v),
+            _ => None,
+        }
+    }
+    fn subterms(&self) -> Vec<(Option<&UntyVar>, &Self)> {
+        match self {
+            PcfExpr::Var(_) | PcfExpr::Nat(_) => vec![],
+            PcfExpr::Abs(abs) => vec![(Some(&abs.var), &abs.expr)],
+            PcfExpr::App(app) => vec![(None, &app.lhs), (None, &app.rhs)],
+            PcfExpr::Succ(s) => vec![(None, &s.expr)],
+            PcfExpr::Pred(p) => vec![(None, &p.expr)],
+            PcfExpr::Ifz(ifz) => vec![(None, &ifz.cond), (None, &ifz.zero), (None, &ifz.succ)],
+            PcfExpr::Fix(fix) => vec![(None, &fix.expr)],
+        }
+    }
+    fn has_alternatives(&self) -> bool {
+        matches!(self, PcfExpr::Ifz(_))
+    }
+}
+
+impl Usage for TypedLamExpr {
+    fn as_var(&self) -> Option<&UntyVar> {
+        match self {
+            TypedLamExpr::Var(v) => Some(v),
+            _ => None,
+        }
+    }
+    fn subterms(&self) -> Vec<(Option<&UntyVar>, &Self)> {
+        match self {
+            TypedLamExpr::Var(_) | TypedLamExpr::Unit(_) => vec![],
+            TypedLamExpr::Abs(abs) => vec![(Some(&abs.var), &abs.expr)],
+            TypedLamExpr::App(app) => vec![(None, &app.lhs), (None, &app.rhs)],
+            TypedLamExpr::Pair(pair) => vec![(None, &pair.fst), (None, &pair.snd)],
+            TypedLamExpr::Proj(proj) => vec![(None, &proj.expr)],
+            TypedLamExpr::Inj(inj) => vec![(None, &inj.expr)],
+            TypedLamExpr::Case(case) => vec![
+                (None, &case.expr),
+                (Some(&case.left.var), &case.left.expr),
+                (Some(&case.right.var), &case.right.expr),
+            ],
+            TypedLamExpr::Absurd(absurd) => vec![(None, &absurd.expr)],
+            TypedLamExpr::Fold(fold) => vec![(None, &fold.expr)],
+            TypedLamExpr::Unfold(unfold) => vec![(None, &unfold.expr)],
+        }
+    }
+    fn has_alternatives(&self) -> bool {
+        matches!(self, TypedLamExpr::Case(_))
+    }
+}
+
+impl Usage for RecordLamExpr {
+    fn as_var(&self) -> Option<&UntyVar> {
+        match self {
+            RecordLamExpr::Var(v) => Some(v),
+            _ => None,
+        }
+    }
+    fn subterms(&self) -> Vec<(Option<&UntyVar>, &Self)> {
+        match self {
+            RecordLamExpr::Var(_) => vec![],
+            RecordLamExpr::Abs(abs) => vec![(Some(&abs.var), &abs.expr)],
+            RecordLamExpr::App(app) => vec![(None, &app.lhs), (None, &app.rhs)],
+            RecordLamExpr::Record(record) => record.fields.iter().map(|(_, e)| (None, e)).collect(),
+            RecordLamExpr::Field(field) => vec![(None, &field.expr)],
+        }
+    }
+}
+
+impl Usage for BidirExpr {
+    fn as_var(&self) -> Option<&UntyVar> {
+        match self {
+            BidirExpr::Var(v) => Some(v),
+            _ => None,
+        }
+    }
+    fn subterms(&self) -> Vec<(Option<&UntyVar>, &Self)> {
+        match self {
+            BidirExpr::Var(_) | BidirExpr::Unit(_) | BidirExpr::Hole(_) => vec![],
+            BidirExpr::Abs(abs) => vec![(Some(&abs.var), &abs.expr)],
+            BidirExpr::TypedAbs(abs) => vec![(Some(&abs.var), &abs.expr)],
+            BidirExpr::App(app) => vec![(None, &app.lhs), (None, &app.rhs)],
+            BidirExpr::Ann(ann) => vec![(None, &ann.expr)],
+            BidirExpr::Pair(pair) => vec![(None, &pair.fst), (None, &pair.snd)],
+            BidirExpr::Proj(proj) => vec![(None, &proj.expr)],
+            BidirExpr::Inj(inj) => vec![(None, &inj.expr)],
+            BidirExpr::Case(case) => vec![
+                (None, &case.expr),
+                (Some(&case.left.var), &case.left.expr),
+                (Some(&case.right.var), &case.right.expr),
+            ],
+            BidirExpr::Absurd(absurd) => vec![(None, &absurd.expr)],
+            BidirExpr::Fold(fold) => vec![(None, &fold.expr)],
+            BidirExpr::Unfold(unfold) => vec![(None, &unfold.expr)],
+        }
+    }
+    fn has_alternatives(&self) -> bool {
+        matches!(self, BidirExpr::Case(_))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BinderUsage, Discipline, UsageReport};
+    use crate::expressions::untyped_lambda_calculus::unty_var::UntyVar;
+    use crate::parsers::bidirectional_lambda_calculus::BidirectionalLambdaCalculusParser;
+    use crate::parsers::let_lambda_calculus::LetLambdaCalculusParser;
+    use crate::parsers::pcf::PcfParser;
+    use crate::parsers::typed_lambda_calculus::TypedLambdaCalculusParser;
+    use crate::parsers::untyped_lambda_calculus::UntypedLambdaCalculusParser;
+
+    fn untyped(input: &str) -> UsageReport {
+        UsageReport::new(&UntypedLambdaCalculusParser::parse(input).unwrap())
+    }
+
+    #[test]
+    fn classifies_terms() {
+        assert_eq!(untyped("λx y. y x").discipline(), Discipline::Linear);
+        assert_eq!(untyped("λx y. x").discipline(), Discipline::Affine);
+        assert_eq!(untyped("λx. x x").discipline(), Discipline::Relevant);
+        assert_eq!(untyped("λx y. x x").discipline(), Discipline::Unrestricted);
+        // free variables aren't bound by anything, so they never violate a discipline
+        assert_eq!(untyped("a a").discipline(), Discipline::Linear);
+    }
+
+    #[test]
+    fn pinpoints_binders() {
+        let report = untyped("λf. λx. f (λx. x x)");
+        assert_eq!(
+            report.violations(Discipline::Linear),
+            vec![
+                BinderUsage {
+                    var: UntyVar::new("x"),
+                    index: 1,
+                    uses: 0
+                },
+                BinderUsage {
+                    var: UntyVar::new("x"),
+                    index: 2,
+                    uses: 2
+                },
+            ]
+        );
+        assert_eq!(report.violations(Discipline::Affine).len(), 1);
+        assert_eq!(report.violations(Discipline::Relevant).len(), 1);
+        assert!(report.violations(Discipline::Unrestricted).is_empty());
+        assert_eq!(
+            report.violations(Discipline::Affine)[0].to_string(),
+            "x (binder 3) is used 2 times"
+        );
+    }
+
+    #[test]
+    fn typed_variants() {
+        let e = LetLambdaCalculusParser::parse("let id = λx. x in id id").unwrap();
+        assert_eq!(UsageReport::new(&e).discipline(), Discipline::Relevant);
+
+        // a variable used once in each branch is used once
+        let e =
+            TypedLambdaCalculusParser::parse("λs: A + A. λy: A. case s of inl a ⇒ y | inr b ⇒ y")
+                .unwrap();
+        let report = UsageReport::new(&e);
+        assert_eq!(report.violations(Discipline::Affine), vec![]);
+        assert_eq!(report.discipline(), Discipline::Affine);
+
+        let e = PcfParser::parse("λn. λm. ifz n then m else succ m").unwrap();
+        assert_eq!(UsageReport::new(&e).discipline(), Discipline::Linear);
+
+        let e =
+            BidirectionalLambdaCalculusParser::parse("(λf x: A. f x : (A → B) → A → B)").unwrap();
+        assert_eq!(UsageReport::new(&e).discipline(), Discipline::Linear);
+        let e = BidirectionalLambdaCalculusParser::parse("λx. (x, x)").unwrap();
+        assert_eq!(UsageReport::new(&e).discipline(), Discipline::Relevant);
+    }
+}
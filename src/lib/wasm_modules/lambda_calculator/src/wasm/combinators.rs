@@ -0,0 +1,207 @@
+use super::history::History;
+use super::{parse_expr, InterfaceError};
+use crate::bracket_abstraction::AbstractionScheme;
+use crate::expressions::combinatory_logic::CombExpr;
+use crate::expressions::ReductionStrategy;
+use crate::parsers::combinatory_logic::CombinatoryLogicParser;
+use wasm_bindgen::prelude::*;
+
+fn parse_comb_expr(input: &str) -> Result<CombExpr, InterfaceError> {
+    CombinatoryLogicParser::parse(input).map_err(|err| InterfaceError::Parse(err.to_string()))
+}
+
+/// A lambda term compiled into combinators.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct Translation {
+    expr: String,
+    size: u32,
+    source_size: u32,
+    preserves_normal_form: Option<bool>,
+}
+
+#[wasm_bindgen]
+impl Translation {
+    #[wasm_bindgen(getter)]
+    pub fn expr(&self) -> String {
+        self.expr.clone()
+    }
+    #[wasm_bindgen(getter)]
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+    /// Size of the lambda term that was compiled.
+    #[wasm_bindgen(getter)]
+    pub fn source_size(&self) -> u32 {
+        self.source_size
+    }
+    /// `undefined` if the lambda term or its translation has no normal form within the step
+    /// limit.
+    #[wasm_bindgen(getter)]
+    pub fn preserves_normal_form(&self) -> Option<bool> {
+        self.preserves_normal_form
+    }
+}
+
+/// The stepper of `WasmInterface` for combinatory logic with `S`, `K`, `I`, `B`, `C` and `W`.
+/// Methods with the same name behave the same. Workspaces, chunked evaluation, statistics,
+/// share strings and machines are only available for lambda terms; `translate` and
+/// `to_lambda` move a term between the two interfaces.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone)]
+pub struct CombinatorWasmInterface {
+    history: History<CombExpr>,
+    strategy: ReductionStrategy,
+}
+
+#[wasm_bindgen]
+impl CombinatorWasmInterface {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn parse(&self, input: &str) -> Result<String, JsError> {
+        Ok(parse_comb_expr(input)?.to_string())
+    }
+    pub fn set_expr(&mut self, input: &str) -> Result<String, JsError> {
+        let e = parse_comb_expr(input)?;
+        Ok(self.history.add(e).to_string())
+    }
+    pub fn current_expr(&self) -> Option<String> {
+        self.history.current().ok().map(|e| e.to_string())
+    }
+    pub fn step(&mut self) -> Result<String, JsError> {
+        Ok(self.history.step(self.strategy)?.to_string())
+    }
+    pub fn normalize(&mut self, max_steps: u32) -> Result<String, JsError> {
+        Ok(self
+            .history
+            .normalize(self.strategy, max_steps)?
+            .to_string())
+    }
+    pub fn undo(&mut self) -> Result<String, JsError> {
+        Ok(self.history.undo()?.to_string())
+    }
+    pub fn redo(&mut self) -> Result<String, JsError> {
+        Ok(self.history.redo()?.to_string())
+    }
+    pub fn can_undo(&self) -> bool {
+        self.history.can_undo()
+    }
+    pub fn can_redo(&self) -> bool {
+        self.history.can_redo()
+    }
+    pub fn is_normal_form(&self) -> Result<bool, JsError> {
+        Ok(self.history.current()?.is_normal_form(self.strategy))
+    }
+    pub fn history(&self) -> Vec<String> {
+        self.history.to_strings()
+    }
+    pub fn history_index(&self) -> usize {
+        self.history.index()
+    }
+    /// Compiles the lambda term `input` with `scheme`, checking the normal form with at most
+    /// `max_steps` steps on each side.
+    pub fn translate(
+        &self,
+        input: &str,
+        scheme: AbstractionScheme,
+        max_steps: u32,
+    ) -> Result<Translation, JsError> {
+        let e = parse_expr(input)?;
+        let compiled = scheme.compile(&e);
+        Ok(Translation {
+            expr: compiled.to_string(),
+            size: compiled.size(),
+            source_size: e.size(),
+            preserves_normal_form: scheme.preserves_normal_form(&e, max_steps),
+        })
+    }
+    /// The lambda term the combinator expression `input` stands for.
+    pub fn to_lambda(&self, input: &str) -> Result<String, JsError> {
+        Ok(parse_comb_expr(input)?.to_lambda().to_string())
+    }
+    #[wasm_bindgen(getter)]
+    pub fn strategy(&self) -> ReductionStrategy {
+        self.strategy
+    }
+    #[wasm_bindgen(setter)]
+    pub fn set_strategy(&mut self, strategy: ReductionStrategy) {
+        self.strategy = strategy;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_comb_expr, CombinatorWasmInterface};
+    use crate::bracket_abstraction::AbstractionScheme;
+    use crate::expressions::ReductionStrategy;
+    use crate::wasm::InterfaceError;
+
+    #[test]
+    fn step_undo_redo() {
+        let mut interface = CombinatorWasmInterface::new();
+        let strategy = interface.strategy;
+        interface.history.add(parse_comb_expr("S K K x").unwrap());
+        assert_eq!(
+            interface.history.step(strategy).unwrap().to_string(),
+            "K x (K x)"
+        );
+        assert_eq!(interface.history.step(strategy).unwrap().to_string(), "x");
+        assert_eq!(
+            interface.history.step(strategy),
+            Err(InterfaceError::NoRedex)
+        );
+        interface.history.undo().unwrap();
+        assert!(interface.can_redo());
+        assert_eq!(interface.history(), vec!["S K K x", "K x (K x)", "x"]);
+    }
+
+    #[test]
+    fn normalize_respects_the_strategy() {
+        let mut interface = CombinatorWasmInterface::new();
+        let strategy = interface.strategy;
+        interface
+            .history
+            .add(parse_comb_expr("K I (S I I (S I I))").unwrap());
+        assert_eq!(
+            interface
+                .history
+                .normalize(strategy, 10)
+                .unwrap()
+                .to_string(),
+            "I"
+        );
+        interface.history.undo().unwrap();
+        assert_eq!(
+            interface
+                .history
+                .normalize(ReductionStrategy::CallByValue, 10),
+            Err(InterfaceError::StepLimit(10))
+        );
+    }
+
+    #[test]
+    fn translate_and_back() {
+        let interface = CombinatorWasmInterface::new();
+        let t = interface
+            .translate("λf x y. f y x", AbstractionScheme::Naive, 1000)
+            .ok()
+            .unwrap();
+        assert_eq!(
+            (t.source_size(), t.preserves_normal_form()),
+            (8, Some(true))
+        );
+        assert!(t.size() > 8);
+        let t = interface
+            .translate("λf x y. f y x", AbstractionScheme::Turner, 1000)
+            .ok()
+            .unwrap();
+        assert_eq!((t.expr().as_str(), t.size()), ("C", 1));
+        assert_eq!(
+            interface.to_lambda("K x").ok().as_deref(),
+            Some("(λ x. λ y. x) x")
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use super::InterfaceError;
+use crate::annotation::StepRule;
+use crate::expressions::{Expression, ReductionStrategy, RuleStep};
+use std::fmt::Display;
+
+/// The expressions a stepper went through and the position of the current one. Adding an
+/// expression after an undo drops the undone ones.
+#[derive(Debug, Clone)]
+pub(super) struct History<E> {
+    exprs: Vec<E>,
+    index: usize,
+}
+
+impl<E> Default for History<E> {
+    fn default() -> Self {
+        Self {
+            exprs: vec![],
+            index: 0,
+        }
+    }
+}
+
+impl<E> History<E> {
+    pub(super) fn current(&self) -> Result<&E, InterfaceError> {
+        self.exprs
+            .get(self.index)
+            .ok_or(InterfaceError::NoExpression)
+    }
+
+    pub(super) fn add(&mut self, e: E) -> &E {
+        if !self.exprs.is_empty() {
+            self.exprs.truncate(self.index + 1);
+        }
+        self.exprs.push(e);
+        self.index = self.exprs.len() - 1;
+        &self.exprs[self.index]
+    }
+
+    pub(super) fn undo(&mut self) -> Result<&E, InterfaceError> {
+        if !self.can_undo() {
+            return Err(InterfaceError::NothingToUndo);
+        }
+        self.index -= 1;
+        self.current()
+    }
+
+    pub(super) fn redo(&mut self) -> Result<&E, InterfaceError> {
+        if !self.can_redo() {
+            return Err(InterfaceError::NothingToRedo);
+        }
+        self.index += 1;
+        self.current()
+    }
+
+    pub(super) fn can_undo(&self) -> bool {
+        self.index > 0
+    }
+
+    pub(super) fn can_redo(&self) -> bool {
+        self.index + 1 < self.exprs.len()
+    }
+
+    pub(super) fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl<E: Display> History<E> {
+    pub(super) fn to_strings(&self) -> Vec<String> {
+        self.exprs.iter().map(|e| e.to_string()).collect()
+    }
+}
+
+impl<E: Expression<E> + Clone> History<E> {
+    pub(super) fn step(&mut self, strategy: ReductionStrategy) -> Result<&E, InterfaceError> {
+        let e = self.current()?.calc_step(strategy)?;
+        Ok(self.add(e))
+    }
+
+    /// Adds the normal form of the current expression, if `strategy` reaches it within
+    /// `max_steps` steps.
+    pub(super) fn normalize(
+        &mut self,
+        strategy: ReductionStrategy,
+        max_steps: u32,
+    ) -> Result<&E, InterfaceError> {
+        let mut e = self.current()?.clone();
+        for _ in 0..max_steps {
+            match e.calc_step(strategy) {
+                Ok(next) => e = next,
+                Err(_) => return Ok(self.add(e)),
+            }
+        }
+        if e.calc_step(strategy).is_err() {
+            Ok(self.add(e))
+        } else {
+            Err(InterfaceError::StepLimit(max_steps))
+        }
+    }
+}
+
+impl<E: RuleStep<E>> History<E> {
+    pub(super) fn rule_step(
+        &mut self,
+        strategy: ReductionStrategy,
+    ) -> Result<(&E, StepRule), InterfaceError> {
+        let (e, rule) = self.current()?.rule_step(strategy)?;
+        Ok((self.add(e), rule))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::History;
+    use crate::parsers::untyped_lambda_calculus::UntypedLambdaCalculusParser;
+    use crate::wasm::InterfaceError;
+
+    #[test]
+    fn adding_after_undo_drops_the_undone_expressions() {
+        let mut history = History::default();
+        assert_eq!(history.current(), Err(InterfaceError::NoExpression));
+        for input in ["a", "b", "c"] {
+            history.add(UntypedLambdaCalculusParser::parse(input).unwrap());
+        }
+        assert_eq!(history.undo().unwrap().to_string(), "b");
+        assert_eq!(history.undo().unwrap().to_string(), "a");
+        assert_eq!(history.undo(), Err(InterfaceError::NothingToUndo));
+        history.redo().unwrap();
+        history.add(UntypedLambdaCalculusParser::parse("d").unwrap());
+        assert_eq!(history.to_strings(), vec!["a", "b", "d"]);
+        assert_eq!(history.redo(), Err(InterfaceError::NothingToRedo));
+    }
+}
@@ -0,0 +1,161 @@
+use super::InterfaceError;
+use crate::expressions::untyped_lambda_calculus::UntyLamExpr;
+use crate::machines::{
+    krivine::KrivineMachine, secd::SecdMachine, AbstractMachine, Machine, Trace,
+};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct MachineState {
+    rule: Option<String>,
+    registers: Vec<(&'static str, String)>,
+    steps: usize,
+    is_final: bool,
+    term: Option<String>,
+}
+
+impl MachineState {
+    fn new<M: Machine>(trace: &Trace<M>) -> Self {
+        let machine = trace.current();
+        Self {
+            rule: trace.rule().map(|rule| rule.to_string()),
+            registers: machine.registers(),
+            steps: trace.steps(),
+            is_final: machine.is_final(),
+            term: machine.read_back().map(|e| e.to_string()),
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl MachineState {
+    /// The rule of the transition into this state, `undefined` in the initial state.
+    #[wasm_bindgen(getter)]
+    pub fn rule(&self) -> Option<String> {
+        self.rule.clone()
+    }
+    pub fn register_names(&self) -> Vec<String> {
+        self.registers
+            .iter()
+            .map(|(name, _)| name.to_string())
+            .collect()
+    }
+    /// Contents of the registers, parallel to `register_names`.
+    pub fn register_values(&self) -> Vec<String> {
+        self.registers
+            .iter()
+            .map(|(_, value)| value.clone())
+            .collect()
+    }
+    #[wasm_bindgen(getter)]
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+    #[wasm_bindgen(getter)]
+    pub fn is_final(&self) -> bool {
+        self.is_final
+    }
+    /// The term the state stands for, if the machine can read it back.
+    #[wasm_bindgen(getter)]
+    pub fn term(&self) -> Option<String> {
+        self.term.clone()
+    }
+}
+
+/// The trace of the machine started in a workspace.
+#[derive(Debug, Clone)]
+pub(super) enum MachineSession {
+    Krivine(Trace<KrivineMachine>),
+    Secd(Trace<SecdMachine>),
+}
+
+// Runs `$body` with `$trace` bound to the trace of whichever machine is running.
+macro_rules! with_trace {
+    ($session:expr, $trace:ident => $body:expr) => {
+        match $session {
+            MachineSession::Krivine($trace) => $body,
+            MachineSession::Secd($trace) => $body,
+        }
+    };
+}
+
+impl MachineSession {
+    pub(super) fn new(machine: AbstractMachine, e: UntyLamExpr) -> Self {
+        match machine {
+            AbstractMachine::Krivine => Self::Krivine(Trace::new(KrivineMachine::new(e))),
+            AbstractMachine::Secd => Self::Secd(Trace::new(SecdMachine::new(&e))),
+        }
+    }
+
+    pub(super) fn state(&self) -> MachineState {
+        with_trace!(self, trace => MachineState::new(trace))
+    }
+
+    pub(super) fn step(&mut self) -> Result<MachineState, InterfaceError> {
+        with_trace!(self, trace => match trace.step() {
+            Some(_) => Ok(MachineState::new(trace)),
+            None => Err(InterfaceError::MachineHalted),
+        })
+    }
+
+    pub(super) fn run(&mut self, max_steps: u32) -> MachineState {
+        with_trace!(self, trace => {
+            trace.run(max_steps);
+            MachineState::new(trace)
+        })
+    }
+
+    pub(super) fn undo(&mut self) -> Result<MachineState, InterfaceError> {
+        with_trace!(self, trace => if trace.undo() {
+            Ok(MachineState::new(trace))
+        } else {
+            Err(InterfaceError::NothingToUndo)
+        })
+    }
+
+    pub(super) fn redo(&mut self) -> Result<MachineState, InterfaceError> {
+        with_trace!(self, trace => if trace.redo() {
+            Ok(MachineState::new(trace))
+        } else {
+            Err(InterfaceError::NothingToRedo)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MachineSession;
+    use crate::machines::AbstractMachine;
+    use crate::parsers::untyped_lambda_calculus::UntypedLambdaCalculusParser;
+    use crate::wasm::InterfaceError;
+
+    #[test]
+    fn step_run_and_undo() {
+        let e = UntypedLambdaCalculusParser::parse("(λx. x) y").unwrap();
+        let mut session = MachineSession::new(AbstractMachine::Krivine, e);
+        let initial = session.state();
+        assert_eq!(initial.rule(), None);
+        assert_eq!(
+            initial.register_names(),
+            vec!["term", "environment", "stack"]
+        );
+        assert_eq!(session.step().unwrap().rule().as_deref(), Some("push"));
+        let last = session.run(10);
+        assert!(last.is_final());
+        assert_eq!((last.steps(), last.term().as_deref()), (3, Some("y")));
+        assert_eq!(session.step(), Err(InterfaceError::MachineHalted));
+        session.undo().unwrap();
+        assert_eq!(session.redo(), Ok(last));
+        assert_eq!(session.redo(), Err(InterfaceError::NothingToRedo));
+    }
+
+    #[test]
+    fn secd_reads_back_final_states_only() {
+        let e = UntypedLambdaCalculusParser::parse("(λx y. x) a b").unwrap();
+        let mut session = MachineSession::new(AbstractMachine::Secd, e);
+        assert_eq!(session.state().register_names()[3], "dump");
+        assert_eq!(session.step().unwrap().term(), None);
+        assert_eq!(session.run(100).term().as_deref(), Some("a"));
+    }
+}
@@ -0,0 +1,663 @@
+use crate::evaluation::EvaluationProgress;
+use crate::expressions::simply_typed_lambda_calculus::simple_type::SimpleType;
+use crate::expressions::untyped_lambda_calculus::UntyLamExpr;
+use crate::expressions::{CalcStepError, ReductionStrategy};
+use crate::machines::AbstractMachine;
+use crate::parsers::intersection_types::IntersectionTypeParser;
+use crate::parsers::let_lambda_calculus::LetLambdaCalculusParser;
+use crate::parsers::linear_lambda_calculus::{LinearLambdaCalculusParser, LinearParseError};
+use crate::parsers::pure_type_system::PureTypeSystemParser;
+use crate::parsers::record_lambda_calculus::RecordLambdaCalculusParser;
+use crate::parsers::simply_typed_lambda_calculus::SimplyTypedLambdaCalculusParser;
+use crate::parsers::untyped_lambda_calculus::UntypedLambdaCalculusParser;
+use crate::share::{self, ShareDecodeError, ShareEncodeError};
+use crate::statistics::ReductionStats;
+use crate::type_checkers::bidirectional_lambda_calculus::BidirTypeError;
+use crate::type_checkers::hindley_milner::{HindleyMilnerChecker, InferenceError};
+use crate::type_checkers::inhabitation::{InhabitantSearch, Inhabitants};
+use crate::type_checkers::intersection_types::{
+    IntersectionTypeChecker, IntersectionTypeError, IntersectionTyping,
+};
+use crate::type_checkers::pcf::PcfTypeError;
+use crate::type_checkers::proof::ProofError;
+use crate::type_checkers::pure_type_system::{LambdaCube, PtsTypeError, PureTypeSystemChecker};
+use crate::type_checkers::record_lambda_calculus::{RecordLambdaCalculusChecker, RecordTypeError};
+use crate::type_checkers::simply_typed_lambda_calculus::SimpleTypeError;
+use crate::type_checkers::system_f::SystemFTypeError;
+use crate::type_checkers::typed_lambda_calculus::TypedLamTypeError;
+use crate::usage::{BinderUsage, Discipline, UsageReport};
+use machine::MachineState;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use step_info::StepInfo;
+use wasm_bindgen::prelude::*;
+use workspace::Workspace;
+
+pub mod combinators;
+mod history;
+pub mod machine;
+pub mod pcf;
+pub mod proof;
+pub mod simply_typed;
+pub mod step_info;
+pub mod system_f;
+pub mod typed;
+pub mod workspace;
+
+/// Bumped whenever an exported function changes its name, arguments or result.
+pub const API_VERSION: u32 = 1;
+
+/// The largest size `inhabitants` enumerates up to, since the number of terms grows
+/// exponentially with it.
+pub const MAX_INHABITANT_SIZE: u32 = 30;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InterfaceError {
+    Parse(String),
+    NoExpression,
+    NoRedex,
+    Overflow,
+    NothingToUndo,
+    NothingToRedo,
+    StepLimit(u32),
+    NoEvaluation,
+    WorkspaceExists(String),
+    NoSuchWorkspace(String),
+    DeleteCurrentWorkspace,
+    Share(ShareDecodeError),
+    Unshareable(ShareEncodeError),
+    Inference(InferenceError),
+    SimplyTyped(SimpleTypeError),
+    SystemF(SystemFTypeError),
+    Pcf(PcfTypeError),
+    PureTypeSystem(PtsTypeError),
+    TypedLambdaCalculus(TypedLamTypeError),
+    Record(RecordTypeError),
+    Bidirectional(BidirTypeError),
+    Intersection(IntersectionTypeError),
+    Proof(ProofError),
+    Uninhabited(SimpleType),
+    InhabitantSize(u32),
+    NotLinear(Vec<BinderUsage>),
+    NoMachine,
+    MachineHalted,
+}
+
+impl Display for InterfaceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InterfaceError::Parse(err) => write!(f, "parse error: {err}"),
+            InterfaceError::NoExpression => write!(f, "no expression has been entered yet"),
+            InterfaceError::NoRedex => write!(f, "{}", CalcStepError::NoRedex),
+            InterfaceError::Overflow => write!(f, "{}", CalcStepError::Overflow),
+            InterfaceError::NothingToUndo => write!(f, "nothing to undo"),
+            InterfaceError::NothingToRedo => write!(f, "nothing to redo"),
+            InterfaceError::StepLimit(steps) => {
+                write!(f, "no normal form reached within {steps} steps")
+            }
+            InterfaceError::NoEvaluation => write!(f, "no evaluation is running"),
+            InterfaceError::WorkspaceExists(name) => {
+                write!(f, "a workspace named \"{name}\" already exists")
+            }
+            InterfaceError::NoSuchWorkspace(name) => {
+                write!(f, "there is no workspace named \"{name}\"")
+            }
+            InterfaceError::DeleteCurrentWorkspace => {
+                write!(
+                    f,
+                    "the current workspace can't be deleted, switch to another one first"
+                )
+            }
+            InterfaceError::Share(err) => write!(f, "invalid share string: {err}"),
+            InterfaceError::Unshareable(err) => write!(f, "can't share this expression: {err}"),
+            InterfaceError::Inference(err) => write!(f, "type error: {err}"),
+            InterfaceError::SimplyTyped(err) => write!(f, "type error: {err}"),
+            InterfaceError::SystemF(err) => write!(f, "type error: {err}"),
+            InterfaceError::Pcf(err) => write!(f, "type error: {err}"),
+            InterfaceError::PureTypeSystem(err) => write!(f, "type error: {err}"),
+            InterfaceError::TypedLambdaCalculus(err) => write!(f, "type error: {err}"),
+            InterfaceError::Record(err) => write!(f, "type error: {err}"),
+            InterfaceError::Bidirectional(err) => write!(f, "type error: {err}"),
+            InterfaceError::Intersection(err) => write!(f, "type error: {err}"),
+            InterfaceError::Proof(err) => write!(f, "refinement rejected: {err}"),
+            InterfaceError::Uninhabited(ty) => write!(
+                f,
+                "`{ty}` has no inhabitants, it is not provable in intuitionistic logic"
+            ),
+            InterfaceError::InhabitantSize(size) => write!(
+                f,
+                "inhabitants are only enumerated up to {MAX_INHABITANT_SIZE} nodes, not {size}"
+            ),
+            InterfaceError::NotLinear(binders) => {
+                write!(f, "{}", LinearParseError::NotLinear(binders.clone()))
+            }
+            InterfaceError::NoMachine => write!(f, "no machine has been started"),
+            InterfaceError::MachineHalted => write!(f, "the machine is in a final state"),
+        }
+    }
+}
+
+impl std::error::Error for InterfaceError {}
+
+impl From<CalcStepError> for InterfaceError {
+    fn from(err: CalcStepError) -> Self {
+        match err {
+            CalcStepError::NoRedex => InterfaceError::NoRedex,
+            CalcStepError::Overflow => InterfaceError::Overflow,
+        }
+    }
+}
+
+impl From<ShareDecodeError> for InterfaceError {
+    fn from(err: ShareDecodeError) -> Self {
+        InterfaceError::Share(err)
+    }
+}
+
+impl From<ShareEncodeError> for InterfaceError {
+    fn from(err: ShareEncodeError) -> Self {
+        InterfaceError::Unshareable(err)
+    }
+}
+
+impl From<InferenceError> for InterfaceError {
+    fn from(err: InferenceError) -> Self {
+        InterfaceError::Inference(err)
+    }
+}
+
+impl From<SimpleTypeError> for InterfaceError {
+    fn from(err: SimpleTypeError) -> Self {
+        InterfaceError::SimplyTyped(err)
+    }
+}
+
+impl From<SystemFTypeError> for InterfaceError {
+    fn from(err: SystemFTypeError) -> Self {
+        InterfaceError::SystemF(err)
+    }
+}
+
+impl From<PcfTypeError> for InterfaceError {
+    fn from(err: PcfTypeError) -> Self {
+        InterfaceError::Pcf(err)
+    }
+}
+
+impl From<PtsTypeError> for InterfaceError {
+    fn from(err: PtsTypeError) -> Self {
+        InterfaceError::PureTypeSystem(err)
+    }
+}
+
+impl From<TypedLamTypeError> for InterfaceError {
+    fn from(err: TypedLamTypeError) -> Self {
+        InterfaceError::TypedLambdaCalculus(err)
+    }
+}
+
+impl From<RecordTypeError> for InterfaceError {
+    fn from(err: RecordTypeError) -> Self {
+        InterfaceError::Record(err)
+    }
+}
+
+impl From<BidirTypeError> for InterfaceError {
+    fn from(err: BidirTypeError) -> Self {
+        InterfaceError::Bidirectional(err)
+    }
+}
+
+impl From<IntersectionTypeError> for InterfaceError {
+    fn from(err: IntersectionTypeError) -> Self {
+        InterfaceError::Intersection(err)
+    }
+}
+
+impl From<ProofError> for InterfaceError {
+    fn from(err: ProofError) -> Self {
+        InterfaceError::Proof(err)
+    }
+}
+
+impl From<LinearParseError> for InterfaceError {
+    fn from(err: LinearParseError) -> Self {
+        match err {
+            LinearParseError::Parse(err) => InterfaceError::Parse(err.to_string()),
+            LinearParseError::NotLinear(binders) => InterfaceError::NotLinear(binders),
+        }
+    }
+}
+
+pub const DEFAULT_WORKSPACE: &str = "main";
+
+fn parse_expr(input: &str) -> Result<UntyLamExpr, InterfaceError> {
+    UntypedLambdaCalculusParser::parse(input).map_err(|err| InterfaceError::Parse(err.to_string()))
+}
+
+fn parse_simple_type(input: &str) -> Result<SimpleType, InterfaceError> {
+    SimplyTypedLambdaCalculusParser::parse_type(input)
+        .map_err(|err| InterfaceError::Parse(err.to_string()))
+}
+
+#[wasm_bindgen]
+#[derive(Debug)]
+pub struct WasmInterface {
+    workspaces: BTreeMap<String, Workspace>,
+    current_workspace: String,
+}
+
+impl Default for WasmInterface {
+    fn default() -> Self {
+        Self {
+            workspaces: BTreeMap::from([(DEFAULT_WORKSPACE.to_string(), Workspace::default())]),
+            current_workspace: DEFAULT_WORKSPACE.to_string(),
+        }
+    }
+}
+
+impl WasmInterface {
+    fn workspace(&self) -> &Workspace {
+        &self.workspaces[&self.current_workspace]
+    }
+
+    fn workspace_mut(&mut self) -> &mut Workspace {
+        self.workspaces
+            .get_mut(&self.current_workspace)
+            .expect("the current workspace always exists")
+    }
+
+    fn get_workspace(&self, name: &str) -> Result<&Workspace, InterfaceError> {
+        self.workspaces
+            .get(name)
+            .ok_or_else(|| InterfaceError::NoSuchWorkspace(name.to_string()))
+    }
+
+    fn add_workspace(&mut self, name: &str, workspace: Workspace) -> Result<(), InterfaceError> {
+        if self.workspaces.contains_key(name) {
+            return Err(InterfaceError::WorkspaceExists(name.to_string()));
+        }
+        self.workspaces.insert(name.to_string(), workspace);
+        Ok(())
+    }
+
+    fn switch_to_workspace(&mut self, name: &str) -> Result<(), InterfaceError> {
+        self.get_workspace(name)?;
+        self.current_workspace = name.to_string();
+        Ok(())
+    }
+
+    fn remove_workspace(&mut self, name: &str) -> Result<(), InterfaceError> {
+        if name == self.current_workspace {
+            return Err(InterfaceError::DeleteCurrentWorkspace);
+        }
+        self.workspaces
+            .remove(name)
+            .map(|_| ())
+            .ok_or_else(|| InterfaceError::NoSuchWorkspace(name.to_string()))
+    }
+
+    fn copy_expr_between(&mut self, from: &str, to: &str) -> Result<&UntyLamExpr, InterfaceError> {
+        let e = self.get_workspace(from)?.get_current_expr()?.clone();
+        let target = self
+            .workspaces
+            .get_mut(to)
+            .ok_or_else(|| InterfaceError::NoSuchWorkspace(to.to_string()))?;
+        target.check_mode(&e)?;
+        target.add_new_expr(e);
+        target.get_current_expr()
+    }
+}
+
+#[wasm_bindgen]
+impl WasmInterface {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses `input` and returns it pretty printed without touching the history.
+    pub fn parse(&self, input: &str) -> Result<String, JsError> {
+        Ok(parse_expr(input)?.to_string())
+    }
+    /// Infers the principal type of `input`, keeping `let`-bound variables polymorphic.
+    pub fn infer_type(&self, input: &str) -> Result<String, JsError> {
+        let e = LetLambdaCalculusParser::parse(input)
+            .map_err(|err| InterfaceError::Parse(err.to_string()))?;
+        Ok(HindleyMilnerChecker::infer(&e)
+            .map_err(InterfaceError::from)?
+            .to_string())
+    }
+    /// Checks `input` as a term of the given corner of the lambda cube and returns its type.
+    pub fn pts_type_of(&self, input: &str, system: LambdaCube) -> Result<String, JsError> {
+        let e = PureTypeSystemParser::parse(input)
+            .map_err(|err| InterfaceError::Parse(err.to_string()))?;
+        Ok(PureTypeSystemChecker::type_of(&system.system(), &e)
+            .map_err(InterfaceError::from)?
+            .to_string())
+    }
+    /// Checks `input` as a term with records and returns its minimal type under subtyping.
+    pub fn record_type_of(&self, input: &str) -> Result<String, JsError> {
+        let e = RecordLambdaCalculusParser::parse(input)
+            .map_err(|err| InterfaceError::Parse(err.to_string()))?;
+        Ok(RecordLambdaCalculusChecker::type_of(&e)
+            .map_err(InterfaceError::from)?
+            .to_string())
+    }
+    /// Some closed normal term of the simple type `ty`, or an error if there is none.
+    pub fn inhabitant(&self, ty: &str) -> Result<String, JsError> {
+        let ty = parse_simple_type(ty)?;
+        match InhabitantSearch::find(&ty) {
+            Some(e) => Ok(e.to_string()),
+            None => Err(InterfaceError::Uninhabited(ty).into()),
+        }
+    }
+    /// All closed normal terms of the simple type `ty` with at most `max_size` nodes, smallest
+    /// first. Fails if `ty` has no inhabitants at all or `max_size` exceeds
+    /// `MAX_INHABITANT_SIZE`.
+    pub fn inhabitants(&self, ty: &str, max_size: u32) -> Result<Vec<String>, JsError> {
+        if max_size > MAX_INHABITANT_SIZE {
+            return Err(InterfaceError::InhabitantSize(max_size).into());
+        }
+        let ty = parse_simple_type(ty)?;
+        match InhabitantSearch::search(&ty, max_size) {
+            Inhabitants::Uninhabited => Err(InterfaceError::Uninhabited(ty).into()),
+            Inhabitants::Inhabited { within_bound, .. } => {
+                Ok(within_bound.iter().map(|e| e.to_string()).collect())
+            }
+        }
+    }
+    /// The most restrictive discipline all binders of `input` obey.
+    pub fn usage(&self, input: &str) -> Result<Discipline, JsError> {
+        Ok(UsageReport::new(&parse_expr(input)?).discipline())
+    }
+    /// Describes the binders of `input` that are used more or less often than `discipline` allows.
+    pub fn usage_violations(
+        &self,
+        input: &str,
+        discipline: Discipline,
+    ) -> Result<Vec<String>, JsError> {
+        Ok(UsageReport::new(&parse_expr(input)?)
+            .violations(discipline)
+            .iter()
+            .map(|binder| binder.to_string())
+            .collect())
+    }
+    /// Parses `input` and makes it the current expression. In linear mode only linear terms
+    /// are accepted.
+    pub fn set_expr(&mut self, input: &str) -> Result<String, JsError> {
+        let e = if self.workspace().linear {
+            LinearLambdaCalculusParser::parse(input).map_err(InterfaceError::from)?
+        } else {
+            parse_expr(input)?
+        };
+        let workspace = self.workspace_mut();
+        workspace.add_new_expr(e);
+        Ok(workspace.get_current_expr()?.to_string())
+    }
+    pub fn current_expr(&self) -> Option<String> {
+        self.workspace()
+            .get_current_expr()
+            .ok()
+            .map(|e| e.to_string())
+    }
+    /// Reduces one redex of the current expression chosen by the current strategy.
+    pub fn step(&mut self) -> Result<String, JsError> {
+        Ok(self.workspace_mut().add_step_expr()?.to_string())
+    }
+    /// Like `step`, but also describes the contracted redex and the substitution.
+    pub fn step_annotated(&mut self) -> Result<StepInfo, JsError> {
+        let (e, annotation) = self.workspace_mut().add_annotated_step_expr()?;
+        Ok(StepInfo::new(e.to_string(), annotation))
+    }
+    /// Contracts the leftmost outermost η-redex `λx. M x` of the current expression, which
+    /// no strategy chooses on its own.
+    pub fn eta_step(&mut self) -> Result<StepInfo, JsError> {
+        let (e, annotation) = self.workspace_mut().add_eta_step_expr()?;
+        Ok(StepInfo::new(e.to_string(), annotation))
+    }
+    /// Reduces the current expression until no redex is left or `max_steps` steps were taken.
+    pub fn normalize(&mut self, max_steps: u32) -> Result<String, JsError> {
+        Ok(self
+            .workspace_mut()
+            .add_normalized_expr(max_steps)?
+            .to_string())
+    }
+    /// Starts a resumable evaluation of the current expression, replacing a running one. Any
+    /// change of the current expression, including undo and redo, drops the evaluation.
+    pub fn start(&mut self) -> Result<EvaluationProgress, JsError> {
+        Ok(self.workspace_mut().start_evaluation()?)
+    }
+    /// Continues the running evaluation for at most `budget_steps` steps. Once `more_work`
+    /// is `false` the result has been added to the history.
+    pub fn run(&mut self, budget_steps: u32) -> Result<EvaluationProgress, JsError> {
+        Ok(self.workspace_mut().run_evaluation(budget_steps)?)
+    }
+    /// Stops the running evaluation and adds the expression reached so far to the history.
+    pub fn cancel(&mut self) -> Result<String, JsError> {
+        Ok(self.workspace_mut().cancel_evaluation()?.to_string())
+    }
+    pub fn is_running(&self) -> bool {
+        self.workspace().evaluation.is_some()
+    }
+    /// Loads the current expression into `machine`, replacing a machine that was started
+    /// before. The machine steps independently of the expression history.
+    pub fn start_machine(&mut self, machine: AbstractMachine) -> Result<MachineState, JsError> {
+        Ok(self.workspace_mut().start_machine(machine)?)
+    }
+    pub fn machine_state(&self) -> Option<MachineState> {
+        self.workspace().machine.as_ref().map(|m| m.state())
+    }
+    pub fn machine_step(&mut self) -> Result<MachineState, JsError> {
+        Ok(self.workspace_mut().machine_mut()?.step()?)
+    }
+    /// Takes at most `max_steps` transitions, stopping early in a final state.
+    pub fn machine_run(&mut self, max_steps: u32) -> Result<MachineState, JsError> {
+        Ok(self.workspace_mut().machine_mut()?.run(max_steps))
+    }
+    pub fn machine_undo(&mut self) -> Result<MachineState, JsError> {
+        Ok(self.workspace_mut().machine_mut()?.undo()?)
+    }
+    pub fn machine_redo(&mut self) -> Result<MachineState, JsError> {
+        Ok(self.workspace_mut().machine_mut()?.redo()?)
+    }
+    pub fn undo(&mut self) -> Result<String, JsError> {
+        Ok(self.workspace_mut().undo_expr()?.to_string())
+    }
+    pub fn redo(&mut self) -> Result<String, JsError> {
+        Ok(self.workspace_mut().redo_expr()?.to_string())
+    }
+    pub fn can_undo(&self) -> bool {
+        self.workspace().can_undo()
+    }
+    pub fn can_redo(&self) -> bool {
+        self.workspace().can_redo()
+    }
+    pub fn is_normal_form(&self) -> Result<bool, JsError> {
+        let workspace = self.workspace();
+        Ok(workspace
+            .get_current_expr()?
+            .is_normal_form(workspace.strategy))
+    }
+    pub fn history(&self) -> Vec<String> {
+        self.workspace().history()
+    }
+    /// The principal intersection typing of the current expression, if it is a normal form.
+    pub fn intersection_typing(&self) -> Result<String, JsError> {
+        let e = self.workspace().get_current_expr()?;
+        let typing = IntersectionTypeChecker::principal_typing(e).map_err(InterfaceError::from)?;
+        Ok(typing.judgement(e))
+    }
+    /// Checks that the current expression has intersection type `ty` in `context`, written
+    /// as `x: T, y: U`, and returns the judgement.
+    pub fn check_intersection_typing(&self, context: &str, ty: &str) -> Result<String, JsError> {
+        let e = self.workspace().get_current_expr()?;
+        let parse_error = |err: peg::error::ParseError<_>| InterfaceError::Parse(err.to_string());
+        let typing = IntersectionTyping {
+            context: IntersectionTypeParser::parse_context(context).map_err(parse_error)?,
+            ty: IntersectionTypeParser::parse_type(ty).map_err(parse_error)?,
+        };
+        IntersectionTypeChecker::check(&typing.context, e, &typing.ty)
+            .map_err(InterfaceError::from)?;
+        Ok(typing.judgement(e))
+    }
+    /// Work done since the current expression was entered, including undone steps.
+    pub fn stats(&self) -> ReductionStats {
+        self.workspace().stats
+    }
+    pub fn reset_stats(&mut self) -> Result<(), JsError> {
+        let workspace = self.workspace_mut();
+        workspace.stats = ReductionStats::new(workspace.get_current_expr()?);
+        Ok(())
+    }
+    pub fn history_index(&self) -> usize {
+        self.workspace().exprs.index()
+    }
+    #[wasm_bindgen(getter)]
+    pub fn strategy(&self) -> ReductionStrategy {
+        self.workspace().strategy
+    }
+    #[wasm_bindgen(setter)]
+    pub fn set_strategy(&mut self, strategy: ReductionStrategy) {
+        self.workspace_mut().strategy = strategy;
+    }
+    #[wasm_bindgen(getter)]
+    pub fn linear(&self) -> bool {
+        self.workspace().linear
+    }
+    /// Whether the current workspace only enters and reduces linear terms.
+    #[wasm_bindgen(setter)]
+    pub fn set_linear(&mut self, linear: bool) {
+        self.workspace_mut().linear = linear;
+    }
+    /// Encodes the current expression and strategy, plus `step` if the receiver should replay steps.
+    pub fn share_string(&self, step: Option<u32>) -> Result<String, JsError> {
+        let workspace = self.workspace();
+        let replay = Some((workspace.strategy, step.map(u64::from)));
+        Ok(share::encode_session(workspace.get_current_expr()?, replay)
+            .map_err(InterfaceError::from)?)
+    }
+    pub fn load_share_string(&mut self, s: &str) -> Result<String, JsError> {
+        Ok(self.workspace_mut().add_shared_expr(s)?.to_string())
+    }
+
+    // Workspaces
+    pub fn workspace_names(&self) -> Vec<String> {
+        self.workspaces.keys().cloned().collect()
+    }
+    #[wasm_bindgen(getter)]
+    pub fn current_workspace(&self) -> String {
+        self.current_workspace.clone()
+    }
+    /// Creates an empty workspace with default settings without switching to it.
+    pub fn create_workspace(&mut self, name: &str) -> Result<(), JsError> {
+        Ok(self.add_workspace(name, Workspace::default())?)
+    }
+    /// Makes `name` the workspace all other calls operate on.
+    pub fn switch_workspace(&mut self, name: &str) -> Result<(), JsError> {
+        Ok(self.switch_to_workspace(name)?)
+    }
+    /// Creates `to` with a copy of the history and settings of `from`.
+    pub fn clone_workspace(&mut self, from: &str, to: &str) -> Result<(), JsError> {
+        let workspace = self.get_workspace(from)?.clone();
+        Ok(self.add_workspace(to, workspace)?)
+    }
+    pub fn delete_workspace(&mut self, name: &str) -> Result<(), JsError> {
+        Ok(self.remove_workspace(name)?)
+    }
+    /// Adds the current expression of `from` to the history of `to`.
+    pub fn copy_expr(&mut self, from: &str, to: &str) -> Result<String, JsError> {
+        Ok(self.copy_expr_between(from, to)?.to_string())
+    }
+}
+
+#[wasm_bindgen]
+pub fn api_version() -> u32 {
+    API_VERSION
+}
+
+#[wasm_bindgen]
+pub fn init() -> WasmInterface {
+    WasmInterface::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_expr, InterfaceError, WasmInterface, Workspace, DEFAULT_WORKSPACE};
+
+    #[test]
+    fn workspaces() {
+        let mut interface = WasmInterface::new();
+        assert_eq!(interface.workspace_names(), vec![DEFAULT_WORKSPACE]);
+        interface
+            .workspace_mut()
+            .add_current_expr(parse_expr("(λx. x) a").unwrap());
+
+        interface
+            .add_workspace("scratch", Default::default())
+            .unwrap();
+        assert_eq!(
+            interface.add_workspace("scratch", Default::default()),
+            Err(InterfaceError::WorkspaceExists("scratch".to_string()))
+        );
+        assert_eq!(
+            interface.switch_to_workspace("missing"),
+            Err(InterfaceError::NoSuchWorkspace("missing".to_string()))
+        );
+        interface.switch_to_workspace("scratch").unwrap();
+        assert_eq!(interface.current_expr(), None);
+
+        assert_eq!(
+            interface
+                .copy_expr_between(DEFAULT_WORKSPACE, "scratch")
+                .unwrap()
+                .to_string(),
+            "(λ x. x) a"
+        );
+        interface.workspace_mut().add_step_expr().unwrap();
+        assert_eq!(interface.current_expr().as_deref(), Some("a"));
+        interface.switch_to_workspace(DEFAULT_WORKSPACE).unwrap();
+        assert_eq!(interface.current_expr().as_deref(), Some("(λ x. x) a"));
+
+        assert_eq!(
+            interface.remove_workspace(DEFAULT_WORKSPACE),
+            Err(InterfaceError::DeleteCurrentWorkspace)
+        );
+        interface.remove_workspace("scratch").unwrap();
+        assert_eq!(interface.workspace_names(), vec![DEFAULT_WORKSPACE]);
+    }
+
+    #[test]
+    fn cloned_workspaces_are_independent() {
+        let mut interface = WasmInterface::new();
+        interface
+            .workspace_mut()
+            .add_current_expr(parse_expr("(λx. x) ((λy. y) a)").unwrap());
+        let copy = interface.get_workspace(DEFAULT_WORKSPACE).unwrap().clone();
+        interface.add_workspace("copy", copy).unwrap();
+        interface.workspace_mut().add_step_expr().unwrap();
+        interface.switch_to_workspace("copy").unwrap();
+        assert_eq!(interface.history().len(), 1);
+    }
+
+    #[test]
+    fn copies_respect_linear_mode() {
+        let mut interface = WasmInterface::new();
+        interface
+            .workspace_mut()
+            .add_current_expr(parse_expr("λx. x x").unwrap());
+        let linear = Workspace {
+            linear: true,
+            ..Default::default()
+        };
+        interface.add_workspace("linear", linear).unwrap();
+        assert!(matches!(
+            interface.copy_expr_between(DEFAULT_WORKSPACE, "linear"),
+            Err(InterfaceError::NotLinear(v)) if v.len() == 1
+        ));
+        assert_eq!(
+            interface.get_workspace("linear").unwrap().history().len(),
+            0
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use super::history::History;
+use super::step_info::RuleStepInfo;
+use super::InterfaceError;
+use crate::expressions::pcf::PcfExpr;
+use crate::expressions::ReductionStrategy;
+use crate::parsers::pcf::PcfParser;
+use crate::type_checkers::pcf::PcfChecker;
+use wasm_bindgen::prelude::*;
+
+fn parse_pcf_expr(input: &str) -> Result<PcfExpr, InterfaceError> {
+    PcfParser::parse(input).map_err(|err| InterfaceError::Parse(err.to_string()))
+}
+
+/// The stepper of `WasmInterface` for PCF. Methods with the same name behave the same.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone)]
+pub struct PcfWasmInterface {
+    history: History<PcfExpr>,
+    strategy: ReductionStrategy,
+}
+
+#[wasm_bindgen]
+impl PcfWasmInterface {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn parse(&self, input: &str) -> Result<String, JsError> {
+        Ok(parse_pcf_expr(input)?.to_string())
+    }
+    /// Infers the type of `input`, with the types its free variables need to have.
+    pub fn type_of(&self, input: &str) -> Result<String, JsError> {
+        let e = parse_pcf_expr(input)?;
+        Ok(PcfChecker::infer(&e)
+            .map_err(InterfaceError::from)?
+            .to_string())
+    }
+    /// The typing derivation of `input` as JSON, see `Derivation::to_json`. For ill-typed
+    /// terms it ends at the failing rule.
+    pub fn derivation_json(&self, input: &str) -> Result<String, JsError> {
+        let e = parse_pcf_expr(input)?;
+        Ok(PcfChecker::derive(&e).0.to_json())
+    }
+    /// Like `derivation_json`, but as a `bussproofs` proof tree.
+    pub fn derivation_latex(&self, input: &str) -> Result<String, JsError> {
+        let e = parse_pcf_expr(input)?;
+        Ok(PcfChecker::derive(&e).0.to_latex())
+    }
+    /// Parses `input` and makes it the current expression. Ill-typed terms are accepted, so
+    /// that students can watch them get stuck.
+    pub fn set_expr(&mut self, input: &str) -> Result<String, JsError> {
+        let e = parse_pcf_expr(input)?;
+        Ok(self.history.add(e).to_string())
+    }
+    pub fn current_expr(&self) -> Option<String> {
+        self.history.current().ok().map(|e| e.to_string())
+    }
+    pub fn step(&mut self) -> Result<String, JsError> {
+        Ok(self.history.step(self.strategy)?.to_string())
+    }
+    /// Like `step`, but also tells whether it was a β-, δ- or `fix` step.
+    pub fn rule_step(&mut self) -> Result<RuleStepInfo, JsError> {
+        let (e, rule) = self.history.rule_step(self.strategy)?;
+        Ok(RuleStepInfo::new(e.to_string(), rule))
+    }
+    pub fn normalize(&mut self, max_steps: u32) -> Result<String, JsError> {
+        Ok(self
+            .history
+            .normalize(self.strategy, max_steps)?
+            .to_string())
+    }
+    pub fn undo(&mut self) -> Result<String, JsError> {
+        Ok(self.history.undo()?.to_string())
+    }
+    pub fn redo(&mut self) -> Result<String, JsError> {
+        Ok(self.history.redo()?.to_string())
+    }
+    pub fn can_undo(&self) -> bool {
+        self.history.can_undo()
+    }
+    pub fn can_redo(&self) -> bool {
+        self.history.can_redo()
+    }
+    pub fn is_normal_form(&self) -> Result<bool, JsError> {
+        Ok(self.history.current()?.is_normal_form(self.strategy))
+    }
+    pub fn history(&self) -> Vec<String> {
+        self.history.to_strings()
+    }
+    pub fn history_index(&self) -> usize {
+        self.history.index()
+    }
+    #[wasm_bindgen(getter)]
+    pub fn strategy(&self) -> ReductionStrategy {
+        self.strategy
+    }
+    #[wasm_bindgen(setter)]
+    pub fn set_strategy(&mut self, strategy: ReductionStrategy) {
+        self.strategy = strategy;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_pcf_expr, PcfWasmInterface};
+    use crate::annotation::StepRule;
+
+    #[test]
+    fn rule_steps() {
+        let mut interface = PcfWasmInterface::new();
+        let strategy = interface.strategy;
+        interface
+            .history
+            .add(parse_pcf_expr("(λx. succ x) (pred 3)").unwrap());
+        let mut rules = vec![];
+        while let Ok((_, rule)) = interface.history.rule_step(strategy) {
+            rules.push(rule);
+        }
+        assert_eq!(rules, [StepRule::Beta, StepRule::Delta, StepRule::Delta]);
+        assert_eq!(interface.current_expr().as_deref(), Some("3"));
+    }
+}
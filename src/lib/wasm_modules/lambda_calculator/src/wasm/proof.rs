@@ -0,0 +1,128 @@
+use super::InterfaceError;
+use crate::parsers::bidirectional_lambda_calculus::BidirectionalLambdaCalculusParser;
+use crate::parsers::typed_lambda_calculus::TypedLambdaCalculusParser;
+use crate::type_checkers::proof::Proof;
+use wasm_bindgen::prelude::*;
+
+/// Proves a proposition by refining a proof term with holes. Propositions are types of
+/// `TypedWasmInterface`, which may also be written with `∧`, `∨`, `⊤`, `⊥` and `¬`.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone)]
+pub struct ProofWasmInterface {
+    proof: Option<Proof>,
+}
+
+impl ProofWasmInterface {
+    fn get_proof(&self) -> Result<&Proof, InterfaceError> {
+        self.proof.as_ref().ok_or(InterfaceError::NoExpression)
+    }
+
+    fn start_proof(&mut self, proposition: &str) -> Result<&Proof, InterfaceError> {
+        let proposition = TypedLambdaCalculusParser::parse_type(proposition)
+            .map_err(|err| InterfaceError::Parse(err.to_string()))?;
+        Ok(self.proof.insert(Proof::new(proposition)))
+    }
+
+    fn refine_proof(&mut self, hole: usize, input: &str) -> Result<&Proof, InterfaceError> {
+        let fragment = BidirectionalLambdaCalculusParser::parse(input)
+            .map_err(|err| InterfaceError::Parse(err.to_string()))?;
+        let proof = self.proof.as_mut().ok_or(InterfaceError::NoExpression)?;
+        proof.refine(hole, &fragment)?;
+        Ok(proof)
+    }
+}
+
+#[wasm_bindgen]
+impl ProofWasmInterface {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts a proof of `proposition`, replacing the current one, and returns the term `?0`.
+    pub fn start(&mut self, proposition: &str) -> Result<String, JsError> {
+        Ok(self.start_proof(proposition)?.term().to_string())
+    }
+    pub fn proposition(&self) -> Option<String> {
+        self.proof
+            .as_ref()
+            .map(|proof| proof.proposition().as_proposition().to_string())
+    }
+    pub fn term(&self) -> Option<String> {
+        self.proof.as_ref().map(|proof| proof.term().to_string())
+    }
+    /// Fills the hole `?hole` with `input`, where `?` marks new holes, and returns the new term.
+    pub fn refine(&mut self, hole: usize, input: &str) -> Result<String, JsError> {
+        Ok(self.refine_proof(hole, input)?.term().to_string())
+    }
+    pub fn undo(&mut self) -> Result<String, JsError> {
+        let proof = self.proof.as_mut().ok_or(InterfaceError::NoExpression)?;
+        if !proof.undo() {
+            return Err(InterfaceError::NothingToUndo.into());
+        }
+        Ok(proof.term().to_string())
+    }
+    /// The open goals as `x : A, y : B ⊢ ?n : C`.
+    pub fn goals(&self) -> Vec<String> {
+        self.proof.as_ref().map_or(vec![], |proof| {
+            proof.goals().iter().map(|goal| goal.to_string()).collect()
+        })
+    }
+    /// The numbers of the holes of `goals`, in the same order.
+    pub fn goal_holes(&self) -> Vec<usize> {
+        self.proof.as_ref().map_or(vec![], |proof| {
+            proof.goals().iter().map(|goal| goal.hole.id()).collect()
+        })
+    }
+    pub fn is_complete(&self) -> bool {
+        self.proof.as_ref().is_some_and(Proof::is_complete)
+    }
+    /// The natural deduction proof as JSON, see `Derivation::to_json`. Open goals are leaves
+    /// with the rule `?`.
+    pub fn derivation_json(&self) -> Result<String, JsError> {
+        Ok(self.get_proof()?.derivation().to_json())
+    }
+    pub fn derivation_latex(&self) -> Result<String, JsError> {
+        Ok(self.get_proof()?.derivation().to_latex())
+    }
+    /// The finished proof as a term of `TypedWasmInterface`.
+    pub fn proof_term(&self) -> Option<String> {
+        self.proof
+            .as_ref()
+            .and_then(Proof::proof_term)
+            .map(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProofWasmInterface;
+    use crate::type_checkers::proof::ProofError;
+    use crate::wasm::InterfaceError;
+
+    #[test]
+    fn refine_until_complete() {
+        let mut interface = ProofWasmInterface::new();
+        assert_eq!(interface.goals(), Vec::<String>::new());
+        assert!(interface.refine_proof(0, "?").is_err());
+        interface.start_proof("(A → B) → A → B").unwrap();
+        assert_eq!(interface.proposition().as_deref(), Some("(A → B) → A → B"));
+        interface.refine_proof(0, "λf x. f ?").unwrap();
+        assert_eq!(interface.term().as_deref(), Some("λ f. λ x. f ?1"));
+        assert_eq!(interface.goal_holes(), vec![1]);
+        assert!(matches!(
+            interface.refine_proof(1, "f"),
+            Err(InterfaceError::Proof(ProofError::Type(_)))
+        ));
+        assert!(matches!(
+            interface.refine_proof(1, "λ"),
+            Err(InterfaceError::Parse(_))
+        ));
+        interface.refine_proof(1, "x").unwrap();
+        assert!(interface.is_complete());
+        assert_eq!(
+            interface.proof_term().as_deref(),
+            Some("λ f: A → B. λ x: A. f x")
+        );
+    }
+}
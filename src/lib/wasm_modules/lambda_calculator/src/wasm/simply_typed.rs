@@ -0,0 +1,125 @@
+use super::history::History;
+use super::InterfaceError;
+use crate::expressions::simply_typed_lambda_calculus::SimplyTypedExpr;
+use crate::expressions::ReductionStrategy;
+use crate::parsers::simply_typed_lambda_calculus::SimplyTypedLambdaCalculusParser;
+use crate::type_checkers::simply_typed_lambda_calculus::SimplyTypedLambdaCalculusChecker;
+use wasm_bindgen::prelude::*;
+
+fn parse_simply_typed_expr(input: &str) -> Result<SimplyTypedExpr, InterfaceError> {
+    SimplyTypedLambdaCalculusParser::parse(input)
+        .map_err(|err| InterfaceError::Parse(err.to_string()))
+}
+
+/// The stepper of `WasmInterface` for the simply typed lambda calculus with base types.
+/// Methods with the same name behave the same.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone)]
+pub struct SimplyTypedWasmInterface {
+    history: History<SimplyTypedExpr>,
+    strategy: ReductionStrategy,
+}
+
+#[wasm_bindgen]
+impl SimplyTypedWasmInterface {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn parse(&self, input: &str) -> Result<String, JsError> {
+        Ok(parse_simply_typed_expr(input)?.to_string())
+    }
+    /// Checks `input` and returns its type.
+    pub fn type_of(&self, input: &str) -> Result<String, JsError> {
+        let e = parse_simply_typed_expr(input)?;
+        Ok(SimplyTypedLambdaCalculusChecker::type_of(&e)
+            .map_err(InterfaceError::from)?
+            .to_string())
+    }
+    /// The typing derivation of `input` as JSON, see `Derivation::to_json`. For ill-typed
+    /// terms it ends at the failing rule.
+    pub fn derivation_json(&self, input: &str) -> Result<String, JsError> {
+        let e = parse_simply_typed_expr(input)?;
+        Ok(SimplyTypedLambdaCalculusChecker::derive(&e).0.to_json())
+    }
+    /// Like `derivation_json`, but as a `bussproofs` proof tree.
+    pub fn derivation_latex(&self, input: &str) -> Result<String, JsError> {
+        let e = parse_simply_typed_expr(input)?;
+        Ok(SimplyTypedLambdaCalculusChecker::derive(&e).0.to_latex())
+    }
+    /// Parses `input` and makes it the current expression. Ill-typed terms are accepted, so
+    /// that students can watch them get stuck.
+    pub fn set_expr(&mut self, input: &str) -> Result<String, JsError> {
+        let e = parse_simply_typed_expr(input)?;
+        Ok(self.history.add(e).to_string())
+    }
+    pub fn current_expr(&self) -> Option<String> {
+        self.history.current().ok().map(|e| e.to_string())
+    }
+    pub fn step(&mut self) -> Result<String, JsError> {
+        Ok(self.history.step(self.strategy)?.to_string())
+    }
+    pub fn normalize(&mut self, max_steps: u32) -> Result<String, JsError> {
+        Ok(self
+            .history
+            .normalize(self.strategy, max_steps)?
+            .to_string())
+    }
+    pub fn undo(&mut self) -> Result<String, JsError> {
+        Ok(self.history.undo()?.to_string())
+    }
+    pub fn redo(&mut self) -> Result<String, JsError> {
+        Ok(self.history.redo()?.to_string())
+    }
+    pub fn can_undo(&self) -> bool {
+        self.history.can_undo()
+    }
+    pub fn can_redo(&self) -> bool {
+        self.history.can_redo()
+    }
+    pub fn is_normal_form(&self) -> Result<bool, JsError> {
+        Ok(self.history.current()?.is_normal_form(self.strategy))
+    }
+    pub fn history(&self) -> Vec<String> {
+        self.history.to_strings()
+    }
+    pub fn history_index(&self) -> usize {
+        self.history.index()
+    }
+    #[wasm_bindgen(getter)]
+    pub fn strategy(&self) -> ReductionStrategy {
+        self.strategy
+    }
+    #[wasm_bindgen(setter)]
+    pub fn set_strategy(&mut self, strategy: ReductionStrategy) {
+        self.strategy = strategy;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_simply_typed_expr, SimplyTypedWasmInterface};
+    use crate::wasm::InterfaceError;
+
+    #[test]
+    fn normalize() {
+        let mut interface = SimplyTypedWasmInterface::new();
+        let strategy = interface.strategy;
+        interface
+            .history
+            .add(parse_simply_typed_expr("(λf: A → A. λx: A. f (f x)) (λy: A. y) a").unwrap());
+        assert_eq!(
+            interface
+                .history
+                .normalize(strategy, 10)
+                .unwrap()
+                .to_string(),
+            "a"
+        );
+        assert_eq!(
+            interface.history.step(strategy),
+            Err(InterfaceError::NoRedex)
+        );
+    }
+}
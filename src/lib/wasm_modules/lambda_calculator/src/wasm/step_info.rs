@@ -0,0 +1,102 @@
+use crate::annotation::{StepAnnotation, StepRule};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct StepInfo {
+    expr: String,
+    annotation: StepAnnotation,
+}
+
+impl StepInfo {
+    pub(super) fn new(expr: String, annotation: StepAnnotation) -> Self {
+        Self { expr, annotation }
+    }
+}
+
+// Paths are strings with one letter per step: `l`/`r` for the sides of an application and
+// `b` for the body of an abstraction, the empty string is the whole term.
+#[wasm_bindgen]
+impl StepInfo {
+    /// The expression after the step.
+    #[wasm_bindgen(getter)]
+    pub fn expr(&self) -> String {
+        self.expr.clone()
+    }
+    #[wasm_bindgen(getter)]
+    pub fn rule(&self) -> String {
+        self.annotation.rule.to_string()
+    }
+    /// Path of the contracted redex in the expression before the step.
+    #[wasm_bindgen(getter)]
+    pub fn redex_path(&self) -> String {
+        self.annotation.redex.to_string()
+    }
+    #[wasm_bindgen(getter)]
+    pub fn bound_var(&self) -> String {
+        self.annotation.bound_var.to_string()
+    }
+    #[wasm_bindgen(getter)]
+    pub fn argument(&self) -> String {
+        self.annotation.argument.to_string()
+    }
+    /// Paths in the new expression where a copy of the argument was inserted.
+    pub fn argument_copies(&self) -> Vec<String> {
+        self.annotation
+            .argument_copies
+            .iter()
+            .map(|p| p.to_string())
+            .collect()
+    }
+    /// Original names of the binders renamed to avoid capture, in the order they were renamed.
+    pub fn renamed_from(&self) -> Vec<String> {
+        self.annotation
+            .renamings
+            .iter()
+            .map(|r| r.from.to_string())
+            .collect()
+    }
+    /// New names of the renamed binders, parallel to `renamed_from`.
+    pub fn renamed_to(&self) -> Vec<String> {
+        self.annotation
+            .renamings
+            .iter()
+            .map(|r| r.to.to_string())
+            .collect()
+    }
+    /// Paths of the renamed binders in the new expression, parallel to `renamed_from`.
+    pub fn renamed_at(&self) -> Vec<String> {
+        self.annotation
+            .renamings
+            .iter()
+            .map(|r| r.binder.to_string())
+            .collect()
+    }
+}
+
+/// A step of a calculus whose steps only report their rule, see `RuleStep`.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct RuleStepInfo {
+    expr: String,
+    rule: StepRule,
+}
+
+impl RuleStepInfo {
+    pub(super) fn new(expr: String, rule: StepRule) -> Self {
+        Self { expr, rule }
+    }
+}
+
+#[wasm_bindgen]
+impl RuleStepInfo {
+    /// The expression after the step.
+    #[wasm_bindgen(getter)]
+    pub fn expr(&self) -> String {
+        self.expr.clone()
+    }
+    #[wasm_bindgen(getter)]
+    pub fn rule(&self) -> String {
+        self.rule.to_string()
+    }
+}
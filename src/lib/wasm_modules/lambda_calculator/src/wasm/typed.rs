@@ -0,0 +1,165 @@
+use super::history::History;
+use super::{parse_expr, InterfaceError};
+use crate::expressions::typed_lambda_calculus::TypedLamExpr;
+use crate::expressions::ReductionStrategy;
+use crate::parsers::bidirectional_lambda_calculus::BidirectionalLambdaCalculusParser;
+use crate::parsers::typed_lambda_calculus::TypedLambdaCalculusParser;
+use crate::type_checkers::bidirectional_lambda_calculus::BidirectionalLambdaCalculusChecker;
+use crate::type_checkers::typed_lambda_calculus::TypedLambdaCalculusChecker;
+use wasm_bindgen::prelude::*;
+
+fn parse_typed_expr(input: &str) -> Result<TypedLamExpr, InterfaceError> {
+    TypedLambdaCalculusParser::parse(input).map_err(|err| InterfaceError::Parse(err.to_string()))
+}
+
+/// The stepper of `WasmInterface` for the simply typed lambda calculus with products, sums and
+/// recursive types. Methods with the same name behave the same, so the frontend can switch
+/// between the two.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone)]
+pub struct TypedWasmInterface {
+    history: History<TypedLamExpr>,
+    strategy: ReductionStrategy,
+}
+
+#[wasm_bindgen]
+impl TypedWasmInterface {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn parse(&self, input: &str) -> Result<String, JsError> {
+        Ok(parse_typed_expr(input)?.to_string())
+    }
+    /// Checks `input` and returns its type.
+    pub fn type_of(&self, input: &str) -> Result<String, JsError> {
+        let e = parse_typed_expr(input)?;
+        Ok(TypedLambdaCalculusChecker::type_of(&e)
+            .map_err(InterfaceError::from)?
+            .to_string())
+    }
+    /// The typing derivation of `input` as JSON, see `Derivation::to_json`. For ill-typed
+    /// terms it ends at the failing rule.
+    pub fn derivation_json(&self, input: &str) -> Result<String, JsError> {
+        let e = parse_typed_expr(input)?;
+        Ok(TypedLambdaCalculusChecker::derive(&e).0.to_json())
+    }
+    /// Like `derivation_json`, but as a `bussproofs` proof tree.
+    pub fn derivation_latex(&self, input: &str) -> Result<String, JsError> {
+        let e = parse_typed_expr(input)?;
+        Ok(TypedLambdaCalculusChecker::derive(&e).0.to_latex())
+    }
+    /// Checks `input`, where only abstractions in function position need their types, and
+    /// returns it with all abstractions annotated.
+    pub fn elaborate(&self, input: &str) -> Result<String, JsError> {
+        let e = BidirectionalLambdaCalculusParser::parse(input)
+            .map_err(|err| InterfaceError::Parse(err.to_string()))?;
+        let (e, _) =
+            BidirectionalLambdaCalculusChecker::synthesize(&e).map_err(InterfaceError::from)?;
+        Ok(e.to_string())
+    }
+    /// Translates the untyped term `input` into this calculus, typed with `μα. α → α`.
+    pub fn embed_untyped(&self, input: &str) -> Result<String, JsError> {
+        Ok(TypedLamExpr::embed(&parse_expr(input)?).to_string())
+    }
+    /// Parses `input` and makes it the current expression. Ill-typed terms are accepted, so
+    /// that students can watch them get stuck.
+    pub fn set_expr(&mut self, input: &str) -> Result<String, JsError> {
+        let e = parse_typed_expr(input)?;
+        Ok(self.history.add(e).to_string())
+    }
+    pub fn current_expr(&self) -> Option<String> {
+        self.history.current().ok().map(|e| e.to_string())
+    }
+    pub fn step(&mut self) -> Result<String, JsError> {
+        Ok(self.history.step(self.strategy)?.to_string())
+    }
+    pub fn normalize(&mut self, max_steps: u32) -> Result<String, JsError> {
+        Ok(self
+            .history
+            .normalize(self.strategy, max_steps)?
+            .to_string())
+    }
+    pub fn undo(&mut self) -> Result<String, JsError> {
+        Ok(self.history.undo()?.to_string())
+    }
+    pub fn redo(&mut self) -> Result<String, JsError> {
+        Ok(self.history.redo()?.to_string())
+    }
+    pub fn can_undo(&self) -> bool {
+        self.history.can_undo()
+    }
+    pub fn can_redo(&self) -> bool {
+        self.history.can_redo()
+    }
+    pub fn is_normal_form(&self) -> Result<bool, JsError> {
+        Ok(self.history.current()?.is_normal_form(self.strategy))
+    }
+    pub fn history(&self) -> Vec<String> {
+        self.history.to_strings()
+    }
+    pub fn history_index(&self) -> usize {
+        self.history.index()
+    }
+    #[wasm_bindgen(getter)]
+    pub fn strategy(&self) -> ReductionStrategy {
+        self.strategy
+    }
+    #[wasm_bindgen(setter)]
+    pub fn set_strategy(&mut self, strategy: ReductionStrategy) {
+        self.strategy = strategy;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_typed_expr, TypedWasmInterface};
+    use crate::wasm::InterfaceError;
+
+    #[test]
+    fn step_undo_redo() {
+        let mut interface = TypedWasmInterface::new();
+        let strategy = interface.strategy;
+        assert_eq!(interface.current_expr(), None);
+        interface
+            .history
+            .add(parse_typed_expr("fst ((λx: A. x) a, b)").unwrap());
+        assert_eq!(
+            interface.history.step(strategy).unwrap().to_string(),
+            "(λ x: A. x) a"
+        );
+        assert_eq!(interface.history.step(strategy).unwrap().to_string(), "a");
+        assert_eq!(
+            interface.history.step(strategy),
+            Err(InterfaceError::NoRedex)
+        );
+        interface.history.undo().unwrap();
+        assert_eq!(interface.current_expr().as_deref(), Some("(λ x: A. x) a"));
+        interface.history.add(parse_typed_expr("()").unwrap());
+        assert!(!interface.can_redo());
+        assert_eq!(
+            interface.history(),
+            vec!["fst ((λ x: A. x) a, b)", "(λ x: A. x) a", "()"]
+        );
+    }
+
+    #[test]
+    fn normalize() {
+        let mut interface = TypedWasmInterface::new();
+        let strategy = interface.strategy;
+        interface.history.add(
+            parse_typed_expr("case inl () as Unit + A of inl u ⇒ (u, u) | inr a ⇒ ((), ())")
+                .unwrap(),
+        );
+        assert_eq!(
+            interface
+                .history
+                .normalize(strategy, 10)
+                .unwrap()
+                .to_string(),
+            "((), ())"
+        );
+        assert_eq!(interface.history().len(), 2);
+    }
+}
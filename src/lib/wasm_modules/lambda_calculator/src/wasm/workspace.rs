@@ -0,0 +1,438 @@
+use super::history::History;
+use super::machine::{MachineSession, MachineState};
+use super::InterfaceError;
+use crate::annotation::{self, StepAnnotation};
+use crate::evaluation::{Evaluation, EvaluationProgress, EvaluationState};
+use crate::expressions::untyped_lambda_calculus::UntyLamExpr;
+use crate::expressions::ReductionStrategy;
+use crate::machines::AbstractMachine;
+use crate::share;
+use crate::statistics::ReductionStats;
+use crate::usage::{Discipline, UsageReport};
+
+#[derive(Debug, Default, Clone)]
+pub struct Workspace {
+    pub(super) exprs: History<UntyLamExpr>,
+    pub(super) strategy: ReductionStrategy,
+    pub(super) evaluation: Option<Evaluation>,
+    pub(super) stats: ReductionStats,
+    /// Refuses to reduce terms that aren't linear.
+    pub(super) linear: bool,
+    pub(super) machine: Option<MachineSession>,
+}
+
+impl Workspace {
+    pub(super) fn get_current_expr(&self) -> Result<&UntyLamExpr, InterfaceError> {
+        self.exprs.current()
+    }
+
+    /// The current expression, as long as it may be reduced in this workspace's mode.
+    fn get_reducible_expr(&self) -> Result<&UntyLamExpr, InterfaceError> {
+        let e = self.get_current_expr()?;
+        self.check_mode(e)?;
+        Ok(e)
+    }
+
+    /// Whether `e` may be entered and reduced in this workspace's mode.
+    pub(super) fn check_mode(&self, e: &UntyLamExpr) -> Result<(), InterfaceError> {
+        if self.linear {
+            let violations = UsageReport::new(e).violations(Discipline::Linear);
+            if !violations.is_empty() {
+                return Err(InterfaceError::NotLinear(violations));
+            }
+        }
+        Ok(())
+    }
+
+    // A running evaluation belongs to the expression it started from, so every change of the
+    // current expression drops it.
+    pub(super) fn add_current_expr(&mut self, e: UntyLamExpr) {
+        self.evaluation = None;
+        self.exprs.add(e);
+    }
+
+    /// Adds an expression that doesn't come from reducing the current one, which starts
+    /// a new session for the statistics.
+    pub(super) fn add_new_expr(&mut self, e: UntyLamExpr) {
+        self.stats = ReductionStats::new(&e);
+        self.add_current_expr(e);
+    }
+
+    pub(super) fn add_step_expr(&mut self) -> Result<&UntyLamExpr, InterfaceError> {
+        Ok(self.add_annotated_step_expr()?.0)
+    }
+
+    pub(super) fn add_annotated_step_expr(
+        &mut self,
+    ) -> Result<(&UntyLamExpr, StepAnnotation), InterfaceError> {
+        let (e, annotation) =
+            annotation::annotated_step(self.get_reducible_expr()?, self.strategy)?;
+        self.stats.record(&e, &annotation);
+        self.add_current_expr(e);
+        Ok((self.get_current_expr()?, annotation))
+    }
+
+    // η-steps aren't counted in the statistics, which measure β-reduction.
+    pub(super) fn add_eta_step_expr(
+        &mut self,
+    ) -> Result<(&UntyLamExpr, StepAnnotation), InterfaceError> {
+        let (e, annotation) = annotation::annotated_eta_step(self.get_reducible_expr()?)?;
+        self.add_current_expr(e);
+        Ok((self.get_current_expr()?, annotation))
+    }
+
+    pub(super) fn add_normalized_expr(
+        &mut self,
+        max_steps: u32,
+    ) -> Result<&UntyLamExpr, InterfaceError> {
+        let mut evaluation = Evaluation::new(self.get_reducible_expr()?.clone(), self.strategy);
+        match evaluation.run(max_steps) {
+            EvaluationState::Finished => {
+                self.add_evaluated_expr(evaluation);
+                self.get_current_expr()
+            }
+            _ => Err(InterfaceError::StepLimit(max_steps)),
+        }
+    }
+
+    fn add_evaluated_expr(&mut self, evaluation: Evaluation) {
+        self.stats.merge(evaluation.stats());
+        if evaluation.steps() > 0 {
+            self.add_current_expr(evaluation.into_expr());
+        }
+    }
+
+    pub(super) fn start_evaluation(&mut self) -> Result<EvaluationProgress, InterfaceError> {
+        let evaluation = Evaluation::new(self.get_reducible_expr()?.clone(), self.strategy);
+        let progress = evaluation.progress();
+        self.evaluation = Some(evaluation);
+        Ok(progress)
+    }
+
+    pub(super) fn run_evaluation(
+        &mut self,
+        budget: u32,
+    ) -> Result<EvaluationProgress, InterfaceError> {
+        let evaluation = self
+            .evaluation
+            .as_mut()
+            .ok_or(InterfaceError::NoEvaluation)?;
+        evaluation.run(budget);
+        let progress = evaluation.progress();
+        if !progress.more_work {
+            let evaluation = self.evaluation.take().expect("checked above");
+            self.add_evaluated_expr(evaluation);
+        }
+        Ok(progress)
+    }
+
+    pub(super) fn cancel_evaluation(&mut self) -> Result<&UntyLamExpr, InterfaceError> {
+        let mut evaluation = self.evaluation.take().ok_or(InterfaceError::NoEvaluation)?;
+        evaluation.cancel();
+        self.add_evaluated_expr(evaluation);
+        self.get_current_expr()
+    }
+
+    pub(super) fn start_machine(
+        &mut self,
+        machine: AbstractMachine,
+    ) -> Result<MachineState, InterfaceError> {
+        let session = MachineSession::new(machine, self.get_reducible_expr()?.clone());
+        Ok(self.machine.insert(session).state())
+    }
+
+    pub(super) fn machine_mut(&mut self) -> Result<&mut MachineSession, InterfaceError> {
+        self.machine.as_mut().ok_or(InterfaceError::NoMachine)
+    }
+
+    pub(super) fn undo_expr(&mut self) -> Result<&UntyLamExpr, InterfaceError> {
+        self.exprs.undo()?;
+        self.evaluation = None;
+        self.get_current_expr()
+    }
+
+    pub(super) fn redo_expr(&mut self) -> Result<&UntyLamExpr, InterfaceError> {
+        self.exprs.redo()?;
+        self.evaluation = None;
+        self.get_current_expr()
+    }
+
+    pub(super) fn add_shared_expr(&mut self, s: &str) -> Result<&UntyLamExpr, InterfaceError> {
+        let session = share::decode_session(s)?;
+        self.check_mode(&session.expr)?;
+        if let Some(strategy) = session.strategy {
+            self.strategy = strategy;
+        }
+        self.add_new_expr(session.expr);
+        for _ in 0..session.step.unwrap_or(0) {
+            match self.add_step_expr() {
+                Ok(_) => {}
+                Err(InterfaceError::NoRedex) => break,
+                Err(err) => return Err(err),
+            }
+        }
+        self.get_current_expr()
+    }
+}
+
+impl Workspace {
+    pub(super) fn can_undo(&self) -> bool {
+        self.exprs.can_undo()
+    }
+
+    pub(super) fn can_redo(&self) -> bool {
+        self.exprs.can_redo()
+    }
+
+    pub(super) fn history(&self) -> Vec<String> {
+        self.exprs.to_strings()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{parse_expr, InterfaceError};
+    use super::Workspace;
+    use crate::annotation::StepRule;
+    use crate::expressions::ReductionStrategy;
+    use crate::machines::AbstractMachine;
+    use crate::share;
+
+    fn workspace_with(input: &str) -> Workspace {
+        let mut workspace = Workspace::default();
+        workspace.add_current_expr(parse_expr(input).unwrap());
+        workspace
+    }
+
+    #[test]
+    fn step_undo_redo() {
+        let mut workspace = workspace_with("(λx. x) ((λy. y) z)");
+        assert_eq!(workspace.add_step_expr().unwrap().to_string(), "(λ y. y) z");
+        assert_eq!(workspace.add_step_expr().unwrap().to_string(), "z");
+        assert_eq!(workspace.add_step_expr(), Err(InterfaceError::NoRedex));
+        assert_eq!(workspace.undo_expr().unwrap().to_string(), "(λ y. y) z");
+        assert_eq!(
+            workspace.undo_expr().unwrap().to_string(),
+            "(λ x. x) ((λ y. y) z)"
+        );
+        assert_eq!(workspace.undo_expr(), Err(InterfaceError::NothingToUndo));
+        assert_eq!(workspace.redo_expr().unwrap().to_string(), "(λ y. y) z");
+        assert_eq!(workspace.redo_expr().unwrap().to_string(), "z");
+        assert_eq!(workspace.redo_expr(), Err(InterfaceError::NothingToRedo));
+        assert_eq!(workspace.history().len(), 3);
+    }
+
+    #[test]
+    fn annotated_step() {
+        let mut workspace = workspace_with("(λx. x x) a");
+        let (e, annotation) = workspace.add_annotated_step_expr().unwrap();
+        assert_eq!(e.to_string(), "a a");
+        assert_eq!(annotation.argument_copies.len(), 2);
+        assert_eq!(workspace.history().len(), 2);
+        assert!(matches!(
+            workspace.add_annotated_step_expr(),
+            Err(InterfaceError::NoRedex)
+        ));
+    }
+
+    #[test]
+    fn eta_step() {
+        let mut workspace = workspace_with("λx. (λy. f y) x");
+        let (e, annotation) = workspace.add_eta_step_expr().unwrap();
+        assert_eq!(e.to_string(), "λ y. f y");
+        assert_eq!(annotation.rule, StepRule::Eta);
+        assert_eq!(workspace.add_eta_step_expr().unwrap().0.to_string(), "f");
+        assert_eq!(workspace.history().len(), 3);
+        assert_eq!(workspace.stats.beta_steps, 0);
+        assert!(matches!(
+            workspace.add_eta_step_expr(),
+            Err(InterfaceError::NoRedex)
+        ));
+    }
+
+    #[test]
+    fn statistics() {
+        let mut workspace = Workspace::default();
+        workspace.add_new_expr(parse_expr("(λx. x x) ((λy. y) a)").unwrap());
+        workspace.add_step_expr().unwrap();
+        workspace.add_normalized_expr(10).unwrap();
+        assert_eq!(workspace.stats.beta_steps, 3);
+        assert_eq!(workspace.stats.substitutions, 4);
+        workspace.undo_expr().unwrap();
+        assert_eq!(workspace.stats.beta_steps, 3);
+        workspace.add_new_expr(parse_expr("a").unwrap());
+        assert_eq!(workspace.stats.beta_steps, 0);
+        assert_eq!(workspace.stats.peak_size, 1);
+    }
+
+    #[test]
+    fn normalize() {
+        let mut workspace = workspace_with("(λf x. f (f x)) (λy. y) a");
+        assert_eq!(workspace.add_normalized_expr(100).unwrap().to_string(), "a");
+        assert_eq!(workspace.history().len(), 2);
+
+        let mut workspace = workspace_with("(λx. x x) (λx. x x)");
+        assert_eq!(
+            workspace.add_normalized_expr(100),
+            Err(InterfaceError::StepLimit(100))
+        );
+        assert_eq!(workspace.history().len(), 1);
+
+        let mut workspace = Workspace::default();
+        assert_eq!(
+            workspace.add_normalized_expr(100),
+            Err(InterfaceError::NoExpression)
+        );
+    }
+
+    #[test]
+    fn chunked_evaluation() {
+        let mut workspace = workspace_with("(λf x. f (f (f x))) (λy. y) a");
+        assert_eq!(
+            workspace.run_evaluation(1),
+            Err(InterfaceError::NoEvaluation)
+        );
+        workspace.start_evaluation().unwrap();
+        let progress = workspace.run_evaluation(3).unwrap();
+        assert!(progress.more_work);
+        assert_eq!(progress.steps, 3);
+        assert_eq!(workspace.history().len(), 1);
+        let progress = workspace.run_evaluation(3).unwrap();
+        assert!(!progress.more_work);
+        assert_eq!(progress.expr, "a");
+        assert_eq!(
+            workspace.history(),
+            vec!["(λ f. λ x. f (f (f x))) (λ y. y) a", "a"]
+        );
+        assert_eq!(
+            workspace.run_evaluation(1),
+            Err(InterfaceError::NoEvaluation)
+        );
+
+        let mut workspace = workspace_with("(λx. x x) (λx. x x) a");
+        workspace.start_evaluation().unwrap();
+        assert!(workspace.run_evaluation(50).unwrap().more_work);
+        assert_eq!(
+            workspace.cancel_evaluation().unwrap().to_string(),
+            "(λ x. x x) (λ x. x x) a"
+        );
+        assert_eq!(workspace.history().len(), 2);
+        assert_eq!(
+            workspace.cancel_evaluation(),
+            Err(InterfaceError::NoEvaluation)
+        );
+    }
+
+    #[test]
+    fn history_changes_drop_the_evaluation() {
+        let mut workspace = workspace_with("(λf x. f (f (f x))) (λy. y) a");
+        workspace.start_evaluation().unwrap();
+        workspace.run_evaluation(1).unwrap();
+        workspace.add_new_expr(parse_expr("y").unwrap());
+        assert_eq!(
+            workspace.run_evaluation(10),
+            Err(InterfaceError::NoEvaluation)
+        );
+        assert_eq!(workspace.history().last().unwrap(), "y");
+
+        workspace.undo_expr().unwrap();
+        workspace.start_evaluation().unwrap();
+        workspace.redo_expr().unwrap();
+        workspace.undo_expr().unwrap();
+        assert_eq!(
+            workspace.cancel_evaluation(),
+            Err(InterfaceError::NoEvaluation)
+        );
+        assert_eq!(workspace.history().len(), 2);
+        assert!(workspace.can_redo());
+    }
+
+    #[test]
+    fn linear_mode() {
+        let mut workspace = workspace_with("(λx. x x) a");
+        workspace.linear = true;
+        assert!(matches!(
+            workspace.add_step_expr(),
+            Err(InterfaceError::NotLinear(v)) if v.len() == 1
+        ));
+        assert!(matches!(
+            workspace.start_evaluation(),
+            Err(InterfaceError::NotLinear(_))
+        ));
+        assert_eq!(workspace.history().len(), 1);
+
+        let mut workspace = workspace_with("(λf x. f x) (λy. y) a");
+        workspace.linear = true;
+        assert_eq!(workspace.add_normalized_expr(10).unwrap().to_string(), "a");
+
+        // shared terms are checked before they enter the history or change the strategy
+        let s = share::encode_session(
+            &parse_expr("λx. x x").unwrap(),
+            Some((ReductionStrategy::CallByValue, None)),
+        )
+        .unwrap();
+        assert!(matches!(
+            workspace.add_shared_expr(&s),
+            Err(InterfaceError::NotLinear(_))
+        ));
+        assert_eq!(workspace.strategy, ReductionStrategy::NormalOrder);
+        assert_eq!(workspace.get_current_expr().unwrap().to_string(), "a");
+    }
+
+    #[test]
+    fn share_string() {
+        let mut workspace = workspace_with("(λx. x) ((λy. y) z)");
+        let s = share::encode_session(
+            workspace.get_current_expr().unwrap(),
+            Some((ReductionStrategy::CallByValue, Some(1))),
+        )
+        .unwrap();
+        let mut receiver = Workspace::default();
+        assert_eq!(
+            receiver.add_shared_expr(&s).unwrap().to_string(),
+            "(λ x. x) z"
+        );
+        assert_eq!(receiver.strategy, ReductionStrategy::CallByValue);
+        assert_eq!(receiver.history().len(), 2);
+        // the step is replayed with the shared strategy, whatever the receiver had selected
+        for strategy in (0..4).filter_map(ReductionStrategy::from_u8) {
+            let s = share::encode_session(
+                workspace.get_current_expr().unwrap(),
+                Some((strategy, Some(2))),
+            )
+            .unwrap();
+            let mut receiver = Workspace {
+                strategy: ReductionStrategy::CallByName,
+                ..Workspace::default()
+            };
+            assert_eq!(receiver.add_shared_expr(&s).unwrap().to_string(), "z");
+            assert_eq!(receiver.strategy, strategy);
+        }
+        // replaying stops at the normal form instead of trying every remaining step
+        let s = share::encode_session(
+            workspace.get_current_expr().unwrap(),
+            Some((ReductionStrategy::NormalOrder, Some(share::MAX_STEP))),
+        )
+        .unwrap();
+        let mut receiver = Workspace::default();
+        assert_eq!(receiver.add_shared_expr(&s).unwrap().to_string(), "z");
+        assert_eq!(receiver.history().len(), 3);
+        assert!(matches!(
+            workspace.add_shared_expr("!"),
+            Err(InterfaceError::Share(_))
+        ));
+    }
+
+    #[test]
+    fn machine_is_separate_from_the_history() {
+        let mut workspace = Workspace::default();
+        assert!(matches!(
+            workspace.machine_mut(),
+            Err(InterfaceError::NoMachine)
+        ));
+        workspace.add_current_expr(parse_expr("(λx. x) y").unwrap());
+        workspace.start_machine(AbstractMachine::Krivine).unwrap();
+        assert!(workspace.machine_mut().unwrap().run(10).is_final());
+        assert_eq!(workspace.history().len(), 1);
+    }
+}
@@ -0,0 +1,1273 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+* @returns {WasmInterface}
+*/
+export function init(): WasmInterface;
+/**
+* @returns {number}
+*/
+export function api_version(): number;
+/**
+*/
+export enum AbstractMachine {
+/**
+* Call-by-name evaluation to weak head normal form.
+*/
+  Krivine = 0,
+/**
+* Landin's machine for call-by-value evaluation of compiled terms.
+*/
+  Secd = 1,
+}
+/**
+* How `λx. M` is compiled into combinators, written `[x] M` below.
+*/
+export enum AbstractionScheme {
+/**
+* `[x] x = I`, `[x] y = K y` and `[x] (M N) = S ([x] M) ([x] N)`. The output grows
+* exponentially with the nesting of abstractions.
+*/
+  Naive = 0,
+/**
+* Avoids `S` where `x` occurs on one side only, using `K`, `B`, `C` and η.
+*/
+  Turner = 1,
+/**
+* Like `Turner`, but with `W` for duplication and without `S` and `I`, so
+* `[x] (M x) = W ([x] M)` where `x` occurs in `M`.
+*/
+  Bckw = 2,
+/**
+* `S`, `K` and `I` with the `K` and η rules of `Turner`, then written in terms of `ι`.
+*/
+  Iota = 3,
+}
+/**
+*/
+export enum CostModel {
+/**
+* Every β-step costs 1, the usual textbook measure.
+*/
+  Unit = 0,
+/**
+* A β-step costs the size of the term it produces, what a stepper that rebuilds the
+* whole term pays.
+*/
+  TermSize = 1,
+/**
+* A β-step costs 1 plus the size of every copy of the argument it substitutes.
+*/
+  SubstitutionSize = 2,
+}
+/**
+* How often a bound variable may be used in its scope.
+*/
+export enum Discipline {
+/**
+* Exactly once.
+*/
+  Linear = 0,
+/**
+* At most once, so arguments may be discarded but never copied.
+*/
+  Affine = 1,
+/**
+* At least once, so arguments may be copied but never discarded.
+*/
+  Relevant = 2,
+  Unrestricted = 3,
+}
+/**
+*/
+export enum LambdaCube {
+/**
+* λ→, terms depending on terms only.
+*/
+  SimplyTyped = 0,
+/**
+* λ2, adds terms depending on types.
+*/
+  SecondOrder = 1,
+/**
+* λP, adds types depending on terms.
+*/
+  DependentTypes = 2,
+/**
+* λω̲, adds types depending on types.
+*/
+  TypeOperators = 3,
+/**
+* λP2
+*/
+  DependentSecondOrder = 4,
+/**
+* λω, also known as System Fω.
+*/
+  HigherOrder = 5,
+/**
+* λPω̲
+*/
+  DependentTypeOperators = 6,
+/**
+* λC, the calculus of constructions.
+*/
+  Constructions = 7,
+}
+/**
+*/
+export enum ReductionStrategy {
+/**
+* Leftmost outermost redex first, also under abstractions.
+*/
+  NormalOrder = 0,
+/**
+* Leftmost innermost redex first, also under abstractions.
+*/
+  ApplicativeOrder = 1,
+/**
+* Leftmost outermost redex first, but never under abstractions or in arguments.
+*/
+  CallByName = 2,
+/**
+* Arguments are reduced to values before they are passed, never under abstractions.
+*/
+  CallByValue = 3,
+}
+/**
+* The stepper of `WasmInterface` for combinatory logic with `S`, `K`, `I`, `B`, `C` and `W`.
+* Methods with the same name behave the same. Workspaces, chunked evaluation, statistics,
+* share strings and machines are only available for lambda terms; `translate` and
+* `to_lambda` move a term between the two interfaces.
+*/
+export class CombinatorWasmInterface {
+  free(): void;
+/**
+* @returns {string | undefined}
+*/
+  current_expr(): string | undefined;
+/**
+* @returns {number}
+*/
+  history_index(): number;
+/**
+* @returns {boolean}
+*/
+  is_normal_form(): boolean;
+/**
+*/
+  constructor();
+/**
+* @returns {string}
+*/
+  redo(): string;
+/**
+* @returns {string}
+*/
+  step(): string;
+/**
+* @returns {string}
+*/
+  undo(): string;
+/**
+* @param {string} input
+* @returns {string}
+*/
+  parse(input: string): string;
+/**
+* @returns {(string)[]}
+*/
+  history(): (string)[];
+/**
+* @returns {boolean}
+*/
+  can_redo(): boolean;
+/**
+* @returns {boolean}
+*/
+  can_undo(): boolean;
+/**
+* @param {string} input
+* @returns {string}
+*/
+  set_expr(input: string): string;
+/**
+* @param {number} max_steps
+* @returns {string}
+*/
+  normalize(max_steps: number): string;
+/**
+* The lambda term the combinator expression `input` stands for.
+* @param {string} input
+* @returns {string}
+*/
+  to_lambda(input: string): string;
+/**
+* Compiles the lambda term `input` with `scheme`, checking the normal form with at most
+* `max_steps` steps on each side.
+* @param {string} input
+* @param {AbstractionScheme} scheme
+* @param {number} max_steps
+* @returns {Translation}
+*/
+  translate(input: string, scheme: AbstractionScheme, max_steps: number): Translation;
+/**
+*/
+  strategy: ReductionStrategy;
+}
+/**
+*/
+export class EvaluationProgress {
+  free(): void;
+/**
+*/
+  expr: string;
+/**
+* `false` once a normal form is reached or the evaluation was cancelled.
+*/
+  more_work: boolean;
+/**
+* Steps taken since the evaluation was started.
+*/
+  steps: number;
+}
+/**
+*/
+export class MachineState {
+  free(): void;
+/**
+* @returns {(string)[]}
+*/
+  register_names(): (string)[];
+/**
+* Contents of the registers, parallel to `register_names`.
+* @returns {(string)[]}
+*/
+  register_values(): (string)[];
+/**
+*/
+  readonly is_final: boolean;
+/**
+* The rule of the transition into this state, `undefined` in the initial state.
+*/
+  readonly rule: string | undefined;
+/**
+*/
+  readonly steps: number;
+/**
+* The term the state stands for, if the machine can read it back.
+*/
+  readonly term: string | undefined;
+}
+/**
+* The stepper of `WasmInterface` for PCF. Methods with the same name behave the same.
+*/
+export class PcfWasmInterface {
+  free(): void;
+/**
+* @returns {string | undefined}
+*/
+  current_expr(): string | undefined;
+/**
+* @returns {number}
+*/
+  history_index(): number;
+/**
+* @returns {boolean}
+*/
+  is_normal_form(): boolean;
+/**
+* The typing derivation of `input` as JSON, see `Derivation::to_json`. For ill-typed
+* terms it ends at the failing rule.
+* @param {string} input
+* @returns {string}
+*/
+  derivation_json(input: string): string;
+/**
+* Like `derivation_json`, but as a `bussproofs` proof tree.
+* @param {string} input
+* @returns {string}
+*/
+  derivation_latex(input: string): string;
+/**
+*/
+  constructor();
+/**
+* @returns {string}
+*/
+  redo(): string;
+/**
+* @returns {string}
+*/
+  step(): string;
+/**
+* @returns {string}
+*/
+  undo(): string;
+/**
+* @param {string} input
+* @returns {string}
+*/
+  parse(input: string): string;
+/**
+* @returns {(string)[]}
+*/
+  history(): (string)[];
+/**
+* Infers the type of `input`, with the types its free variables need to have.
+* @param {string} input
+* @returns {string}
+*/
+  type_of(input: string): string;
+/**
+* @returns {boolean}
+*/
+  can_redo(): boolean;
+/**
+* @returns {boolean}
+*/
+  can_undo(): boolean;
+/**
+* Parses `input` and makes it the current expression. Ill-typed terms are accepted, so
+* that students can watch them get stuck.
+* @param {string} input
+* @returns {string}
+*/
+  set_expr(input: string): string;
+/**
+* @param {number} max_steps
+* @returns {string}
+*/
+  normalize(max_steps: number): string;
+/**
+* Like `step`, but also tells whether it was a β-, δ- or `fix` step.
+* @returns {RuleStepInfo}
+*/
+  rule_step(): RuleStepInfo;
+/**
+*/
+  strategy: ReductionStrategy;
+}
+/**
+* Proves a proposition by refining a proof term with holes. Propositions are types of
+* `TypedWasmInterface`, which may also be written with `∧`, `∨`, `⊤`, `⊥` and `¬`.
+*/
+export class ProofWasmInterface {
+  free(): void;
+/**
+* The numbers of the holes of `goals`, in the same order.
+* @returns {Uint32Array}
+*/
+  goal_holes(): Uint32Array;
+/**
+* The finished proof as a term of `TypedWasmInterface`.
+* @returns {string | undefined}
+*/
+  proof_term(): string | undefined;
+/**
+* @returns {boolean}
+*/
+  is_complete(): boolean;
+/**
+* @returns {string | undefined}
+*/
+  proposition(): string | undefined;
+/**
+* The natural deduction proof as JSON, see `Derivation::to_json`. Open goals are leaves
+* with the rule `?`.
+* @returns {string}
+*/
+  derivation_json(): string;
+/**
+* @returns {string}
+*/
+  derivation_latex(): string;
+/**
+*/
+  constructor();
+/**
+* @returns {string | undefined}
+*/
+  term(): string | undefined;
+/**
+* @returns {string}
+*/
+  undo(): string;
+/**
+* The open goals as `x : A, y : B ⊢ ?n : C`.
+* @returns {(string)[]}
+*/
+  goals(): (string)[];
+/**
+* Starts a proof of `proposition`, replacing the current one, and returns the term `?0`.
+* @param {string} proposition
+* @returns {string}
+*/
+  start(proposition: string): string;
+/**
+* Fills the hole `?hole` with `input`, where `?` marks new holes, and returns the new term.
+* @param {number} hole
+* @param {string} input
+* @returns {string}
+*/
+  refine(hole: number, input: string): string;
+}
+/**
+*/
+export class ReductionStats {
+  free(): void;
+/**
+* @param {CostModel} model
+* @returns {number}
+*/
+  cost(model: CostModel): number;
+/**
+* Every step builds a new term, so this is the sum of the sizes of all produced terms.
+*/
+  allocated_nodes: number;
+/**
+*/
+  alpha_renames: number;
+/**
+*/
+  beta_steps: number;
+/**
+*/
+  peak_depth: number;
+/**
+*/
+  peak_size: number;
+/**
+* Variable occurrences replaced by a copy of the argument.
+*/
+  substitutions: number;
+}
+/**
+* A step of a calculus whose steps only report their rule, see `RuleStep`.
+*/
+export class RuleStepInfo {
+  free(): void;
+/**
+* The expression after the step.
+*/
+  readonly expr: string;
+/**
+*/
+  readonly rule: string;
+}
+/**
+* The stepper of `WasmInterface` for the simply typed lambda calculus with base types.
+* Methods with the same name behave the same.
+*/
+export class SimplyTypedWasmInterface {
+  free(): void;
+/**
+* @returns {string | undefined}
+*/
+  current_expr(): string | undefined;
+/**
+* @returns {number}
+*/
+  history_index(): number;
+/**
+* @returns {boolean}
+*/
+  is_normal_form(): boolean;
+/**
+* The typing derivation of `input` as JSON, see `Derivation::to_json`. For ill-typed
+* terms it ends at the failing rule.
+* @param {string} input
+* @returns {string}
+*/
+  derivation_json(input: string): string;
+/**
+* Like `derivation_json`, but as a `bussproofs` proof tree.
+* @param {string} input
+* @returns {string}
+*/
+  derivation_latex(input: string): string;
+/**
+*/
+  constructor();
+/**
+* @returns {string}
+*/
+  redo(): string;
+/**
+* @returns {string}
+*/
+  step(): string;
+/**
+* @returns {string}
+*/
+  undo(): string;
+/**
+* @param {string} input
+* @returns {string}
+*/
+  parse(input: string): string;
+/**
+* @returns {(string)[]}
+*/
+  history(): (string)[];
+/**
+* Checks `input` and returns its type.
+* @param {string} input
+* @returns {string}
+*/
+  type_of(input: string): string;
+/**
+* @returns {boolean}
+*/
+  can_redo(): boolean;
+/**
+* @returns {boolean}
+*/
+  can_undo(): boolean;
+/**
+* Parses `input` and makes it the current expression. Ill-typed terms are accepted, so
+* that students can watch them get stuck.
+* @param {string} input
+* @returns {string}
+*/
+  set_expr(input: string): string;
+/**
+* @param {number} max_steps
+* @returns {string}
+*/
+  normalize(max_steps: number): string;
+/**
+*/
+  strategy: ReductionStrategy;
+}
+/**
+*/
+export class StepInfo {
+  free(): void;
+/**
+* Paths of the renamed binders in the new expression, parallel to `renamed_from`.
+* @returns {(string)[]}
+*/
+  renamed_at(): (string)[];
+/**
+* New names of the renamed binders, parallel to `renamed_from`.
+* @returns {(string)[]}
+*/
+  renamed_to(): (string)[];
+/**
+* Original names of the binders renamed to avoid capture, in the order they were renamed.
+* @returns {(string)[]}
+*/
+  renamed_from(): (string)[];
+/**
+* Paths in the new expression where a copy of the argument was inserted.
+* @returns {(string)[]}
+*/
+  argument_copies(): (string)[];
+/**
+*/
+  readonly argument: string;
+/**
+*/
+  readonly bound_var: string;
+/**
+* The expression after the step.
+*/
+  readonly expr: string;
+/**
+* Path of the contracted redex in the expression before the step.
+*/
+  readonly redex_path: string;
+/**
+*/
+  readonly rule: string;
+}
+/**
+* The stepper of `WasmInterface` for System F, where steps contract both term and type
+* applications. Methods with the same name behave the same.
+*/
+export class SystemFWasmInterface {
+  free(): void;
+/**
+* @returns {string | undefined}
+*/
+  current_expr(): string | undefined;
+/**
+* @returns {number}
+*/
+  history_index(): number;
+/**
+* @returns {boolean}
+*/
+  is_normal_form(): boolean;
+/**
+* The typing derivation of `input` as JSON, see `Derivation::to_json`. For ill-typed
+* terms it ends at the failing rule.
+* @param {string} input
+* @returns {string}
+*/
+  derivation_json(input: string): string;
+/**
+* Like `derivation_json`, but as a `bussproofs` proof tree.
+* @param {string} input
+* @returns {string}
+*/
+  derivation_latex(input: string): string;
+/**
+*/
+  constructor();
+/**
+* @returns {string}
+*/
+  redo(): string;
+/**
+* @returns {string}
+*/
+  step(): string;
+/**
+* @returns {string}
+*/
+  undo(): string;
+/**
+* @param {string} input
+* @returns {string}
+*/
+  parse(input: string): string;
+/**
+* @returns {(string)[]}
+*/
+  history(): (string)[];
+/**
+* Checks `input` and returns its type.
+* @param {string} input
+* @returns {string}
+*/
+  type_of(input: string): string;
+/**
+* @returns {boolean}
+*/
+  can_redo(): boolean;
+/**
+* @returns {boolean}
+*/
+  can_undo(): boolean;
+/**
+* Parses `input` and makes it the current expression. Ill-typed terms are accepted, so
+* that students can watch them get stuck.
+* @param {string} input
+* @returns {string}
+*/
+  set_expr(input: string): string;
+/**
+* @param {number} max_steps
+* @returns {string}
+*/
+  normalize(max_steps: number): string;
+/**
+*/
+  strategy: ReductionStrategy;
+}
+/**
+* A lambda term compiled into combinators.
+*/
+export class Translation {
+  free(): void;
+/**
+*/
+  readonly expr: string;
+/**
+* `undefined` if the lambda term or its translation has no normal form within the step
+* limit.
+*/
+  readonly preserves_normal_form: boolean | undefined;
+/**
+*/
+  readonly size: number;
+/**
+* Size of the lambda term that was compiled.
+*/
+  readonly source_size: number;
+}
+/**
+* The stepper of `WasmInterface` for the simply typed lambda calculus with products, sums and
+* recursive types. Methods with the same name behave the same, so the frontend can switch
+* between the two.
+*/
+export class TypedWasmInterface {
+  free(): void;
+/**
+* @returns {string | undefined}
+*/
+  current_expr(): string | undefined;
+/**
+* Translates the untyped term `input` into this calculus, typed with `μα. α → α`.
+* @param {string} input
+* @returns {string}
+*/
+  embed_untyped(input: string): string;
+/**
+* @returns {number}
+*/
+  history_index(): number;
+/**
+* @returns {boolean}
+*/
+  is_normal_form(): boolean;
+/**
+* The typing derivation of `input` as JSON, see `Derivation::to_json`. For ill-typed
+* terms it ends at the failing rule.
+* @param {string} input
+* @returns {string}
+*/
+  derivation_json(input: string): string;
+/**
+* Like `derivation_json`, but as a `bussproofs` proof tree.
+* @param {string} input
+* @returns {string}
+*/
+  derivation_latex(input: string): string;
+/**
+*/
+  constructor();
+/**
+* @returns {string}
+*/
+  redo(): string;
+/**
+* @returns {string}
+*/
+  step(): string;
+/**
+* @returns {string}
+*/
+  undo(): string;
+/**
+* @param {string} input
+* @returns {string}
+*/
+  parse(input: string): string;
+/**
+* @returns {(string)[]}
+*/
+  history(): (string)[];
+/**
+* Checks `input` and returns its type.
+* @param {string} input
+* @returns {string}
+*/
+  type_of(input: string): string;
+/**
+* @returns {boolean}
+*/
+  can_redo(): boolean;
+/**
+* @returns {boolean}
+*/
+  can_undo(): boolean;
+/**
+* Parses `input` and makes it the current expression. Ill-typed terms are accepted, so
+* that students can watch them get stuck.
+* @param {string} input
+* @returns {string}
+*/
+  set_expr(input: string): string;
+/**
+* Checks `input`, where only abstractions in function position need their types, and
+* returns it with all abstractions annotated.
+* @param {string} input
+* @returns {string}
+*/
+  elaborate(input: string): string;
+/**
+* @param {number} max_steps
+* @returns {string}
+*/
+  normalize(max_steps: number): string;
+/**
+*/
+  strategy: ReductionStrategy;
+}
+/**
+*/
+export class WasmInterface {
+  free(): void;
+/**
+* Infers the principal type of `input`, keeping `let`-bound variables polymorphic.
+* @param {string} input
+* @returns {string}
+*/
+  infer_type(input: string): string;
+/**
+* Some closed normal term of the simple type `ty`, or an error if there is none.
+* @param {string} ty
+* @returns {string}
+*/
+  inhabitant(ty: string): string;
+/**
+* @returns {boolean}
+*/
+  is_running(): boolean;
+/**
+* All closed normal terms of the simple type `ty` with at most `max_size` nodes, smallest
+* first. Fails if `ty` has no inhabitants at all or `max_size` exceeds
+* `MAX_INHABITANT_SIZE`.
+* @param {string} ty
+* @param {number} max_size
+* @returns {(string)[]}
+*/
+  inhabitants(ty: string, max_size: number): (string)[];
+/**
+* Takes at most `max_steps` transitions, stopping early in a final state.
+* @param {number} max_steps
+* @returns {MachineState}
+*/
+  machine_run(max_steps: number): MachineState;
+/**
+* Checks `input` as a term of the given corner of the lambda cube and returns its type.
+* @param {string} input
+* @param {LambdaCube} system
+* @returns {string}
+*/
+  pts_type_of(input: string, system: LambdaCube): string;
+/**
+*/
+  reset_stats(): void;
+/**
+* @returns {string | undefined}
+*/
+  current_expr(): string | undefined;
+/**
+* @returns {MachineState}
+*/
+  machine_redo(): MachineState;
+/**
+* @returns {MachineState}
+*/
+  machine_step(): MachineState;
+/**
+* @returns {MachineState}
+*/
+  machine_undo(): MachineState;
+/**
+* Encodes the current expression and strategy, plus `step` if the receiver should replay steps.
+* @param {number | undefined} [step]
+* @returns {string}
+*/
+  share_string(step?: number): string;
+/**
+* @returns {number}
+*/
+  history_index(): number;
+/**
+* @returns {MachineState | undefined}
+*/
+  machine_state(): MachineState | undefined;
+/**
+* Loads the current expression into `machine`, replacing a machine that was started
+* before. The machine steps independently of the expression history.
+* @param {AbstractMachine} machine
+* @returns {MachineState}
+*/
+  start_machine(machine: AbstractMachine): MachineState;
+/**
+* @returns {boolean}
+*/
+  is_normal_form(): boolean;
+/**
+* Checks `input` as a term with records and returns its minimal type under subtyping.
+* @param {string} input
+* @returns {string}
+*/
+  record_type_of(input: string): string;
+/**
+* Like `step`, but also describes the contracted redex and the substitution.
+* @returns {StepInfo}
+*/
+  step_annotated(): StepInfo;
+/**
+* Creates `to` with a copy of the history and settings of `from`.
+* @param {string} from
+* @param {string} to
+*/
+  clone_workspace(from: string, to: string): void;
+/**
+* @returns {(string)[]}
+*/
+  workspace_names(): (string)[];
+/**
+* Creates an empty workspace with default settings without switching to it.
+* @param {string} name
+*/
+  create_workspace(name: string): void;
+/**
+* @param {string} name
+*/
+  delete_workspace(name: string): void;
+/**
+* Makes `name` the workspace all other calls operate on.
+* @param {string} name
+*/
+  switch_workspace(name: string): void;
+/**
+* Describes the binders of `input` that are used more or less often than `discipline` allows.
+* @param {string} input
+* @param {Discipline} discipline
+* @returns {(string)[]}
+*/
+  usage_violations(input: string, discipline: Discipline): (string)[];
+/**
+* @param {string} s
+* @returns {string}
+*/
+  load_share_string(s: string): string;
+/**
+* The principal intersection typing of the current expression, if it is a normal form.
+* @returns {string}
+*/
+  intersection_typing(): string;
+/**
+* Checks that the current expression has intersection type `ty` in `context`, written
+* as `x: T, y: U`, and returns the judgement.
+* @param {string} context
+* @param {string} ty
+* @returns {string}
+*/
+  check_intersection_typing(context: string, ty: string): string;
+/**
+*/
+  constructor();
+/**
+* Continues the running evaluation for at most `budget_steps` steps. Once `more_work`
+* is `false` the result has been added to the history.
+* @param {number} budget_steps
+* @returns {EvaluationProgress}
+*/
+  run(budget_steps: number): EvaluationProgress;
+/**
+* @returns {string}
+*/
+  redo(): string;
+/**
+* Reduces one redex of the current expression chosen by the current strategy.
+* @returns {string}
+*/
+  step(): string;
+/**
+* @returns {string}
+*/
+  undo(): string;
+/**
+* Parses `input` and returns it pretty printed without touching the history.
+* @param {string} input
+* @returns {string}
+*/
+  parse(input: string): string;
+/**
+* Starts a resumable evaluation of the current expression, replacing a running one. Any
+* change of the current expression, including undo and redo, drops the evaluation.
+* @returns {EvaluationProgress}
+*/
+  start(): EvaluationProgress;
+/**
+* Work done since the current expression was entered, including undone steps.
+* @returns {ReductionStats}
+*/
+  stats(): ReductionStats;
+/**
+* The most restrictive discipline all binders of `input` obey.
+* @param {string} input
+* @returns {Discipline}
+*/
+  usage(input: string): Discipline;
+/**
+* Stops the running evaluation and adds the expression reached so far to the history.
+* @returns {string}
+*/
+  cancel(): string;
+/**
+* @returns {(string)[]}
+*/
+  history(): (string)[];
+/**
+* @returns {boolean}
+*/
+  can_redo(): boolean;
+/**
+* @returns {boolean}
+*/
+  can_undo(): boolean;
+/**
+* Contracts the leftmost outermost η-redex `λx. M x` of the current expression, which
+* no strategy chooses on its own.
+* @returns {StepInfo}
+*/
+  eta_step(): StepInfo;
+/**
+* Parses `input` and makes it the current expression. In linear mode only linear terms
+* are accepted.
+* @param {string} input
+* @returns {string}
+*/
+  set_expr(input: string): string;
+/**
+* Adds the current expression of `from` to the history of `to`.
+* @param {string} from
+* @param {string} to
+* @returns {string}
+*/
+  copy_expr(from: string, to: string): string;
+/**
+* Reduces the current expression until no redex is left or `max_steps` steps were taken.
+* @param {number} max_steps
+* @returns {string}
+*/
+  normalize(max_steps: number): string;
+/**
+*/
+  readonly current_workspace: string;
+/**
+*/
+  linear: boolean;
+/**
+*/
+  strategy: ReductionStrategy;
+}
+
+export type InitInput = RequestInfo | URL | Response | BufferSource | WebAssembly.Module;
+
+export interface InitOutput {
+  readonly memory: WebAssembly.Memory;
+  readonly __wbg_proofwasminterface_free: (a: number) => void;
+  readonly proofwasminterface_derivation_json: (a: number, b: number) => void;
+  readonly proofwasminterface_derivation_latex: (a: number, b: number) => void;
+  readonly proofwasminterface_goal_holes: (a: number, b: number) => void;
+  readonly proofwasminterface_goals: (a: number, b: number) => void;
+  readonly proofwasminterface_is_complete: (a: number) => number;
+  readonly proofwasminterface_new: () => number;
+  readonly proofwasminterface_proof_term: (a: number, b: number) => void;
+  readonly proofwasminterface_proposition: (a: number, b: number) => void;
+  readonly proofwasminterface_refine: (a: number, b: number, c: number, d: number, e: number) => void;
+  readonly proofwasminterface_start: (a: number, b: number, c: number, d: number) => void;
+  readonly proofwasminterface_term: (a: number, b: number) => void;
+  readonly proofwasminterface_undo: (a: number, b: number) => void;
+  readonly __wbg_evaluationprogress_free: (a: number) => void;
+  readonly __wbg_get_evaluationprogress_expr: (a: number, b: number) => void;
+  readonly __wbg_get_evaluationprogress_more_work: (a: number) => number;
+  readonly __wbg_get_evaluationprogress_steps: (a: number) => number;
+  readonly __wbg_set_evaluationprogress_expr: (a: number, b: number, c: number) => void;
+  readonly __wbg_set_evaluationprogress_more_work: (a: number, b: number) => void;
+  readonly __wbg_set_evaluationprogress_steps: (a: number, b: number) => void;
+  readonly __wbg_simplytypedwasminterface_free: (a: number) => void;
+  readonly simplytypedwasminterface_can_redo: (a: number) => number;
+  readonly simplytypedwasminterface_can_undo: (a: number) => number;
+  readonly simplytypedwasminterface_current_expr: (a: number, b: number) => void;
+  readonly simplytypedwasminterface_derivation_json: (a: number, b: number, c: number, d: number) => void;
+  readonly simplytypedwasminterface_derivation_latex: (a: number, b: number, c: number, d: number) => void;
+  readonly simplytypedwasminterface_history: (a: number, b: number) => void;
+  readonly simplytypedwasminterface_is_normal_form: (a: number, b: number) => void;
+  readonly simplytypedwasminterface_new: () => number;
+  readonly simplytypedwasminterface_normalize: (a: number, b: number, c: number) => void;
+  readonly simplytypedwasminterface_parse: (a: number, b: number, c: number, d: number) => void;
+  readonly simplytypedwasminterface_redo: (a: number, b: number) => void;
+  readonly simplytypedwasminterface_set_expr: (a: number, b: number, c: number, d: number) => void;
+  readonly simplytypedwasminterface_set_strategy: (a: number, b: number) => void;
+  readonly simplytypedwasminterface_step: (a: number, b: number) => void;
+  readonly simplytypedwasminterface_strategy: (a: number) => number;
+  readonly simplytypedwasminterface_type_of: (a: number, b: number, c: number, d: number) => void;
+  readonly simplytypedwasminterface_undo: (a: number, b: number) => void;
+  readonly simplytypedwasminterface_history_index: (a: number) => number;
+  readonly __wbg_wasminterface_free: (a: number) => void;
+  readonly api_version: () => number;
+  readonly init: () => number;
+  readonly wasminterface_can_redo: (a: number) => number;
+  readonly wasminterface_can_undo: (a: number) => number;
+  readonly wasminterface_cancel: (a: number, b: number) => void;
+  readonly wasminterface_check_intersection_typing: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
+  readonly wasminterface_clone_workspace: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
+  readonly wasminterface_copy_expr: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
+  readonly wasminterface_create_workspace: (a: number, b: number, c: number, d: number) => void;
+  readonly wasminterface_current_expr: (a: number, b: number) => void;
+  readonly wasminterface_current_workspace: (a: number, b: number) => void;
+  readonly wasminterface_delete_workspace: (a: number, b: number, c: number, d: number) => void;
+  readonly wasminterface_eta_step: (a: number, b: number) => void;
+  readonly wasminterface_history: (a: number, b: number) => void;
+  readonly wasminterface_history_index: (a: number) => number;
+  readonly wasminterface_infer_type: (a: number, b: number, c: number, d: number) => void;
+  readonly wasminterface_inhabitant: (a: number, b: number, c: number, d: number) => void;
+  readonly wasminterface_inhabitants: (a: number, b: number, c: number, d: number, e: number) => void;
+  readonly wasminterface_intersection_typing: (a: number, b: number) => void;
+  readonly wasminterface_is_normal_form: (a: number, b: number) => void;
+  readonly wasminterface_is_running: (a: number) => number;
+  readonly wasminterface_linear: (a: number) => number;
+  readonly wasminterface_load_share_string: (a: number, b: number, c: number, d: number) => void;
+  readonly wasminterface_machine_redo: (a: number, b: number) => void;
+  readonly wasminterface_machine_run: (a: number, b: number, c: number) => void;
+  readonly wasminterface_machine_state: (a: number) => number;
+  readonly wasminterface_machine_step: (a: number, b: number) => void;
+  readonly wasminterface_machine_undo: (a: number, b: number) => void;
+  readonly wasminterface_normalize: (a: number, b: number, c: number) => void;
+  readonly wasminterface_parse: (a: number, b: number, c: number, d: number) => void;
+  readonly wasminterface_pts_type_of: (a: number, b: number, c: number, d: number, e: number) => void;
+  readonly wasminterface_record_type_of: (a: number, b: number, c: number, d: number) => void;
+  readonly wasminterface_redo: (a: number, b: number) => void;
+  readonly wasminterface_reset_stats: (a: number, b: number) => void;
+  readonly wasminterface_run: (a: number, b: number, c: number) => void;
+  readonly wasminterface_set_expr: (a: number, b: number, c: number, d: number) => void;
+  readonly wasminterface_set_linear: (a: number, b: number) => void;
+  readonly wasminterface_set_strategy: (a: number, b: number) => void;
+  readonly wasminterface_share_string: (a: number, b: number, c: number, d: number) => void;
+  readonly wasminterface_start: (a: number, b: number) => void;
+  readonly wasminterface_start_machine: (a: number, b: number, c: number) => void;
+  readonly wasminterface_stats: (a: number) => number;
+  readonly wasminterface_step: (a: number, b: number) => void;
+  readonly wasminterface_step_annotated: (a: number, b: number) => void;
+  readonly wasminterface_strategy: (a: number) => number;
+  readonly wasminterface_switch_workspace: (a: number, b: number, c: number, d: number) => void;
+  readonly wasminterface_undo: (a: number, b: number) => void;
+  readonly wasminterface_usage: (a: number, b: number, c: number, d: number) => void;
+  readonly wasminterface_usage_violations: (a: number, b: number, c: number, d: number, e: number) => void;
+  readonly wasminterface_workspace_names: (a: number, b: number) => void;
+  readonly wasminterface_new: () => number;
+  readonly __wbg_pcfwasminterface_free: (a: number) => void;
+  readonly __wbg_systemfwasminterface_free: (a: number) => void;
+  readonly pcfwasminterface_can_redo: (a: number) => number;
+  readonly pcfwasminterface_can_undo: (a: number) => number;
+  readonly pcfwasminterface_current_expr: (a: number, b: number) => void;
+  readonly pcfwasminterface_derivation_json: (a: number, b: number, c: number, d: number) => void;
+  readonly pcfwasminterface_derivation_latex: (a: number, b: number, c: number, d: number) => void;
+  readonly pcfwasminterface_history: (a: number, b: number) => void;
+  readonly pcfwasminterface_history_index: (a: number) => number;
+  readonly pcfwasminterface_is_normal_form: (a: number, b: number) => void;
+  readonly pcfwasminterface_new: () => number;
+  readonly pcfwasminterface_normalize: (a: number, b: number, c: number) => void;
+  readonly pcfwasminterface_parse: (a: number, b: number, c: number, d: number) => void;
+  readonly pcfwasminterface_redo: (a: number, b: number) => void;
+  readonly pcfwasminterface_rule_step: (a: number, b: number) => void;
+  readonly pcfwasminterface_set_expr: (a: number, b: number, c: number, d: number) => void;
+  readonly pcfwasminterface_set_strategy: (a: number, b: number) => void;
+  readonly pcfwasminterface_step: (a: number, b: number) => void;
+  readonly pcfwasminterface_strategy: (a: number) => number;
+  readonly pcfwasminterface_type_of: (a: number, b: number, c: number, d: number) => void;
+  readonly pcfwasminterface_undo: (a: number, b: number) => void;
+  readonly systemfwasminterface_can_redo: (a: number) => number;
+  readonly systemfwasminterface_current_expr: (a: number, b: number) => void;
+  readonly systemfwasminterface_derivation_json: (a: number, b: number, c: number, d: number) => void;
+  readonly systemfwasminterface_derivation_latex: (a: number, b: number, c: number, d: number) => void;
+  readonly systemfwasminterface_history: (a: number, b: number) => void;
+  readonly systemfwasminterface_is_normal_form: (a: number, b: number) => void;
+  readonly systemfwasminterface_new: () => number;
+  readonly systemfwasminterface_normalize: (a: number, b: number, c: number) => void;
+  readonly systemfwasminterface_parse: (a: number, b: number, c: number, d: number) => void;
+  readonly systemfwasminterface_redo: (a: number, b: number) => void;
+  readonly systemfwasminterface_set_expr: (a: number, b: number, c: number, d: number) => void;
+  readonly systemfwasminterface_step: (a: number, b: number) => void;
+  readonly systemfwasminterface_type_of: (a: number, b: number, c: number, d: number) => void;
+  readonly systemfwasminterface_undo: (a: number, b: number) => void;
+  readonly systemfwasminterface_strategy: (a: number) => number;
+  readonly systemfwasminterface_set_strategy: (a: number, b: number) => void;
+  readonly systemfwasminterface_can_undo: (a: number) => number;
+  readonly systemfwasminterface_history_index: (a: number) => number;
+  readonly __wbg_typedwasminterface_free: (a: number) => void;
+  readonly typedwasminterface_can_redo: (a: number) => number;
+  readonly typedwasminterface_can_undo: (a: number) => number;
+  readonly typedwasminterface_current_expr: (a: number, b: number) => void;
+  readonly typedwasminterface_derivation_json: (a: number, b: number, c: number, d: number) => void;
+  readonly typedwasminterface_derivation_latex: (a: number, b: number, c: number, d: number) => void;
+  readonly typedwasminterface_elaborate: (a: number, b: number, c: number, d: number) => void;
+  readonly typedwasminterface_embed_untyped: (a: number, b: number, c: number, d: number) => void;
+  readonly typedwasminterface_history: (a: number, b: number) => void;
+  readonly typedwasminterface_history_index: (a: number) => number;
+  readonly typedwasminterface_is_normal_form: (a: number, b: number) => void;
+  readonly typedwasminterface_new: () => number;
+  readonly typedwasminterface_normalize: (a: number, b: number, c: number) => void;
+  readonly typedwasminterface_parse: (a: number, b: number, c: number, d: number) => void;
+  readonly typedwasminterface_redo: (a: number, b: number) => void;
+  readonly typedwasminterface_set_expr: (a: number, b: number, c: number, d: number) => void;
+  readonly typedwasminterface_set_strategy: (a: number, b: number) => void;
+  readonly typedwasminterface_step: (a: number, b: number) => void;
+  readonly typedwasminterface_strategy: (a: number) => number;
+  readonly typedwasminterface_type_of: (a: number, b: number, c: number, d: number) => void;
+  readonly typedwasminterface_undo: (a: number, b: number) => void;
+  readonly __wbg_rulestepinfo_free: (a: number) => void;
+  readonly __wbg_stepinfo_free: (a: number) => void;
+  readonly rulestepinfo_expr: (a: number, b: number) => void;
+  readonly rulestepinfo_rule: (a: number, b: number) => void;
+  readonly stepinfo_argument: (a: number, b: number) => void;
+  readonly stepinfo_argument_copies: (a: number, b: number) => void;
+  readonly stepinfo_bound_var: (a: number, b: number) => void;
+  readonly stepinfo_expr: (a: number, b: number) => void;
+  readonly stepinfo_redex_path: (a: number, b: number) => void;
+  readonly stepinfo_renamed_at: (a: number, b: number) => void;
+  readonly stepinfo_renamed_from: (a: number, b: number) => void;
+  readonly stepinfo_renamed_to: (a: number, b: number) => void;
+  readonly stepinfo_rule: (a: number, b: number) => void;
+  readonly __wbg_combinatorwasminterface_free: (a: number) => void;
+  readonly __wbg_machinestate_free: (a: number) => void;
+  readonly __wbg_translation_free: (a: number) => void;
+  readonly combinatorwasminterface_can_redo: (a: number) => number;
+  readonly combinatorwasminterface_can_undo: (a: number) => number;
+  readonly combinatorwasminterface_current_expr: (a: number, b: number) => void;
+  readonly combinatorwasminterface_history: (a: number, b: number) => void;
+  readonly combinatorwasminterface_history_index: (a: number) => number;
+  readonly combinatorwasminterface_is_normal_form: (a: number, b: number) => void;
+  readonly combinatorwasminterface_new: () => number;
+  readonly combinatorwasminterface_normalize: (a: number, b: number, c: number) => void;
+  readonly combinatorwasminterface_parse: (a: number, b: number, c: number, d: number) => void;
+  readonly combinatorwasminterface_redo: (a: number, b: number) => void;
+  readonly combinatorwasminterface_set_expr: (a: number, b: number, c: number, d: number) => void;
+  readonly combinatorwasminterface_set_strategy: (a: number, b: number) => void;
+  readonly combinatorwasminterface_step: (a: number, b: number) => void;
+  readonly combinatorwasminterface_strategy: (a: number) => number;
+  readonly combinatorwasminterface_to_lambda: (a: number, b: number, c: number, d: number) => void;
+  readonly combinatorwasminterface_translate: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
+  readonly combinatorwasminterface_undo: (a: number, b: number) => void;
+  readonly machinestate_is_final: (a: number) => number;
+  readonly machinestate_register_names: (a: number, b: number) => void;
+  readonly machinestate_register_values: (a: number, b: number) => void;
+  readonly machinestate_rule: (a: number, b: number) => void;
+  readonly machinestate_steps: (a: number) => number;
+  readonly machinestate_term: (a: number, b: number) => void;
+  readonly translation_expr: (a: number, b: number) => void;
+  readonly translation_preserves_normal_form: (a: number) => number;
+  readonly translation_source_size: (a: number) => number;
+  readonly translation_size: (a: number) => number;
+  readonly __wbg_get_reductionstats_allocated_nodes: (a: number) => number;
+  readonly __wbg_get_reductionstats_alpha_renames: (a: number) => number;
+  readonly __wbg_get_reductionstats_beta_steps: (a: number) => number;
+  readonly __wbg_get_reductionstats_peak_depth: (a: number) => number;
+  readonly __wbg_get_reductionstats_peak_size: (a: number) => number;
+  readonly __wbg_get_reductionstats_substitutions: (a: number) => number;
+  readonly __wbg_reductionstats_free: (a: number) => void;
+  readonly __wbg_set_reductionstats_allocated_nodes: (a: number, b: number) => void;
+  readonly __wbg_set_reductionstats_alpha_renames: (a: number, b: number) => void;
+  readonly __wbg_set_reductionstats_beta_steps: (a: number, b: number) => void;
+  readonly __wbg_set_reductionstats_peak_depth: (a: number, b: number) => void;
+  readonly __wbg_set_reductionstats_peak_size: (a: number, b: number) => void;
+  readonly __wbg_set_reductionstats_substitutions: (a: number, b: number) => void;
+  readonly reductionstats_cost: (a: number, b: number) => number;
+  readonly __wbindgen_add_to_stack_pointer: (a: number) => number;
+  readonly __wbindgen_free: (a: number, b: number, c: number) => void;
+  readonly __wbindgen_malloc: (a: number, b: number) => number;
+  readonly __wbindgen_realloc: (a: number, b: number, c: number, d: number) => number;
+}
+
+export type SyncInitInput = BufferSource | WebAssembly.Module;
+/**
+* Instantiates the given `module`, which can either be bytes or
+* a precompiled `WebAssembly.Module`.
+*
+* @param {SyncInitInput} module
+*
+* @returns {InitOutput}
+*/
+export function initSync(module: SyncInitInput): InitOutput;
+
+/**
+* If `module_or_path` is {RequestInfo} or {URL}, makes a request and
+* for everything else, calls `WebAssembly.instantiate` directly.
+*
+* @param {InitInput | Promise<InitInput>} module_or_path
+*
+* @returns {Promise<InitOutput>}
+*/
+export default function __wbg_init (module_or_path?: InitInput | Promise<InitInput>): Promise<InitOutput>;
@@ -0,0 +1,33 @@
+# wasm-bindgen 0.2.92 declares the enums in a different order on every run, so they are
+# sorted by name to keep the generated typings reproducible.
+
+function flush_enums(    i, j, tmp) {
+    for (i = 2; i <= n; i++)
+        for (j = i; j > 1 && names[j - 1] > names[j]; j--) {
+            tmp = names[j]; names[j] = names[j - 1]; names[j - 1] = tmp
+        }
+    for (i = 1; i <= n; i++)
+        printf "%s", enums[names[i]]
+    n = 0
+}
+
+{ block = block $0 "\n" }
+
+/^export enum / { name = $3 }
+
+/^}$/ || /^export .*;$/ {
+    if (name != "") {
+        names[++n] = name
+        enums[name] = block
+    } else {
+        flush_enums()
+        printf "%s", block
+    }
+    block = ""
+    name = ""
+}
+
+END {
+    flush_enums()
+    printf "%s", block
+}